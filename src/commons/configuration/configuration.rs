@@ -4,7 +4,7 @@ use cargo_metadata::{CargoOpt, MetadataCommand};
 use lazy_static::lazy_static;
 use uuid::Uuid;
 
-use crate::{commons::exception::connect_exception::ConnectException, infrastructure::{db_service::DBService, db_service_lite::DBServiceLite}};
+use crate::{commons::exception::connect_exception::ConnectException, domain::environment::environment_definition::Environment, infrastructure::{db_service::DBService, db_service_lite::DBServiceLite}};
 
 lazy_static! {
     static ref INSTANCE: Mutex<Option<Configuration>> = Mutex::new(None);
@@ -18,7 +18,8 @@ pub struct Configuration {
     app_version: String,
     session_id: String,
     timestamp: u128,
-    services: HashMap<String, DBService>
+    services: HashMap<String, DBService>,
+    environments: HashMap<String, Environment>
 }
 
 impl Configuration {
@@ -50,9 +51,10 @@ impl Configuration {
             .expect("Cannot read actual date.")
             .as_millis();
         let services = HashMap::new();
+        let environments = HashMap::new();
 
         let config = Configuration {
-            rustc_version, cargo_version, app_name, app_version, session_id, timestamp, services
+            rustc_version, cargo_version, app_name, app_version, session_id, timestamp, services, environments
         };
 
         *instance = Some(config);
@@ -179,4 +181,86 @@ impl Configuration {
         return config.services.remove(&service.name());
     }
 
+    pub fn find_environments() -> Vec<Environment> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+        
+        config.environments.values().cloned().collect()
+    }
+
+    pub fn find_environment(name: &str) -> Option<Environment> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+        
+        config.environments.get(name).cloned()
+    }
+
+    pub fn find_connection_environment(alias: &str) -> Option<Environment> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+        
+        config.environments.values().find(|e| e.contains(alias)).cloned()
+    }
+
+    pub fn push_environment(environment: &Environment) -> Result<&Environment, ConnectException> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+
+        if config.environments.contains_key(&environment.name()) {
+            let exception = ConnectException::new(String::from("Environment already exists."));
+            return Err(exception);
+        }
+
+        let owner = environment.connections().into_iter()
+            .find_map(|alias| config.environments.values().find(|e| e.contains(&alias)).map(|e| (alias, e.name())));
+        if let Some((alias, name)) = owner {
+            let exception = ConnectException::new(format!("Connection '{}' already belongs to environment '{}'.", alias, name));
+            return Err(exception);
+        }
+        
+        config.environments.insert(environment.name(), environment.clone());
+        
+        return Ok(environment);
+    }
+
+    pub fn put_environment(environment: Environment) -> Environment {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+        
+        config.environments.insert(environment.name(), environment.clone());
+        
+        return environment;
+    }
+
+    pub fn remove_environment(name: &str) -> Option<Environment> {
+        let mut instance = INSTANCE.lock().expect("Could not lock mutex");
+        
+        let config = match instance.as_mut() {
+            Some(config) => config,
+            None => panic!("Configuration is not initialized."),
+        };
+        
+        return config.environments.remove(name);
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,58 @@
+use serde_json::{json, Value};
+
+use crate::infrastructure::repository::e_db_repository::EDBRepository;
+
+pub fn help() -> String {
+    let entries = vec![
+        entry("FIND_QUERY", "Find documents", "Runs a filter against a collection and returns the matching documents. Filter snippets are expanded before the query runs.", "READ", vec![], vec!["COUNT", "FIND_PAGE"]),
+        entry("FIND_PAGE", "Find a page of documents", "Returns one page of matching documents using an offset and a limit.", "READ", vec![], vec!["FIND_QUERY"]),
+        entry("COUNT", "Count documents", "Counts the documents that match a filter without transferring them.", "READ", vec![], vec!["FIND_QUERY"]),
+        entry("DOCUMENT_SIZE", "Document size breakdown", "Reports the BSON size of matching documents and the fields that take most of the space.", "READ", vec![], vec![]),
+        entry("SCHEMA", "Infer schema", "Samples documents from the collection and infers field names, types, optionality and example values.", "READ", vec!["The result describes the sample only, rare fields may be missing."], vec!["COLLECTION_VALIDATOR"]),
+        entry("SUGGEST_INDEXES", "Suggest indexes", "Compares recent filters with the existing indexes and proposes indexes for unindexed fields.", "READ", vec![], vec!["COLLECTION_CREATE"]),
+        entry("INSERT", "Insert document", "Inserts a single document. An _id is generated with the configured strategy when missing.", "WRITE", vec!["The document is checked against the registered schema validator first."], vec!["INSERT_MANY", "UPSERT"]),
+        entry("INSERT_MANY", "Insert documents", "Inserts documents in batches and reports the outcome of every document.", "WRITE", vec!["Documents that fail schema validation are reported and skipped."], vec!["INSERT", "COLLECTION_IMPORT"]),
+        entry("UPDATE", "Replace documents", "Replaces the documents that match a filter with a new version.", "WRITE", vec!["Matching documents are overwritten, use a narrow filter."], vec!["PATCH", "UPDATE_TRANSACTION"]),
+        entry("UPDATE_TRANSACTION", "Replace documents in a transaction", "Replaces matching documents inside a transaction so either all or none are changed.", "WRITE", vec!["Requires a replica set or sharded cluster."], vec!["UPDATE"]),
+        entry("PATCH", "Patch fields", "Sets, renames or removes individual fields in the matching documents.", "WRITE", vec![], vec!["UPDATE", "BULK_SET"]),
+        entry("BULK_SET", "Bulk update", "Applies an update specification to every matching document and returns a summary.", "WRITE", vec!["Run the preview first to see how many documents change."], vec!["BULK_SET_PREVIEW"]),
+        entry("BULK_SET_PREVIEW", "Preview bulk update", "Shows how many documents a bulk update would touch, with before and after samples.", "READ", vec![], vec!["BULK_SET"]),
+        entry("UPSERT", "Upsert document", "Replaces the matching document or inserts it when nothing matches.", "WRITE", vec![], vec!["INSERT", "UPDATE"]),
+        entry("DELETE", "Delete documents", "Deletes every document that matches the filter.", "DESTRUCTIVE", vec!["Deleted documents cannot be recovered, run the preview first."], vec!["DELETE_PREVIEW", "DELETE_TRANSACTION"]),
+        entry("DELETE_TRANSACTION", "Delete documents in a transaction", "Deletes matching documents inside a transaction so either all or none are removed.", "DESTRUCTIVE", vec!["Requires a replica set or sharded cluster."], vec!["DELETE"]),
+        entry("DELETE_PREVIEW", "Preview delete", "Shows how many documents a delete would remove, with samples.", "READ", vec![], vec!["DELETE"]),
+        entry("DATA_BASE_CREATE", "Create database", "Creates a database.", "WRITE", vec![], vec!["DATA_BASE_DROP"]),
+        entry("DATA_BASE_DROP", "Drop database", "Drops a database with all of its collections.", "DESTRUCTIVE", vec!["Every collection in the database is removed."], vec!["DATA_BASE_CREATE"]),
+        entry("COLLECTION_CREATE", "Create collection", "Creates a collection with its indexes, validator and backend-specific options such as capped size, collation or time series.", "WRITE", vec!["Some options, such as capped or time series, cannot be changed after creation."], vec!["COLLECTION_VALIDATOR", "COLLECTION_DROP"]),
+        entry("COLLECTION_DROP", "Drop collection", "Drops a collection with its documents and indexes.", "DESTRUCTIVE", vec!["All documents and indexes are removed."], vec!["COLLECTION_DUMP"]),
+        entry("COLLECTION_RENAME", "Rename collection", "Renames a collection inside its database.", "WRITE", vec!["Clients using the old name stop finding the collection."], vec![]),
+        entry("COLLECTION_VALIDATOR", "Collection validator", "Reads or replaces the $jsonSchema validator, validation level and validation action of a collection.", "WRITE", vec!["Existing documents are not revalidated when the validator changes."], vec!["SCHEMA", "COLLECTION_CREATE"]),
+        entry("COLLECTION_EXPORT", "Export collection", "Exports every document of a collection, as a list or as NDJSON.", "READ", vec![], vec!["COLLECTION_IMPORT", "COLLECTION_DUMP"]),
+        entry("COLLECTION_IMPORT", "Import documents", "Imports documents into a collection, optionally after a dry-run preview.", "WRITE", vec!["Run the preview to find duplicates and validation failures first."], vec!["COLLECTION_IMPORT_PREVIEW", "COLLECTION_EXPORT"]),
+        entry("COLLECTION_IMPORT_PREVIEW", "Preview import", "Classifies documents to import as inserts, duplicates or invalid without writing anything.", "READ", vec![], vec!["COLLECTION_IMPORT"]),
+        entry("COLLECTION_DUMP", "Dump collection", "Writes the documents and metadata of a collection in the mongodump layout.", "READ", vec![], vec!["COLLECTION_RESTORE"]),
+        entry("COLLECTION_RESTORE", "Restore collection", "Restores documents and indexes from a mongodump layout.", "WRITE", vec!["Documents with an existing _id are reported as conflicts."], vec!["COLLECTION_DUMP"]),
+        entry("COLLECTION_COMPACT", "Compact collection", "Reclaims disk space used by a collection.", "WRITE", vec!["May block operations on the collection while it runs."], vec!["COLLECTION_REINDEX"]),
+        entry("COLLECTION_REINDEX", "Rebuild indexes", "Rebuilds every index of a collection.", "WRITE", vec!["Blocks operations on the collection while it runs."], vec!["COLLECTION_COMPACT"]),
+        entry("COPY_COLLECTION", "Copy collection", "Copies documents between collections or connections with a conflict policy.", "WRITE", vec!["The overwrite policy replaces documents in the destination."], vec!["PROMOTE"]),
+        entry("PROMOTE", "Promote between environments", "Copies selected collections to another environment, masking fields when moving to a lower tier.", "SEED", vec!["The target environment policy may require a confirmation phrase."], vec!["COPY_COLLECTION"]),
+    ];
+
+    Value::Array(entries).to_string()
+}
+
+fn entry(code: &str, title: &str, summary: &str, safety: &str, notes: Vec<&str>, related: Vec<&str>) -> Value {
+    let backends: Vec<String> = EDBRepository::items().iter()
+        .map(|r| r.to_string())
+        .collect();
+
+    json!({
+        "code": code,
+        "title": title,
+        "summary": summary,
+        "backends": backends,
+        "safety": safety,
+        "notes": notes,
+        "related": related
+    })
+}
\ No newline at end of file
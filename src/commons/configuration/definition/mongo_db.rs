@@ -64,6 +64,144 @@ pub fn mongo_db() -> String {
                     ],
                     "reference": []
                 }
+            ],
+            "options": [
+                {
+                    "order": 0,
+                    "name": "Capped",
+                    "code": "CAPPED",
+                    "category": "BOOLEAN",
+                    "requires": [],
+                    "values": [
+                        {
+                            "key": "True",
+                            "value": "true"
+                        },
+                        {
+                            "key": "False",
+                            "value": "false"
+                        }
+                    ]
+                },
+                {
+                    "order": 1,
+                    "name": "Capped size (bytes)",
+                    "code": "CAPPED_SIZE",
+                    "category": "NUMERIC",
+                    "requires": ["CAPPED"],
+                    "values": []
+                },
+                {
+                    "order": 2,
+                    "name": "Capped max documents",
+                    "code": "CAPPED_MAX",
+                    "category": "NUMERIC",
+                    "requires": ["CAPPED", "CAPPED_SIZE"],
+                    "values": []
+                },
+                {
+                    "order": 3,
+                    "name": "Collation locale",
+                    "code": "COLLATION_LOCALE",
+                    "category": "STRING",
+                    "requires": [],
+                    "values": [
+                        {
+                            "key": "Simple",
+                            "value": "simple"
+                        },
+                        {
+                            "key": "English",
+                            "value": "en"
+                        },
+                        {
+                            "key": "Spanish",
+                            "value": "es"
+                        },
+                        {
+                            "key": "French",
+                            "value": "fr"
+                        },
+                        {
+                            "key": "German",
+                            "value": "de"
+                        }
+                    ]
+                },
+                {
+                    "order": 4,
+                    "name": "Collation strength",
+                    "code": "COLLATION_STRENGTH",
+                    "category": "NUMERIC",
+                    "requires": ["COLLATION_LOCALE"],
+                    "values": [
+                        {
+                            "key": "Primary",
+                            "value": "1"
+                        },
+                        {
+                            "key": "Secondary",
+                            "value": "2"
+                        },
+                        {
+                            "key": "Tertiary",
+                            "value": "3"
+                        },
+                        {
+                            "key": "Quaternary",
+                            "value": "4"
+                        },
+                        {
+                            "key": "Identical",
+                            "value": "5"
+                        }
+                    ]
+                },
+                {
+                    "order": 5,
+                    "name": "Time series time field",
+                    "code": "TIMESERIES_TIME_FIELD",
+                    "category": "STRING",
+                    "requires": [],
+                    "values": []
+                },
+                {
+                    "order": 6,
+                    "name": "Time series meta field",
+                    "code": "TIMESERIES_META_FIELD",
+                    "category": "STRING",
+                    "requires": ["TIMESERIES_TIME_FIELD"],
+                    "values": []
+                },
+                {
+                    "order": 7,
+                    "name": "Time series granularity",
+                    "code": "TIMESERIES_GRANULARITY",
+                    "category": "STRING",
+                    "requires": ["TIMESERIES_TIME_FIELD"],
+                    "values": [
+                        {
+                            "key": "Seconds",
+                            "value": "seconds"
+                        },
+                        {
+                            "key": "Minutes",
+                            "value": "minutes"
+                        },
+                        {
+                            "key": "Hours",
+                            "value": "hours"
+                        }
+                    ]
+                },
+                {
+                    "order": 8,
+                    "name": "Expire after seconds",
+                    "code": "EXPIRE_AFTER_SECONDS",
+                    "category": "NUMERIC",
+                    "requires": ["TIMESERIES_TIME_FIELD"],
+                    "values": []
+                }
             ]
         }
     ).to_string()
@@ -1,9 +1,30 @@
-use std::fmt;
+use std::{fmt, io, sync::Arc};
 use std::error::Error;
 
+use mongodb::error::{ErrorKind, WriteFailure};
+
+use super::{e_connect_error_kind::EConnectErrorKind, error_explainer, error_explanation::ErrorExplanation};
+
+const BAD_VALUE: i32 = 2;
+const FAILED_TO_PARSE: i32 = 9;
+pub(super) const UNAUTHORIZED: i32 = 13;
+const TYPE_MISMATCH: i32 = 14;
+pub(super) const AUTHENTICATION_FAILED: i32 = 18;
+const NAMESPACE_NOT_FOUND: i32 = 26;
+const INDEX_NOT_FOUND: i32 = 27;
+pub(super) const MAX_TIME_MS_EXPIRED: i32 = 50;
+const COMMAND_NOT_FOUND: i32 = 59;
+const WRITE_CONFLICT: i32 = 112;
+const COMMAND_NOT_SUPPORTED: i32 = 115;
+const DOCUMENT_VALIDATION_FAILURE: i32 = 121;
+const DUPLICATE_KEY: i32 = 11000;
+
 #[derive(Debug, Clone)]
 pub struct ConnectException {
     message: String,
+    kind: EConnectErrorKind,
+    code: Option<i32>,
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 
 impl fmt::Display for ConnectException {
@@ -14,13 +35,104 @@ impl fmt::Display for ConnectException {
     
 }
 
-impl Error for ConnectException {}
+impl Error for ConnectException {
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return self.source.as_ref().map(|s| s.as_ref() as &(dyn Error + 'static));
+    }
+
+}
+
+impl From<mongodb::error::Error> for ConnectException {
+
+    fn from(error: mongodb::error::Error) -> Self {
+        let code = match error.kind.as_ref() {
+            ErrorKind::Command(command) => Some(command.code),
+            ErrorKind::Write(WriteFailure::WriteError(write)) => Some(write.code),
+            _ => None,
+        };
+        let kind = match error.kind.as_ref() {
+            ErrorKind::Authentication { .. } => EConnectErrorKind::AUTH,
+            ErrorKind::ServerSelection { .. } | ErrorKind::DnsResolve { .. } | ErrorKind::ConnectionPoolCleared { .. } | ErrorKind::InvalidTlsConfig { .. } => EConnectErrorKind::CONNECTION,
+            ErrorKind::Io(io) => ConnectException::io_kind(io),
+            ErrorKind::InvalidArgument { .. } | ErrorKind::BsonDeserialization(_) | ErrorKind::BsonSerialization(_) => EConnectErrorKind::INVALIDPAYLOAD,
+            ErrorKind::SessionsNotSupported | ErrorKind::IncompatibleServer { .. } => EConnectErrorKind::UNSUPPORTED,
+            ErrorKind::Command(_) | ErrorKind::Write(WriteFailure::WriteError(_)) => code.map(ConnectException::code_kind).unwrap_or(EConnectErrorKind::DRIVER),
+            _ => EConnectErrorKind::DRIVER,
+        };
+        let mut exception = ConnectException::from_source(kind, error.to_string(), Arc::new(error));
+        exception.code = code;
+        return exception;
+    }
+
+}
+
+impl From<mongodb::bson::de::Error> for ConnectException {
+
+    fn from(error: mongodb::bson::de::Error) -> Self {
+        return ConnectException::from_source(EConnectErrorKind::INVALIDPAYLOAD, error.to_string(), Arc::new(error));
+    }
+
+}
+
+impl From<mongodb::bson::ser::Error> for ConnectException {
+
+    fn from(error: mongodb::bson::ser::Error) -> Self {
+        return ConnectException::from_source(EConnectErrorKind::INVALIDPAYLOAD, error.to_string(), Arc::new(error));
+    }
+
+}
+
+impl From<mongodb::bson::document::ValueAccessError> for ConnectException {
+
+    fn from(error: mongodb::bson::document::ValueAccessError) -> Self {
+        return ConnectException::from_source(EConnectErrorKind::DRIVER, error.to_string(), Arc::new(error));
+    }
+
+}
+
+impl From<serde_json::Error> for ConnectException {
+
+    fn from(error: serde_json::Error) -> Self {
+        return ConnectException::from_source(EConnectErrorKind::INVALIDPAYLOAD, error.to_string(), Arc::new(error));
+    }
+
+}
+
+impl From<io::Error> for ConnectException {
+
+    fn from(error: io::Error) -> Self {
+        let kind = match error.kind() {
+            io::ErrorKind::NotFound => EConnectErrorKind::NOTFOUND,
+            io::ErrorKind::InvalidData | io::ErrorKind::UnexpectedEof => EConnectErrorKind::INVALIDPAYLOAD,
+            _ => ConnectException::io_kind(&error),
+        };
+        return ConnectException::from_source(kind, error.to_string(), Arc::new(error));
+    }
+
+}
 
 impl ConnectException {
     
     pub fn new(message: String) -> ConnectException {
+        return ConnectException::from_kind(EConnectErrorKind::UNKNOWN, message);
+    }
+
+    pub fn from_kind(kind: EConnectErrorKind, message: String) -> ConnectException {
+        return ConnectException {
+            message,
+            kind,
+            code: None,
+            source: None
+        };
+    }
+
+    pub fn from_source(kind: EConnectErrorKind, message: String, source: Arc<dyn Error + Send + Sync>) -> ConnectException {
         return ConnectException {
-            message
+            message,
+            kind,
+            code: None,
+            source: Some(source)
         };
     }
     
@@ -28,4 +140,41 @@ impl ConnectException {
         return self.message.clone();
     }
 
+    pub fn kind(&self) -> EConnectErrorKind {
+        return self.kind.clone();
+    }
+
+    pub fn code(&self) -> Option<i32> {
+        return self.code;
+    }
+
+    pub fn is(&self, kind: EConnectErrorKind) -> bool {
+        return self.kind == kind;
+    }
+
+    pub fn explain(&self) -> Option<ErrorExplanation> {
+        return error_explainer::explain(self);
+    }
+
+    fn io_kind(error: &io::Error) -> EConnectErrorKind {
+        return match error.kind() {
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => EConnectErrorKind::TIMEOUT,
+            io::ErrorKind::ConnectionRefused | io::ErrorKind::ConnectionReset | io::ErrorKind::ConnectionAborted
+                | io::ErrorKind::NotConnected | io::ErrorKind::BrokenPipe | io::ErrorKind::AddrNotAvailable => EConnectErrorKind::CONNECTION,
+            _ => EConnectErrorKind::DRIVER,
+        };
+    }
+
+    fn code_kind(code: i32) -> EConnectErrorKind {
+        return match code {
+            UNAUTHORIZED | AUTHENTICATION_FAILED => EConnectErrorKind::AUTH,
+            NAMESPACE_NOT_FOUND | INDEX_NOT_FOUND => EConnectErrorKind::NOTFOUND,
+            MAX_TIME_MS_EXPIRED => EConnectErrorKind::TIMEOUT,
+            WRITE_CONFLICT => EConnectErrorKind::CONFLICT,
+            COMMAND_NOT_FOUND | COMMAND_NOT_SUPPORTED => EConnectErrorKind::UNSUPPORTED,
+            BAD_VALUE | FAILED_TO_PARSE | TYPE_MISMATCH | DOCUMENT_VALIDATION_FAILURE | DUPLICATE_KEY => EConnectErrorKind::INVALIDPAYLOAD,
+            _ => EConnectErrorKind::DRIVER,
+        };
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EConnectErrorKind {
+    CONNECTION,
+    AUTH,
+    NOTFOUND,
+    INVALIDPAYLOAD,
+    TIMEOUT,
+    CONFLICT,
+    UNSUPPORTED,
+    DRIVER,
+    UNKNOWN
+}
+
+impl EConnectErrorKind {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EConnectErrorKind::CONNECTION => String::from("CONNECTION"),
+            EConnectErrorKind::AUTH => String::from("AUTH"),
+            EConnectErrorKind::NOTFOUND => String::from("NOTFOUND"),
+            EConnectErrorKind::INVALIDPAYLOAD => String::from("INVALIDPAYLOAD"),
+            EConnectErrorKind::TIMEOUT => String::from("TIMEOUT"),
+            EConnectErrorKind::CONFLICT => String::from("CONFLICT"),
+            EConnectErrorKind::UNSUPPORTED => String::from("UNSUPPORTED"),
+            EConnectErrorKind::DRIVER => String::from("DRIVER"),
+            EConnectErrorKind::UNKNOWN => String::from("UNKNOWN"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EConnectErrorKind> {
+        match code {
+            "CONNECTION" => Some(EConnectErrorKind::CONNECTION),
+            "AUTH" => Some(EConnectErrorKind::AUTH),
+            "NOTFOUND" => Some(EConnectErrorKind::NOTFOUND),
+            "INVALIDPAYLOAD" => Some(EConnectErrorKind::INVALIDPAYLOAD),
+            "TIMEOUT" => Some(EConnectErrorKind::TIMEOUT),
+            "CONFLICT" => Some(EConnectErrorKind::CONFLICT),
+            "UNSUPPORTED" => Some(EConnectErrorKind::UNSUPPORTED),
+            "DRIVER" => Some(EConnectErrorKind::DRIVER),
+            "UNKNOWN" => Some(EConnectErrorKind::UNKNOWN),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,82 @@
+use std::error::Error;
+
+use mongodb::error::ErrorKind;
+
+use super::{connect_exception::{ConnectException, AUTHENTICATION_FAILED, MAX_TIME_MS_EXPIRED, UNAUTHORIZED}, e_connect_error_kind::EConnectErrorKind, error_explanation::ErrorExplanation};
+
+struct ExplainRule {
+    code: &'static str,
+    cause: &'static str,
+    suggestion: &'static str
+}
+
+const AUTHENTICATION: ExplainRule = ExplainRule {
+    code: "AUTHENTICATION",
+    cause: "The server rejected the username or password.",
+    suggestion: "Check the credentials, the authentication source database (usually 'admin') and the authentication mechanism of the connection."
+};
+
+const UNAUTHORIZED_USER: ExplainRule = ExplainRule {
+    code: "UNAUTHORIZED",
+    cause: "The connected user is not allowed to run this command.",
+    suggestion: "Grant the user a role with the required privileges or connect with a different user."
+};
+
+const TIMELIMIT: ExplainRule = ExplainRule {
+    code: "TIMELIMIT",
+    cause: "The operation ran longer than the time limit allowed by the server.",
+    suggestion: "Narrow the filter, add an index on the filtered fields or raise the time limit."
+};
+
+const TLS: ExplainRule = ExplainRule {
+    code: "TLS",
+    cause: "The secure connection with the server could not be established.",
+    suggestion: "Check whether the server requires TLS, the CA and client certificate paths, and that the host name matches the certificate."
+};
+
+const DNS: ExplainRule = ExplainRule {
+    code: "DNS",
+    cause: "The server host name could not be resolved.",
+    suggestion: "Check the host in the connection string and the network or VPN, and for mongodb+srv URIs that the SRV records exist."
+};
+
+const UNREACHABLE: ExplainRule = ExplainRule {
+    code: "UNREACHABLE",
+    cause: "No reachable server matched the connection settings.",
+    suggestion: "Check that the server is running, the port and firewall rules, and the replica set name and read preference."
+};
+
+pub fn explain(exception: &ConnectException) -> Option<ErrorExplanation> {
+    let rule = server_rule(exception)
+        .or_else(|| driver_rule(exception))
+        .or_else(|| kind_rule(exception))?;
+    Some(ErrorExplanation::new(String::from(rule.code), String::from(rule.cause), String::from(rule.suggestion)))
+}
+
+fn server_rule(exception: &ConnectException) -> Option<&'static ExplainRule> {
+    match exception.code()? {
+        AUTHENTICATION_FAILED => Some(&AUTHENTICATION),
+        UNAUTHORIZED => Some(&UNAUTHORIZED_USER),
+        MAX_TIME_MS_EXPIRED => Some(&TIMELIMIT),
+        _ => None
+    }
+}
+
+fn driver_rule(exception: &ConnectException) -> Option<&'static ExplainRule> {
+    let error = exception.source()?.downcast_ref::<mongodb::error::Error>()?;
+    match error.kind.as_ref() {
+        ErrorKind::Authentication { .. } => Some(&AUTHENTICATION),
+        ErrorKind::DnsResolve { .. } => Some(&DNS),
+        ErrorKind::InvalidTlsConfig { .. } => Some(&TLS),
+        ErrorKind::ServerSelection { .. } => Some(&UNREACHABLE),
+        _ => None
+    }
+}
+
+fn kind_rule(exception: &ConnectException) -> Option<&'static ExplainRule> {
+    match exception.kind() {
+        EConnectErrorKind::AUTH => Some(&AUTHENTICATION),
+        EConnectErrorKind::CONNECTION => Some(&UNREACHABLE),
+        _ => None
+    }
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+#[derive(Debug, Clone)]
+pub struct ErrorExplanation {
+    code: String,
+    cause: String,
+    suggestion: String
+}
+
+impl ErrorExplanation {
+
+    pub fn new(code: String, cause: String, suggestion: String) -> ErrorExplanation {
+        return ErrorExplanation {
+            code,
+            cause,
+            suggestion
+        };
+    }
+
+    pub fn code(&self) -> String {
+        return self.code.clone();
+    }
+
+    pub fn cause(&self) -> String {
+        return self.cause.clone();
+    }
+
+    pub fn suggestion(&self) -> String {
+        return self.suggestion.clone();
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,206 @@
+use std::{env, num::NonZeroU32};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::{aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN}, pbkdf2, rand::{SecureRandom, SystemRandom}};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::i_secret_store::ISecretStore;
+
+pub const MASTER_KEY_VARIABLE: &str = "RUST_DB_MANAGER_MASTER_KEY";
+
+const SEALED_PREFIX: &str = "enc:v1:";
+const SALT_LEN: usize = 16;
+const KEY_ITERATIONS: u32 = 100_000;
+const KEY_LEN: usize = 32;
+
+pub struct AesGcmSecretStore {
+    master: String,
+    salt: [u8; SALT_LEN],
+    key: [u8; KEY_LEN],
+    random: SystemRandom
+}
+
+impl AesGcmSecretStore {
+
+    pub fn new(master: &str) -> Result<AesGcmSecretStore, ConnectException> {
+        if master.is_empty() {
+            return Err(ConnectException::new(String::from("Master key cannot be empty.")));
+        }
+
+        let random = SystemRandom::new();
+
+        let mut salt = [0u8; SALT_LEN];
+        if random.fill(&mut salt).is_err() {
+            return Err(ConnectException::new(String::from("Cannot generate secret salt.")));
+        }
+
+        Ok(AesGcmSecretStore {
+            master: String::from(master),
+            salt,
+            key: AesGcmSecretStore::derive(master, &salt),
+            random
+        })
+    }
+
+    pub fn from_env() -> Result<AesGcmSecretStore, ConnectException> {
+        let master = env::var(MASTER_KEY_VARIABLE);
+        if master.is_err() {
+            let exception = ConnectException::new(format!("Master key not found, define '{}'.", MASTER_KEY_VARIABLE));
+            return Err(exception);
+        }
+        return AesGcmSecretStore::new(&master.unwrap());
+    }
+
+    fn derive(master: &str, salt: &[u8]) -> [u8; KEY_LEN] {
+        let mut key = [0u8; KEY_LEN];
+        let iterations = NonZeroU32::new(KEY_ITERATIONS).unwrap();
+        pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, salt, master.as_bytes(), &mut key);
+        return key;
+    }
+
+    fn cipher(&self, salt: &[u8]) -> Result<LessSafeKey, ConnectException> {
+        let key = if salt == self.salt {
+            self.key
+        } else {
+            AesGcmSecretStore::derive(&self.master, salt)
+        };
+
+        let unbound = UnboundKey::new(&AES_256_GCM, &key);
+        if unbound.is_err() {
+            return Err(ConnectException::new(String::from("Invalid secret key.")));
+        }
+        return Ok(LessSafeKey::new(unbound.unwrap()));
+    }
+
+}
+
+impl ISecretStore for AesGcmSecretStore {
+
+    fn encrypt(&self, plain: &str) -> Result<String, ConnectException> {
+        let cipher = self.cipher(&self.salt)?;
+
+        let mut nonce = [0u8; NONCE_LEN];
+        if self.random.fill(&mut nonce).is_err() {
+            return Err(ConnectException::new(String::from("Cannot generate secret nonce.")));
+        }
+
+        let mut buffer = plain.as_bytes().to_vec();
+        let result = cipher.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut buffer);
+        if result.is_err() {
+            return Err(ConnectException::new(String::from("Cannot encrypt secret.")));
+        }
+
+        let mut sealed = self.salt.to_vec();
+        sealed.extend(nonce);
+        sealed.extend(buffer);
+
+        return Ok(format!("{}{}", SEALED_PREFIX, STANDARD.encode(sealed)));
+    }
+
+    fn decrypt(&self, sealed: &str) -> Result<String, ConnectException> {
+        let Some(encoded) = sealed.strip_prefix(SEALED_PREFIX) else {
+            return Err(ConnectException::new(String::from("Secret is not sealed.")));
+        };
+
+        let decoded = STANDARD.decode(encoded);
+        if let Err(error) = decoded {
+            return Err(ConnectException::new(error.to_string()));
+        }
+
+        let mut salt = decoded.unwrap();
+        if salt.len() < SALT_LEN + NONCE_LEN {
+            return Err(ConnectException::new(String::from("Sealed secret is truncated.")));
+        }
+
+        let mut buffer = salt.split_off(SALT_LEN);
+        let mut payload = buffer.split_off(NONCE_LEN);
+        let nonce = Nonce::try_assume_unique_for_key(&buffer);
+        if nonce.is_err() {
+            return Err(ConnectException::new(String::from("Invalid secret nonce.")));
+        }
+
+        let cipher = self.cipher(&salt)?;
+        let plain = cipher.open_in_place(nonce.unwrap(), Aad::empty(), &mut payload);
+        if plain.is_err() {
+            return Err(ConnectException::new(String::from("Cannot decrypt secret, check the master key.")));
+        }
+
+        let plain = String::from_utf8(plain.unwrap().to_vec());
+        if let Err(error) = plain {
+            return Err(ConnectException::new(error.to_string()));
+        }
+
+        return Ok(plain.unwrap());
+    }
+
+    fn is_sealed(&self, value: &str) -> bool {
+        return value.starts_with(SEALED_PREFIX);
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use base64::{engine::general_purpose::STANDARD, Engine};
+
+    use crate::commons::secret::i_secret_store::ISecretStore;
+
+    use super::{AesGcmSecretStore, SEALED_PREFIX};
+
+    #[test]
+    fn sealed_value_round_trips() {
+        let store = AesGcmSecretStore::new("master").unwrap();
+
+        let sealed = store.encrypt("s3cr3t").unwrap();
+
+        assert!(store.is_sealed(&sealed));
+        assert_ne!(sealed, "s3cr3t");
+        assert_eq!(store.decrypt(&sealed).unwrap(), "s3cr3t");
+    }
+
+    #[test]
+    fn stores_use_their_own_salt() {
+        let first = AesGcmSecretStore::new("master").unwrap();
+        let second = AesGcmSecretStore::new("master").unwrap();
+
+        assert_ne!(first.salt, second.salt);
+        assert_ne!(first.key, second.key);
+
+        let sealed = first.encrypt("s3cr3t").unwrap();
+        assert_eq!(second.decrypt(&sealed).unwrap(), "s3cr3t");
+    }
+
+    #[test]
+    fn wrong_master_key_cannot_decrypt() {
+        let sealed = AesGcmSecretStore::new("master").unwrap().encrypt("s3cr3t").unwrap();
+
+        let other = AesGcmSecretStore::new("other").unwrap();
+
+        assert!(other.decrypt(&sealed).is_err());
+    }
+
+    #[test]
+    fn tampered_value_cannot_decrypt() {
+        let store = AesGcmSecretStore::new("master").unwrap();
+        let sealed = store.encrypt("s3cr3t").unwrap();
+
+        let mut bytes = STANDARD.decode(sealed.strip_prefix(SEALED_PREFIX).unwrap()).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+        let tampered = format!("{}{}", SEALED_PREFIX, STANDARD.encode(bytes));
+
+        assert!(store.decrypt(&tampered).is_err());
+    }
+
+    #[test]
+    fn plain_and_truncated_values_are_rejected() {
+        let store = AesGcmSecretStore::new("master").unwrap();
+
+        assert!(!store.is_sealed("s3cr3t"));
+        assert!(store.decrypt("s3cr3t").is_err());
+        assert!(store.decrypt(&format!("{}{}", SEALED_PREFIX, STANDARD.encode([0u8; 8]))).is_err());
+    }
+
+}
\ No newline at end of file
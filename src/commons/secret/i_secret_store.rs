@@ -0,0 +1,9 @@
+use crate::commons::exception::connect_exception::ConnectException;
+
+pub trait ISecretStore: Send + Sync {
+
+    fn encrypt(&self, plain: &str) -> Result<String, ConnectException>;
+    fn decrypt(&self, sealed: &str) -> Result<String, ConnectException>;
+    fn is_sealed(&self, value: &str) -> bool;
+
+}
\ No newline at end of file
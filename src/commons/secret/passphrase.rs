@@ -0,0 +1,40 @@
+use std::num::NonZeroU32;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::{pbkdf2, rand::{SecureRandom, SystemRandom}};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+const HASH_PREFIX: &str = "pbkdf2:v1:";
+const HASH_ITERATIONS: u32 = 100_000;
+const HASH_LEN: usize = 32;
+const SALT_LEN: usize = 16;
+
+pub fn hash(passphrase: &str) -> Result<String, ConnectException> {
+    if passphrase.is_empty() {
+        return Err(ConnectException::new(String::from("Passphrase cannot be empty.")));
+    }
+
+    let mut salt = [0u8; SALT_LEN];
+    if SystemRandom::new().fill(&mut salt).is_err() {
+        return Err(ConnectException::new(String::from("Could not generate passphrase salt.")));
+    }
+
+    let mut hash = [0u8; HASH_LEN];
+    let iterations = NonZeroU32::new(HASH_ITERATIONS).unwrap();
+    pbkdf2::derive(pbkdf2::PBKDF2_HMAC_SHA256, iterations, &salt, passphrase.as_bytes(), &mut hash);
+
+    return Ok(format!("{}{}:{}", HASH_PREFIX, STANDARD.encode(salt), STANDARD.encode(hash)));
+}
+
+pub fn verify(passphrase: &str, hashed: &str) -> bool {
+    let Some((salt, hash)) = hashed.strip_prefix(HASH_PREFIX).and_then(|h| h.split_once(':')) else {
+        return false;
+    };
+    let (Ok(salt), Ok(hash)) = (STANDARD.decode(salt), STANDARD.decode(hash)) else {
+        return false;
+    };
+
+    let iterations = NonZeroU32::new(HASH_ITERATIONS).unwrap();
+    return pbkdf2::verify(pbkdf2::PBKDF2_HMAC_SHA256, iterations, &salt, passphrase.as_bytes(), &hash).is_ok();
+}
\ No newline at end of file
@@ -0,0 +1,16 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use ring::hmac;
+
+pub fn sign(key: &str, payload: &str) -> String {
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes());
+    let tag = hmac::sign(&key, payload.as_bytes());
+    return STANDARD.encode(tag.as_ref());
+}
+
+pub fn verify(key: &str, payload: &str, signature: &str) -> bool {
+    let Ok(tag) = STANDARD.decode(signature) else {
+        return false;
+    };
+    let key = hmac::Key::new(hmac::HMAC_SHA256, key.as_bytes());
+    return hmac::verify(&key, payload.as_bytes(), &tag).is_ok();
+}
\ No newline at end of file
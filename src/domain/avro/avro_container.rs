@@ -0,0 +1,148 @@
+use ring::rand::{SecureRandom, SystemRandom};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::avro_schema::AvroSchema;
+
+const MAGIC: &[u8; 4] = b"Obj\x01";
+pub const SYNC_SIZE: usize = 16;
+
+/// Frames encoded records as an Avro Object Container File: a header with
+/// the schema and a random sync marker, then blocks of records each
+/// followed by that marker. Records are written uncompressed ("null" codec).
+#[derive(Debug, Clone)]
+pub struct AvroContainer {
+    schema: AvroSchema,
+    sync: [u8; SYNC_SIZE]
+}
+
+impl AvroContainer {
+
+    pub fn new(schema: AvroSchema) -> Result<Self, ConnectException> {
+        let mut sync = [0u8; SYNC_SIZE];
+        if SystemRandom::new().fill(&mut sync).is_err() {
+            return Err(ConnectException::new(String::from("Cannot generate the Avro sync marker.")));
+        }
+        Ok(Self::from_sync(schema, sync))
+    }
+
+    pub fn from_sync(schema: AvroSchema, sync: [u8; SYNC_SIZE]) -> Self {
+        Self {
+            schema, sync
+        }
+    }
+
+    pub fn schema(&self) -> AvroSchema {
+        self.schema.clone()
+    }
+
+    pub fn sync(&self) -> [u8; SYNC_SIZE] {
+        self.sync
+    }
+
+    pub fn header(&self) -> Vec<u8> {
+        let mut buffer = MAGIC.to_vec();
+
+        Self::write_long(&mut buffer, 2);
+        Self::write_bytes(&mut buffer, b"avro.schema");
+        Self::write_bytes(&mut buffer, self.schema.as_avro().to_string().as_bytes());
+        Self::write_bytes(&mut buffer, b"avro.codec");
+        Self::write_bytes(&mut buffer, b"null");
+        Self::write_long(&mut buffer, 0);
+
+        buffer.extend_from_slice(&self.sync);
+        buffer
+    }
+
+    pub fn block(&self, records: &[Vec<u8>]) -> Vec<u8> {
+        let size: usize = records.iter().map(|r| r.len()).sum();
+
+        let mut buffer = Vec::with_capacity(size + SYNC_SIZE + 20);
+        Self::write_long(&mut buffer, records.len() as i64);
+        Self::write_long(&mut buffer, size as i64);
+        for record in records {
+            buffer.extend_from_slice(record);
+        }
+
+        buffer.extend_from_slice(&self.sync);
+        buffer
+    }
+
+    pub fn write_long(buffer: &mut Vec<u8>, value: i64) {
+        let mut zigzag = ((value << 1) ^ (value >> 63)) as u64;
+        while zigzag >= 0x80 {
+            buffer.push((zigzag as u8 & 0x7F) | 0x80);
+            zigzag >>= 7;
+        }
+        buffer.push(zigzag as u8);
+    }
+
+    pub fn write_double(buffer: &mut Vec<u8>, value: f64) {
+        buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    pub fn write_boolean(buffer: &mut Vec<u8>, value: bool) {
+        buffer.push(value as u8);
+    }
+
+    pub fn write_bytes(buffer: &mut Vec<u8>, value: &[u8]) {
+        Self::write_long(buffer, value.len() as i64);
+        buffer.extend_from_slice(value);
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::domain::{avro::avro_schema::AvroSchema, document::{document_schema::DocumentSchema, schema_field::SchemaField}};
+
+    use super::{AvroContainer, SYNC_SIZE};
+
+    fn long(value: i64) -> Vec<u8> {
+        let mut buffer = Vec::new();
+        AvroContainer::write_long(&mut buffer, value);
+        buffer
+    }
+
+    fn container() -> AvroContainer {
+        let mut schema = DocumentSchema::new(Vec::new(), false, Vec::new());
+        schema.set_inferred(vec![SchemaField::new(String::from("n"), vec![String::from("int")], 1, false, Vec::new())], 1);
+        let schema = AvroSchema::from_schema("shop", "orders", &schema).unwrap();
+        AvroContainer::from_sync(schema, [7; SYNC_SIZE])
+    }
+
+    #[test]
+    fn longs_are_zigzag_varints() {
+        assert_eq!(long(0), vec![0x00]);
+        assert_eq!(long(-1), vec![0x01]);
+        assert_eq!(long(1), vec![0x02]);
+        assert_eq!(long(-64), vec![0x7F]);
+        assert_eq!(long(64), vec![0x80, 0x01]);
+        assert_eq!(long(i64::MIN), vec![0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01]);
+    }
+
+    #[test]
+    fn header_carries_schema_and_sync_marker() {
+        let container = container();
+        let header = container.header();
+
+        assert!(header.starts_with(b"Obj\x01"));
+        assert!(header.ends_with(&[7; SYNC_SIZE]));
+
+        let schema = container.schema().as_avro().to_string();
+        assert!(header.windows(schema.len()).any(|w| w == schema.as_bytes()));
+        assert!(header.windows(4).any(|w| w == b"null"));
+    }
+
+    #[test]
+    fn blocks_frame_count_size_and_sync() {
+        let container = container();
+        let block = container.block(&[vec![0x02], vec![0x04, 0x06]]);
+
+        let mut expected = vec![0x04, 0x06, 0x02, 0x04, 0x06];
+        expected.extend_from_slice(&[7; SYNC_SIZE]);
+        assert_eq!(block, expected);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+use serde_json::{json, Value};
+
+use super::e_avro_type::EAvroType;
+
+#[derive(Debug, Clone)]
+pub struct AvroField {
+    name: String,
+    path: String,
+    kind: EAvroType,
+    optional: bool
+}
+
+impl AvroField {
+
+    pub fn new(name: String, path: String, kind: EAvroType, optional: bool) -> Self {
+        Self {
+            name, path, kind, optional
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn kind(&self) -> EAvroType {
+        self.kind.clone()
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    pub fn as_avro(&self) -> Value {
+        if !self.optional {
+            return json!({ "name": self.name, "type": self.kind.as_avro() });
+        }
+        json!({ "name": self.name, "type": ["null", self.kind.as_avro()], "default": null })
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,267 @@
+use serde_json::{json, Map, Value};
+
+use crate::{commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind}, domain::document::document_schema::DocumentSchema};
+
+use super::{avro_container::AvroContainer, avro_field::AvroField, e_avro_type::EAvroType};
+
+/// Avro record schema generated from the inferred fields of a DocumentSchema.
+/// Only top-level fields become Avro fields; nested documents, arrays and
+/// fields with mixed types are written as their JSON text.
+#[derive(Debug, Clone)]
+pub struct AvroSchema {
+    name: String,
+    namespace: String,
+    fields: Vec<AvroField>
+}
+
+impl AvroSchema {
+
+    pub fn from_schema(data_base: &str, collection: &str, schema: &DocumentSchema) -> Result<Self, ConnectException> {
+        let mut fields: Vec<AvroField> = Vec::new();
+        for inferred in schema.inferred().iter().filter(|f| !f.path().contains('.')) {
+            let mut name = Self::sanitize(&inferred.path());
+            let base = name.clone();
+            let mut suffix = 1;
+            while fields.iter().any(|f| f.name() == name) {
+                name = format!("{}_{}", base, suffix);
+                suffix += 1;
+            }
+
+            let optional = inferred.is_optional() || inferred.types().iter().any(|t| t == "null");
+            fields.push(AvroField::new(name, inferred.path(), EAvroType::from_inferred(&inferred.types()), optional));
+        }
+
+        if fields.is_empty() {
+            let message = format!("Cannot generate an Avro schema for '{}.{}', no fields were inferred.", data_base, collection);
+            return Err(ConnectException::new(message));
+        }
+
+        Ok(Self {
+            name: Self::sanitize(collection),
+            namespace: Self::sanitize(data_base),
+            fields
+        })
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn namespace(&self) -> String {
+        self.namespace.clone()
+    }
+
+    pub fn fields(&self) -> Vec<AvroField> {
+        self.fields.clone()
+    }
+
+    pub fn as_avro(&self) -> Value {
+        let fields: Vec<Value> = self.fields.iter().map(|f| f.as_avro()).collect();
+        json!({
+            "type": "record",
+            "name": self.name,
+            "namespace": self.namespace,
+            "fields": fields
+        })
+    }
+
+    pub fn encode(&self, document: &str) -> Result<Vec<u8>, ConnectException> {
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let Value::Object(object) = json.unwrap() else {
+            return Err(ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, String::from("Document must be a JSON object.")));
+        };
+
+        let mut buffer = Vec::new();
+        for field in &self.fields {
+            let value = object.get(&field.path()).filter(|v| !v.is_null());
+            match (value, field.is_optional()) {
+                (None, true) => AvroContainer::write_long(&mut buffer, 0),
+                (None, false) => {
+                    let message = format!("Field '{}' is required by the Avro schema but missing.", field.path());
+                    return Err(ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, message));
+                },
+                (Some(value), optional) => {
+                    if optional {
+                        AvroContainer::write_long(&mut buffer, 1);
+                    }
+                    Self::encode_value(&mut buffer, field, value)?;
+                },
+            }
+        }
+
+        Ok(buffer)
+    }
+
+    fn encode_value(buffer: &mut Vec<u8>, field: &AvroField, value: &Value) -> Result<(), ConnectException> {
+        match field.kind() {
+            EAvroType::STRING => {
+                let text = match value {
+                    Value::String(text) => text.clone(),
+                    Value::Object(object) => Self::extended(object, &["$oid", "$symbol", "$numberDecimal"])
+                        .and_then(|v| v.as_str().map(String::from))
+                        .unwrap_or_else(|| value.to_string()),
+                    value => value.to_string(),
+                };
+                AvroContainer::write_bytes(buffer, text.as_bytes());
+            },
+            EAvroType::JSON => AvroContainer::write_bytes(buffer, value.to_string().as_bytes()),
+            EAvroType::LONG => {
+                let Some(number) = Self::as_long(value) else {
+                    return Err(Self::mismatch(field, value));
+                };
+                AvroContainer::write_long(buffer, number);
+            },
+            EAvroType::DOUBLE => {
+                let Some(number) = Self::as_double(value) else {
+                    return Err(Self::mismatch(field, value));
+                };
+                AvroContainer::write_double(buffer, number);
+            },
+            EAvroType::BOOLEAN => {
+                let Some(flag) = value.as_bool() else {
+                    return Err(Self::mismatch(field, value));
+                };
+                AvroContainer::write_boolean(buffer, flag);
+            },
+            EAvroType::TIMESTAMP => {
+                let millis = value.as_object()
+                    .and_then(|o| Self::extended(o, &["$date"]))
+                    .and_then(Self::as_long);
+                let Some(millis) = millis else {
+                    return Err(Self::mismatch(field, value));
+                };
+                AvroContainer::write_long(buffer, millis);
+            },
+        }
+        Ok(())
+    }
+
+    fn as_long(value: &Value) -> Option<i64> {
+        if let Some(number) = value.as_i64() {
+            return Some(number);
+        }
+        Self::extended(value.as_object()?, &["$numberLong", "$numberInt"])
+            .and_then(|n| n.as_str())
+            .and_then(|n| n.parse().ok())
+    }
+
+    fn as_double(value: &Value) -> Option<f64> {
+        if let Some(number) = value.as_f64() {
+            return Some(number);
+        }
+        Self::extended(value.as_object()?, &["$numberDouble", "$numberLong", "$numberInt"])
+            .and_then(|n| n.as_str())
+            .and_then(|n| n.parse().ok())
+    }
+
+    fn extended<'a>(object: &'a Map<String, Value>, keys: &[&str]) -> Option<&'a Value> {
+        if object.len() != 1 {
+            return None;
+        }
+        keys.iter().find_map(|k| object.get(*k))
+    }
+
+    fn mismatch(field: &AvroField, value: &Value) -> ConnectException {
+        let message = format!("Field '{}' does not match the Avro type {}: {}", field.path(), field.kind().to_string(), value);
+        ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, message)
+    }
+
+    fn sanitize(name: &str) -> String {
+        let mut sanitized: String = name.chars()
+            .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+            .collect();
+        if !sanitized.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+            sanitized.insert(0, '_');
+        }
+        sanitized
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::json;
+
+    use crate::domain::{avro::e_avro_type::EAvroType, document::{document_schema::DocumentSchema, schema_field::SchemaField}};
+
+    use super::AvroSchema;
+
+    fn field(path: &str, types: &[&str], optional: bool) -> SchemaField {
+        SchemaField::new(String::from(path), types.iter().map(|t| String::from(*t)).collect(), 1, optional, Vec::new())
+    }
+
+    fn schema() -> AvroSchema {
+        let mut schema = DocumentSchema::new(Vec::new(), false, Vec::new());
+        schema.set_inferred(vec![
+            field("_id", &["objectId"], false),
+            field("total", &["int", "double"], false),
+            field("items", &["long"], true),
+            field("created", &["date"], false),
+            field("address", &["object"], false),
+            field("address.city", &["string"], false),
+            field("paid-at", &["bool", "null"], false),
+        ], 10);
+        AvroSchema::from_schema("shop", "order-lines", &schema).unwrap()
+    }
+
+    #[test]
+    fn schema_keeps_top_level_fields_with_mapped_types() {
+        let schema = schema();
+
+        let kinds: Vec<(String, EAvroType, bool)> = schema.fields().iter()
+            .map(|f| (f.name(), f.kind(), f.is_optional()))
+            .collect();
+        assert_eq!(kinds, vec![
+            (String::from("_id"), EAvroType::STRING, false),
+            (String::from("total"), EAvroType::DOUBLE, false),
+            (String::from("items"), EAvroType::LONG, true),
+            (String::from("created"), EAvroType::TIMESTAMP, false),
+            (String::from("address"), EAvroType::JSON, false),
+            (String::from("paid_at"), EAvroType::BOOLEAN, true),
+        ]);
+
+        let avro = schema.as_avro();
+        assert_eq!(avro["name"], json!("order_lines"));
+        assert_eq!(avro["fields"][2], json!({"name": "items", "type": ["null", "long"], "default": null}));
+        assert_eq!(avro["fields"][3]["type"], json!({"type": "long", "logicalType": "timestamp-millis"}));
+    }
+
+    #[test]
+    fn documents_encode_in_field_order() {
+        let document = json!({
+            "_id": {"$oid": "abc"},
+            "total": 2,
+            "created": {"$date": {"$numberLong": "1"}},
+            "address": {"city": "x"},
+            "paid-at": true
+        });
+
+        let record = schema().encode(&document.to_string()).unwrap();
+
+        let mut expected = vec![0x06];
+        expected.extend_from_slice(b"abc");
+        expected.extend_from_slice(&2f64.to_le_bytes());
+        expected.push(0x00);
+        expected.push(0x02);
+        expected.push(0x18);
+        expected.extend_from_slice(br#"{"city":"x"}"#);
+        expected.extend_from_slice(&[0x02, 0x01]);
+        assert_eq!(record, expected);
+    }
+
+    #[test]
+    fn mismatched_or_missing_values_are_rejected() {
+        let missing = json!({"total": 1, "created": {"$date": {"$numberLong": "1"}}, "address": {}});
+        assert!(schema().encode(&missing.to_string()).is_err());
+
+        let mismatched = json!({"_id": "a", "total": "one", "created": {"$date": {"$numberLong": "1"}}, "address": {}});
+        assert!(schema().encode(&mismatched.to_string()).is_err());
+    }
+
+}
\ No newline at end of file
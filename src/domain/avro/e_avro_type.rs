@@ -0,0 +1,74 @@
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EAvroType {
+    STRING,
+    LONG,
+    DOUBLE,
+    BOOLEAN,
+    TIMESTAMP,
+    JSON
+}
+
+impl EAvroType {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EAvroType::STRING => String::from("STRING"),
+            EAvroType::LONG => String::from("LONG"),
+            EAvroType::DOUBLE => String::from("DOUBLE"),
+            EAvroType::BOOLEAN => String::from("BOOLEAN"),
+            EAvroType::TIMESTAMP => String::from("TIMESTAMP"),
+            EAvroType::JSON => String::from("JSON"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EAvroType> {
+        match code {
+            "STRING" => Some(EAvroType::STRING),
+            "LONG" => Some(EAvroType::LONG),
+            "DOUBLE" => Some(EAvroType::DOUBLE),
+            "BOOLEAN" => Some(EAvroType::BOOLEAN),
+            "TIMESTAMP" => Some(EAvroType::TIMESTAMP),
+            "JSON" => Some(EAvroType::JSON),
+            _ => None
+        }
+    }
+
+    pub fn from_inferred(types: &[String]) -> EAvroType {
+        let types: Vec<&str> = types.iter()
+            .map(|t| t.as_str())
+            .filter(|t| *t != "null")
+            .collect();
+
+        if types.is_empty() {
+            return EAvroType::STRING;
+        }
+
+        if types.iter().all(|t| matches!(*t, "int" | "long")) {
+            return EAvroType::LONG;
+        }
+
+        if types.iter().all(|t| matches!(*t, "int" | "long" | "double")) {
+            return EAvroType::DOUBLE;
+        }
+
+        match types.as_slice() {
+            ["string"] | ["objectId"] | ["decimal"] | ["symbol"] => EAvroType::STRING,
+            ["bool"] => EAvroType::BOOLEAN,
+            ["date"] => EAvroType::TIMESTAMP,
+            _ => EAvroType::JSON,
+        }
+    }
+
+    pub fn as_avro(&self) -> Value {
+        match self {
+            EAvroType::STRING | EAvroType::JSON => json!("string"),
+            EAvroType::LONG => json!("long"),
+            EAvroType::DOUBLE => json!("double"),
+            EAvroType::BOOLEAN => json!("boolean"),
+            EAvroType::TIMESTAMP => json!({"type": "long", "logicalType": "timestamp-millis"}),
+        }
+    }
+
+}
\ No newline at end of file
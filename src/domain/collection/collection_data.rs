@@ -1,17 +1,18 @@
-use crate::domain::document::document_data::DocumentData;
+use crate::domain::document::{document_data::DocumentData, document_origin::DocumentOrigin};
 
 pub struct CollectionData {
     total: usize,
     limit: Option<usize>,
     offset: Option<usize>,
-    documents: Vec<DocumentData>
+    documents: Vec<DocumentData>,
+    warnings: Vec<String>
 }
 
 impl CollectionData {
     
     pub fn new(total: usize, limit: Option<usize>, offset: Option<usize>, documents: Vec<DocumentData>) -> Self {
         Self {
-            total, limit, offset, documents
+            total, limit, offset, documents, warnings: Vec::new()
         }
     }
 
@@ -31,4 +32,28 @@ impl CollectionData {
         self.documents.clone()
     }
 
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+
+    pub fn set_warnings(&mut self, warnings: Vec<String>) -> &mut Self {
+        self.warnings = warnings;
+        self
+    }
+
+    pub fn set_origin(&mut self, origin: DocumentOrigin) -> &mut Self {
+        for document in self.documents.iter_mut() {
+            document.set_origin(origin.clone());
+        }
+        self
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.offset.unwrap_or_default() + self.documents.len() < self.total
+    }
+
+    pub fn has_previous(&self) -> bool {
+        self.offset.unwrap_or_default() > 0
+    }
+
 }
\ No newline at end of file
@@ -2,18 +2,22 @@ use serde::Deserialize;
 
 use crate::domain::field::{definition::field_definition::FieldDefinition, generate::field_data::FieldData};
 
+use super::collection_option_definition::CollectionOptionDefinition;
+
 #[derive(Clone, Deserialize)]
 pub struct CollectionDefinition {
     swrelational: bool,
     definition: Vec<FieldDefinition>,
-    defaults: Vec<FieldData>
+    defaults: Vec<FieldData>,
+    #[serde(default)]
+    options: Vec<CollectionOptionDefinition>
 }
 
 impl CollectionDefinition {
     
-    pub fn new(swrelational: bool, definition: Vec<FieldDefinition>, defaults: Vec<FieldData>) -> Self {
+    pub fn new(swrelational: bool, definition: Vec<FieldDefinition>, defaults: Vec<FieldData>, options: Vec<CollectionOptionDefinition>) -> Self {
         Self {
-            swrelational, definition, defaults
+            swrelational, definition, defaults, options
         }
     }
 
@@ -29,4 +33,8 @@ impl CollectionDefinition {
         self.defaults.clone()
     }
 
+    pub fn options(&self) -> Vec<CollectionOptionDefinition> {
+        self.options.clone()
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,37 @@
+#[derive(Debug, Clone)]
+pub struct CollectionIndex {
+    name: String,
+    fields: Vec<String>,
+    unique: bool
+}
+
+impl CollectionIndex {
+
+    pub fn new(name: String, fields: Vec<String>, unique: bool) -> Self {
+        Self {
+            name, fields, unique
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn fields(&self) -> Vec<String> {
+        self.fields.clone()
+    }
+
+    pub fn is_unique(&self) -> bool {
+        self.unique
+    }
+
+    pub fn covers(&self, fields: &[String]) -> bool {
+        if fields.is_empty() || fields.len() > self.fields.len() {
+            return false;
+        }
+
+        let prefix = &self.fields[..fields.len()];
+        fields.iter().all(|f| prefix.contains(f))
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,23 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CollectionOption {
+    code: String,
+    value: String
+}
+
+impl CollectionOption {
+
+    pub fn new(code: String, value: String) -> Self {
+        Self { code, value }
+    }
+
+    pub fn code(&self) -> String {
+        self.code.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,49 @@
+use serde::Deserialize;
+
+use crate::domain::{e_json_type::EJSONType, field::definition::field_attribute_default_definition::FieldAttributeDefaultDefinition};
+
+#[derive(Clone, Deserialize)]
+pub struct CollectionOptionDefinition {
+    order: usize,
+    name: String,
+    code: String,
+    category: EJSONType,
+    requires: Vec<String>,
+    values: Vec<FieldAttributeDefaultDefinition>
+}
+
+impl CollectionOptionDefinition {
+
+    pub fn new(order: usize, name: String, code: String, category: EJSONType, requires: Vec<String>, values: Vec<FieldAttributeDefaultDefinition>) -> Self {
+        Self {
+            order, name, code,
+            category, requires,
+            values
+        }
+    }
+
+    pub fn order(&self) -> usize {
+        self.order
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn code(&self) -> String {
+        self.code.clone()
+    }
+
+    pub fn category(&self) -> EJSONType {
+        self.category.clone()
+    }
+
+    pub fn requires(&self) -> Vec<String> {
+        self.requires.clone()
+    }
+
+    pub fn values(&self) -> Vec<FieldAttributeDefaultDefinition> {
+        self.values.clone()
+    }
+
+}
\ No newline at end of file
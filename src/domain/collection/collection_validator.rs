@@ -0,0 +1,52 @@
+use serde_json::Value;
+
+use super::{e_validation_action::EValidationAction, e_validation_level::EValidationLevel};
+
+#[derive(Debug, Clone)]
+pub struct CollectionValidator {
+    schema: Value,
+    level: EValidationLevel,
+    action: EValidationAction
+}
+
+impl CollectionValidator {
+
+    pub fn new(schema: Value) -> CollectionValidator {
+        CollectionValidator {
+            schema,
+            level: EValidationLevel::STRICT,
+            action: EValidationAction::ERROR
+        }
+    }
+
+    pub fn from(schema: Value, level: EValidationLevel, action: EValidationAction) -> CollectionValidator {
+        CollectionValidator {
+            schema,
+            level,
+            action
+        }
+    }
+
+    pub fn schema(&self) -> Value {
+        return self.schema.clone();
+    }
+
+    pub fn level(&self) -> EValidationLevel {
+        return self.level.clone();
+    }
+
+    pub fn action(&self) -> EValidationAction {
+        return self.action.clone();
+    }
+
+    pub fn set_level(&mut self, level: EValidationLevel) -> &mut CollectionValidator {
+        self.level = level;
+        return self;
+    }
+
+    pub fn set_action(&mut self, action: EValidationAction) -> &mut CollectionValidator {
+        self.action = action;
+        return self;
+    }
+
+}
\ No newline at end of file
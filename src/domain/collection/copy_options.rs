@@ -0,0 +1,44 @@
+use super::e_conflict_policy::EConflictPolicy;
+
+const DEFAULT_BATCH_SIZE: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct CopyOptions {
+    conflict: EConflictPolicy,
+    batch_size: usize,
+    byte_budget: Option<usize>
+}
+
+impl CopyOptions {
+
+    pub fn new(conflict: EConflictPolicy) -> CopyOptions {
+        CopyOptions {
+            conflict,
+            batch_size: DEFAULT_BATCH_SIZE,
+            byte_budget: None
+        }
+    }
+
+    pub fn conflict(&self) -> EConflictPolicy {
+        return self.conflict.clone();
+    }
+
+    pub fn batch_size(&self) -> usize {
+        return self.batch_size;
+    }
+
+    pub fn byte_budget(&self) -> Option<usize> {
+        return self.byte_budget;
+    }
+
+    pub fn set_batch_size(&mut self, batch_size: usize) -> &mut CopyOptions {
+        self.batch_size = batch_size.max(1);
+        return self;
+    }
+
+    pub fn set_byte_budget(&mut self, byte_budget: usize) -> &mut CopyOptions {
+        self.byte_budget = Some(byte_budget);
+        return self;
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,83 @@
+use super::e_conflict_policy::EConflictPolicy;
+
+#[derive(Debug, Clone)]
+pub struct CopyReport {
+    conflict: EConflictPolicy,
+    read: usize,
+    inserted: usize,
+    overwritten: usize,
+    skipped: usize,
+    errors: Vec<String>
+}
+
+impl CopyReport {
+
+    pub fn new(conflict: EConflictPolicy) -> Self {
+        Self {
+            conflict,
+            read: 0,
+            inserted: 0,
+            overwritten: 0,
+            skipped: 0,
+            errors: Vec::new()
+        }
+    }
+
+    pub fn conflict(&self) -> EConflictPolicy {
+        self.conflict.clone()
+    }
+
+    pub fn read(&self) -> usize {
+        self.read
+    }
+
+    pub fn inserted(&self) -> usize {
+        self.inserted
+    }
+
+    pub fn overwritten(&self) -> usize {
+        self.overwritten
+    }
+
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+
+    pub fn failed(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+
+    pub fn processed(&self) -> usize {
+        self.inserted + self.overwritten + self.skipped + self.errors.len()
+    }
+
+    pub fn add_read(&mut self, count: usize) -> &mut Self {
+        self.read += count;
+        self
+    }
+
+    pub fn add_inserted(&mut self, count: usize) -> &mut Self {
+        self.inserted += count;
+        self
+    }
+
+    pub fn add_overwritten(&mut self, count: usize) -> &mut Self {
+        self.overwritten += count;
+        self
+    }
+
+    pub fn add_skipped(&mut self, count: usize) -> &mut Self {
+        self.skipped += count;
+        self
+    }
+
+    pub fn push_error(&mut self, error: String) -> &mut Self {
+        self.errors.push(error);
+        self
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+#[derive(Debug, Clone)]
+pub struct DumpReport {
+    data_base: String,
+    collection: String,
+    path: String,
+    documents: usize,
+    indexes: usize,
+    files: Vec<String>,
+    manifest: Option<String>
+}
+
+impl DumpReport {
+
+    pub fn new(data_base: String, collection: String, path: String, documents: usize, indexes: usize) -> Self {
+        Self {
+            data_base, collection, path, documents, indexes, files: Vec::new(), manifest: None
+        }
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    pub fn indexes(&self) -> usize {
+        self.indexes
+    }
+
+    pub fn files(&self) -> Vec<String> {
+        self.files.clone()
+    }
+
+    pub fn manifest(&self) -> Option<String> {
+        self.manifest.clone()
+    }
+
+    pub fn set_files(&mut self, files: Vec<String>) -> &mut Self {
+        self.files = files;
+        self
+    }
+
+    pub fn set_manifest(&mut self, manifest: String) -> &mut Self {
+        self.manifest = Some(manifest);
+        self
+    }
+
+}
\ No newline at end of file
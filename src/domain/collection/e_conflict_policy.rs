@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EConflictPolicy {
+    SKIP,
+    OVERWRITE
+}
+
+impl EConflictPolicy {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EConflictPolicy::SKIP => String::from("SKIP"),
+            EConflictPolicy::OVERWRITE => String::from("OVERWRITE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EConflictPolicy> {
+        match code {
+            "SKIP" => Some(EConflictPolicy::SKIP),
+            "OVERWRITE" => Some(EConflictPolicy::OVERWRITE),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EImportAction {
+    INSERT,
+    REPLACE,
+    FAIL
+}
+
+impl EImportAction {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EImportAction::INSERT => String::from("INSERT"),
+            EImportAction::REPLACE => String::from("REPLACE"),
+            EImportAction::FAIL => String::from("FAIL"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EImportAction> {
+        match code {
+            "INSERT" => Some(EImportAction::INSERT),
+            "REPLACE" => Some(EImportAction::REPLACE),
+            "FAIL" => Some(EImportAction::FAIL),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
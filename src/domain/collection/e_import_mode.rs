@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EImportMode {
+    ORDERED,
+    UNORDERED
+}
+
+impl EImportMode {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EImportMode::ORDERED => String::from("ORDERED"),
+            EImportMode::UNORDERED => String::from("UNORDERED"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EImportMode> {
+        match code {
+            "ORDERED" => Some(EImportMode::ORDERED),
+            "UNORDERED" => Some(EImportMode::UNORDERED),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
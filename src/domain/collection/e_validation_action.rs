@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EValidationAction {
+    ERROR,
+    WARN
+}
+
+impl EValidationAction {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EValidationAction::ERROR => String::from("ERROR"),
+            EValidationAction::WARN => String::from("WARN"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EValidationAction> {
+        match code {
+            "ERROR" => Some(EValidationAction::ERROR),
+            "WARN" => Some(EValidationAction::WARN),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EValidationLevel {
+    OFF,
+    STRICT,
+    MODERATE
+}
+
+impl EValidationLevel {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EValidationLevel::OFF => String::from("OFF"),
+            EValidationLevel::STRICT => String::from("STRICT"),
+            EValidationLevel::MODERATE => String::from("MODERATE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EValidationLevel> {
+        match code {
+            "OFF" => Some(EValidationLevel::OFF),
+            "STRICT" => Some(EValidationLevel::STRICT),
+            "MODERATE" => Some(EValidationLevel::MODERATE),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -1,10 +1,14 @@
 use crate::domain::field::generate::field_data::FieldData;
 
+use super::{collection_option::CollectionOption, collection_validator::CollectionValidator};
+
 #[derive(Clone)]
 pub struct GenerateCollectionQuery {
     data_base: String,
     collection: String,
-    fields: Vec<FieldData>
+    fields: Vec<FieldData>,
+    options: Vec<CollectionOption>,
+    validator: Option<CollectionValidator>
 }
 
 impl GenerateCollectionQuery {
@@ -13,7 +17,9 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: String::new(),
-            fields: Vec::new()
+            fields: Vec::new(),
+            options: Vec::new(),
+            validator: None
         }
     }
 
@@ -21,7 +27,9 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: collection,
-            fields: Vec::new()
+            fields: Vec::new(),
+            options: Vec::new(),
+            validator: None
         }
     }
 
@@ -29,7 +37,9 @@ impl GenerateCollectionQuery {
         Self {
             data_base: data_base,
             collection: collection,
-            fields: fields
+            fields: fields,
+            options: Vec::new(),
+            validator: None
         }
     }
 
@@ -45,4 +55,27 @@ impl GenerateCollectionQuery {
         return self.fields.clone();
     }
 
+    pub fn options(&self) -> Vec<CollectionOption> {
+        return self.options.clone();
+    }
+
+    pub fn option(&self, code: &str) -> Option<String> {
+        return self.options.iter().find(|o| o.code() == code).map(|o| o.value());
+    }
+
+    pub fn set_option(&mut self, code: &str, value: &str) -> &mut Self {
+        self.options.retain(|o| o.code() != code);
+        self.options.push(CollectionOption::new(String::from(code), String::from(value)));
+        return self;
+    }
+
+    pub fn validator(&self) -> Option<CollectionValidator> {
+        return self.validator.clone();
+    }
+
+    pub fn set_validator(&mut self, validator: CollectionValidator) -> &mut Self {
+        self.validator = Some(validator);
+        return self;
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,28 @@
+#[derive(Debug, Clone)]
+pub struct ImportFailure {
+    index: usize,
+    key: Option<String>,
+    error: String
+}
+
+impl ImportFailure {
+
+    pub fn new(index: usize, key: Option<String>, error: String) -> Self {
+        Self {
+            index, key, error
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn key(&self) -> Option<String> {
+        self.key.clone()
+    }
+
+    pub fn error(&self) -> String {
+        self.error.clone()
+    }
+
+}
\ No newline at end of file
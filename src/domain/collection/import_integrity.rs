@@ -0,0 +1,95 @@
+use super::import_failure::ImportFailure;
+
+#[derive(Debug, Clone)]
+pub struct ImportIntegrity {
+    data_base: String,
+    collection: String,
+    received: usize,
+    parsed: usize,
+    inserted: usize,
+    before: u64,
+    after: u64,
+    failures: Vec<ImportFailure>
+}
+
+impl ImportIntegrity {
+
+    pub fn new(data_base: String, collection: String, received: usize, parsed: usize, inserted: usize, failures: Vec<ImportFailure>) -> Self {
+        Self {
+            data_base, collection, received, parsed, inserted, before: 0, after: 0, failures
+        }
+    }
+
+    pub fn set_counts(&mut self, before: u64, after: u64) -> &mut Self {
+        self.before = before;
+        self.after = after;
+        self
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn received(&self) -> usize {
+        self.received
+    }
+
+    pub fn parsed(&self) -> usize {
+        self.parsed
+    }
+
+    pub fn inserted(&self) -> usize {
+        self.inserted
+    }
+
+    pub fn before(&self) -> u64 {
+        self.before
+    }
+
+    pub fn after(&self) -> u64 {
+        self.after
+    }
+
+    pub fn failures(&self) -> Vec<ImportFailure> {
+        self.failures.clone()
+    }
+
+    pub fn failed_keys(&self) -> Vec<String> {
+        self.failures.iter().filter_map(|f| f.key()).collect()
+    }
+
+    pub fn delta(&self) -> i64 {
+        self.after as i64 - self.before as i64
+    }
+
+    pub fn is_consistent(&self) -> bool {
+        self.delta() == self.inserted as i64
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty() && self.is_consistent()
+    }
+
+    pub fn discrepancies(&self) -> Vec<String> {
+        let mut discrepancies = Vec::new();
+
+        if self.parsed < self.received {
+            discrepancies.push(format!("{} of {} source documents could not be parsed.", self.received - self.parsed, self.received));
+        }
+
+        if self.inserted < self.parsed {
+            discrepancies.push(format!("{} of {} parsed documents were rejected by the server.", self.parsed - self.inserted, self.parsed));
+        }
+
+        if !self.is_consistent() {
+            discrepancies.push(format!("Collection count changed by {}, expected {}.", self.delta(), self.inserted));
+        }
+
+        discrepancies
+    }
+
+}
\ No newline at end of file
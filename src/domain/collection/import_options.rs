@@ -0,0 +1,56 @@
+use super::e_import_mode::EImportMode;
+
+const DEFAULT_PARALLELISM: usize = 4;
+const DEFAULT_BATCH_SIZE: usize = 1000;
+
+#[derive(Debug, Clone)]
+pub struct ImportOptions {
+    mode: EImportMode,
+    parallelism: usize,
+    batch_size: usize,
+    byte_budget: Option<usize>
+}
+
+impl ImportOptions {
+
+    pub fn new(mode: EImportMode) -> ImportOptions {
+        ImportOptions {
+            mode,
+            parallelism: DEFAULT_PARALLELISM,
+            batch_size: DEFAULT_BATCH_SIZE,
+            byte_budget: None
+        }
+    }
+
+    pub fn mode(&self) -> EImportMode {
+        return self.mode.clone();
+    }
+
+    pub fn parallelism(&self) -> usize {
+        return self.parallelism;
+    }
+
+    pub fn batch_size(&self) -> usize {
+        return self.batch_size;
+    }
+
+    pub fn byte_budget(&self) -> Option<usize> {
+        return self.byte_budget;
+    }
+
+    pub fn set_parallelism(&mut self, parallelism: usize) -> &mut ImportOptions {
+        self.parallelism = parallelism.max(1);
+        return self;
+    }
+
+    pub fn set_batch_size(&mut self, batch_size: usize) -> &mut ImportOptions {
+        self.batch_size = batch_size.max(1);
+        return self;
+    }
+
+    pub fn set_byte_budget(&mut self, byte_budget: usize) -> &mut ImportOptions {
+        self.byte_budget = Some(byte_budget);
+        return self;
+    }
+
+}
\ No newline at end of file
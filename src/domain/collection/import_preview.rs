@@ -0,0 +1,57 @@
+use super::{e_import_action::EImportAction, import_preview_entry::ImportPreviewEntry};
+
+#[derive(Debug, Clone)]
+pub struct ImportPreview {
+    entries: Vec<ImportPreviewEntry>,
+    validated: bool,
+    applied: bool,
+    warnings: Vec<String>
+}
+
+impl ImportPreview {
+
+    pub fn new(entries: Vec<ImportPreviewEntry>, validated: bool, warnings: Vec<String>) -> Self {
+        Self {
+            entries, validated, warnings,
+            applied: false
+        }
+    }
+
+    pub fn entries(&self) -> Vec<ImportPreviewEntry> {
+        self.entries.clone()
+    }
+
+    pub fn is_validated(&self) -> bool {
+        self.validated
+    }
+
+    pub fn is_applied(&self) -> bool {
+        self.applied
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+
+    pub fn set_applied(&mut self, applied: bool) -> &mut Self {
+        self.applied = applied;
+        self
+    }
+
+    pub fn inserts(&self) -> usize {
+        self.count(EImportAction::INSERT)
+    }
+
+    pub fn replaces(&self) -> usize {
+        self.count(EImportAction::REPLACE)
+    }
+
+    pub fn failures(&self) -> usize {
+        self.count(EImportAction::FAIL)
+    }
+
+    fn count(&self, action: EImportAction) -> usize {
+        self.entries.iter().filter(|e| e.action() == action).count()
+    }
+
+}
\ No newline at end of file
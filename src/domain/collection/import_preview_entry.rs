@@ -0,0 +1,47 @@
+use super::e_import_action::EImportAction;
+
+#[derive(Debug, Clone)]
+pub struct ImportPreviewEntry {
+    index: usize,
+    action: EImportAction,
+    id: Option<String>,
+    error: Option<String>
+}
+
+impl ImportPreviewEntry {
+
+    pub fn insert(index: usize, id: Option<String>) -> Self {
+        Self {
+            index, action: EImportAction::INSERT, id, error: None
+        }
+    }
+
+    pub fn replace(index: usize, id: String) -> Self {
+        Self {
+            index, action: EImportAction::REPLACE, id: Some(id), error: None
+        }
+    }
+
+    pub fn failure(index: usize, id: Option<String>, error: String) -> Self {
+        Self {
+            index, action: EImportAction::FAIL, id, error: Some(error)
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn action(&self) -> EImportAction {
+        self.action.clone()
+    }
+
+    pub fn id(&self) -> Option<String> {
+        self.id.clone()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use crate::domain::document::document_insert_result::DocumentInsertResult;
+
+use super::{e_import_mode::EImportMode, import_worker_report::ImportWorkerReport};
+
+#[derive(Debug, Clone)]
+pub struct ImportReport {
+    mode: EImportMode,
+    results: Vec<DocumentInsertResult>,
+    workers: Vec<ImportWorkerReport>
+}
+
+impl ImportReport {
+
+    pub fn new(mode: EImportMode, results: Vec<DocumentInsertResult>, workers: Vec<ImportWorkerReport>) -> Self {
+        Self {
+            mode, results, workers
+        }
+    }
+
+    pub fn mode(&self) -> EImportMode {
+        self.mode.clone()
+    }
+
+    pub fn results(&self) -> Vec<DocumentInsertResult> {
+        self.results.clone()
+    }
+
+    pub fn workers(&self) -> Vec<ImportWorkerReport> {
+        self.workers.clone()
+    }
+
+    pub fn inserted(&self) -> usize {
+        self.results.iter().filter(|r| r.is_success()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.iter().filter(|r| !r.is_success()).count()
+    }
+
+}
\ No newline at end of file
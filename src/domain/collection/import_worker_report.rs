@@ -0,0 +1,48 @@
+use crate::domain::document::document_insert_result::DocumentInsertResult;
+
+#[derive(Debug, Clone)]
+pub struct ImportWorkerReport {
+    worker: usize,
+    inserted: usize,
+    failed: usize,
+    errors: Vec<String>
+}
+
+impl ImportWorkerReport {
+
+    pub fn new(worker: usize) -> Self {
+        Self {
+            worker, inserted: 0, failed: 0, errors: Vec::new()
+        }
+    }
+
+    pub fn register(&mut self, result: &DocumentInsertResult) -> &mut Self {
+        match result.error() {
+            Some(error) => {
+                self.failed += 1;
+                if !self.errors.contains(&error) {
+                    self.errors.push(error);
+                }
+            },
+            None => self.inserted += 1,
+        }
+        self
+    }
+
+    pub fn worker(&self) -> usize {
+        self.worker
+    }
+
+    pub fn inserted(&self) -> usize {
+        self.inserted
+    }
+
+    pub fn failed(&self) -> usize {
+        self.failed
+    }
+
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+
+}
\ No newline at end of file
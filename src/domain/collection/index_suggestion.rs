@@ -0,0 +1,23 @@
+#[derive(Debug, Clone)]
+pub struct IndexSuggestion {
+    fields: Vec<String>,
+    occurrences: usize
+}
+
+impl IndexSuggestion {
+
+    pub fn new(fields: Vec<String>, occurrences: usize) -> Self {
+        Self {
+            fields, occurrences
+        }
+    }
+
+    pub fn fields(&self) -> Vec<String> {
+        self.fields.clone()
+    }
+
+    pub fn occurrences(&self) -> usize {
+        self.occurrences
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,40 @@
+use crate::domain::table::table_data_group::TableDataGroup;
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceReport {
+    operation: String,
+    ok: bool,
+    details: TableDataGroup,
+    errors: Vec<String>,
+    warnings: Vec<String>
+}
+
+impl MaintenanceReport {
+
+    pub fn new(operation: String, ok: bool, details: TableDataGroup, errors: Vec<String>, warnings: Vec<String>) -> Self {
+        Self {
+            operation, ok, details, errors, warnings
+        }
+    }
+
+    pub fn operation(&self) -> String {
+        self.operation.clone()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.ok
+    }
+
+    pub fn details(&self) -> TableDataGroup {
+        self.details.clone()
+    }
+
+    pub fn errors(&self) -> Vec<String> {
+        self.errors.clone()
+    }
+
+    pub fn warnings(&self) -> Vec<String> {
+        self.warnings.clone()
+    }
+
+}
\ No newline at end of file
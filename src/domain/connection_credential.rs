@@ -0,0 +1,159 @@
+use std::fmt;
+
+use crate::commons::{exception::connect_exception::ConnectException, secret::i_secret_store::ISecretStore};
+
+use super::e_auth_mechanism::EAuthMechanism;
+
+const REDACTED: &str = "****";
+
+#[derive(Clone)]
+pub struct ConnectionCredential {
+    username: String,
+    password: Option<String>,
+    source: Option<String>,
+    mechanism: Option<EAuthMechanism>
+}
+
+impl ConnectionCredential {
+
+    pub fn new(username: String, password: Option<String>) -> ConnectionCredential {
+        ConnectionCredential {
+            username,
+            password,
+            source: None,
+            mechanism: None
+        }
+    }
+
+    pub fn username(&self) -> String {
+        return self.username.clone();
+    }
+
+    pub fn password(&self) -> Option<String> {
+        return self.password.clone();
+    }
+
+    pub fn source(&self) -> Option<String> {
+        return self.source.clone();
+    }
+
+    pub fn mechanism(&self) -> Option<EAuthMechanism> {
+        return self.mechanism.clone();
+    }
+
+    pub fn set_source(&mut self, source: String) -> &mut ConnectionCredential {
+        self.source = Some(source);
+        return self;
+    }
+
+    pub fn set_mechanism(&mut self, mechanism: EAuthMechanism) -> &mut ConnectionCredential {
+        self.mechanism = Some(mechanism);
+        return self;
+    }
+
+    pub fn seal(&self, store: &dyn ISecretStore) -> Result<ConnectionCredential, ConnectException> {
+        let mut sealed = self.clone();
+        if let Some(password) = &self.password {
+            if !store.is_sealed(password) {
+                sealed.password = Some(store.encrypt(password)?);
+            }
+        }
+        return Ok(sealed);
+    }
+
+    pub fn unseal(&self, store: &dyn ISecretStore) -> Result<ConnectionCredential, ConnectException> {
+        let mut unsealed = self.clone();
+        if let Some(password) = &self.password {
+            if store.is_sealed(password) {
+                unsealed.password = Some(store.decrypt(password)?);
+            }
+        }
+        return Ok(unsealed);
+    }
+
+    pub fn key(&self) -> String {
+        return format!("{}|{}|{}|{}",
+            self.username,
+            self.password.clone().unwrap_or_default(),
+            self.source.clone().unwrap_or_default(),
+            self.mechanism.as_ref().map(|m| m.to_string()).unwrap_or_default());
+    }
+
+}
+
+impl fmt::Debug for ConnectionCredential {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionCredential")
+            .field("username", &self.username)
+            .field("password", &self.password.as_ref().map(|_| REDACTED))
+            .field("source", &self.source)
+            .field("mechanism", &self.mechanism)
+            .finish()
+    }
+
+}
+
+pub fn redact_connection(connection: &str) -> String {
+    let Some(scheme) = connection.find("://") else {
+        return String::from(connection);
+    };
+
+    let start = scheme + 3;
+    let authority_end = connection[start..].find('/')
+        .map(|i| start + i)
+        .unwrap_or(connection.len());
+
+    let Some(at) = connection[start..authority_end].rfind('@').map(|i| start + i) else {
+        return String::from(connection);
+    };
+
+    match connection[start..at].find(':') {
+        Some(colon) => format!("{}{}{}", &connection[..start + colon + 1], REDACTED, &connection[at..]),
+        None => String::from(connection),
+    }
+}
+
+pub fn split_connection(connection: &str) -> (String, Option<ConnectionCredential>) {
+    let Some(scheme) = connection.find("://") else {
+        return (String::from(connection), None);
+    };
+
+    let start = scheme + 3;
+    let authority_end = connection[start..].find('/')
+        .map(|i| start + i)
+        .unwrap_or(connection.len());
+
+    let Some(at) = connection[start..authority_end].rfind('@').map(|i| start + i) else {
+        return (String::from(connection), None);
+    };
+
+    let userinfo = &connection[start..at];
+    let credential = match userinfo.split_once(':') {
+        Some((username, password)) => ConnectionCredential::new(decode_userinfo(username), Some(decode_userinfo(password))),
+        None => ConnectionCredential::new(decode_userinfo(userinfo), None),
+    };
+
+    let stripped = format!("{}{}", &connection[..start], &connection[at + 1..]);
+    return (stripped, Some(credential));
+}
+
+fn decode_userinfo(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        if bytes[index] == b'%' && index + 2 < bytes.len() {
+            let hex = std::str::from_utf8(&bytes[index + 1..index + 3]).ok()
+                .and_then(|h| u8::from_str_radix(h, 16).ok());
+            if let Some(byte) = hex {
+                decoded.push(byte);
+                index += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[index]);
+        index += 1;
+    }
+    return String::from_utf8_lossy(&decoded).into_owned();
+}
\ No newline at end of file
@@ -1,9 +1,22 @@
-use crate::infrastructure::repository::e_db_repository::EDBRepository;
+use std::{collections::HashMap, fmt};
 
-#[derive(Debug, Clone)]
+use ring::digest;
+
+use crate::{commons::{exception::connect_exception::ConnectException, secret::i_secret_store::ISecretStore}, infrastructure::repository::e_db_repository::EDBRepository};
+
+use super::{connection_credential::{redact_connection, ConnectionCredential}, connection_tls::ConnectionTls, document::{e_id_strategy::EIdStrategy, lineage_policy::LineagePolicy, soft_delete_policy::SoftDeletePolicy}, e_read_preference::EReadPreference, routing::routing_policy::RoutingPolicy};
+
+#[derive(Clone)]
 pub struct ConnectionData {
     category: EDBRepository,
-    connection: String
+    connection: String,
+    read_preference: Option<EReadPreference>,
+    routing: Option<RoutingPolicy>,
+    tls: Option<ConnectionTls>,
+    credential: Option<ConnectionCredential>,
+    id_strategies: HashMap<String, EIdStrategy>,
+    soft_deletes: HashMap<String, SoftDeletePolicy>,
+    lineage: Option<LineagePolicy>
 }
 
 impl ConnectionData {
@@ -11,7 +24,14 @@ impl ConnectionData {
     pub fn new(category: EDBRepository, connection: String) -> ConnectionData {
         ConnectionData {
             category,
-            connection
+            connection,
+            read_preference: None,
+            routing: None,
+            tls: None,
+            credential: None,
+            id_strategies: HashMap::new(),
+            soft_deletes: HashMap::new(),
+            lineage: None
         }
     }
 
@@ -23,4 +43,149 @@ impl ConnectionData {
         return self.connection.clone();
     }
 
+    pub fn read_preference(&self) -> Option<EReadPreference> {
+        return self.read_preference.clone();
+    }
+
+    pub fn set_read_preference(&mut self, read_preference: EReadPreference) -> &mut ConnectionData {
+        self.read_preference = Some(read_preference);
+        return self;
+    }
+
+    pub fn routing(&self) -> Option<RoutingPolicy> {
+        return self.routing.clone();
+    }
+
+    pub fn set_routing(&mut self, routing: RoutingPolicy) -> &mut ConnectionData {
+        self.routing = Some(routing);
+        return self;
+    }
+
+    pub fn lineage(&self) -> Option<LineagePolicy> {
+        return self.lineage.clone();
+    }
+
+    pub fn set_lineage(&mut self, lineage: LineagePolicy) -> &mut ConnectionData {
+        self.lineage = Some(lineage);
+        return self;
+    }
+
+    pub fn tls(&self) -> Option<ConnectionTls> {
+        return self.tls.clone();
+    }
+
+    pub fn set_tls(&mut self, tls: ConnectionTls) -> &mut ConnectionData {
+        self.tls = Some(tls);
+        return self;
+    }
+
+    pub fn credential(&self) -> Option<ConnectionCredential> {
+        return self.credential.clone();
+    }
+
+    pub fn set_credential(&mut self, credential: ConnectionCredential) -> &mut ConnectionData {
+        self.credential = Some(credential);
+        return self;
+    }
+
+    pub fn seal(&self, store: &dyn ISecretStore) -> Result<ConnectionData, ConnectException> {
+        let mut sealed = self.clone();
+        if let Some(credential) = &self.credential {
+            sealed.credential = Some(credential.seal(store)?);
+        }
+        return Ok(sealed);
+    }
+
+    pub fn unseal(&self, store: &dyn ISecretStore) -> Result<ConnectionData, ConnectException> {
+        let mut unsealed = self.clone();
+        if let Some(credential) = &self.credential {
+            unsealed.credential = Some(credential.unseal(store)?);
+        }
+        return Ok(unsealed);
+    }
+
+    pub fn redacted_connection(&self) -> String {
+        return redact_connection(&self.connection);
+    }
+
+    pub fn client_key(&self) -> String {
+        let read_preference = self.read_preference.as_ref()
+            .map(|r| r.to_string())
+            .unwrap_or_default();
+        let routing = self.routing.as_ref()
+            .map(|r| r.key())
+            .unwrap_or_default();
+        let tls = self.tls.as_ref()
+            .map(|t| t.key())
+            .unwrap_or_default();
+        let credential = self.credential.as_ref()
+            .map(|c| c.key())
+            .unwrap_or_default();
+        let source = format!("{}|{}|{}|{}|{}|{}", self.category.to_string(), self.connection, read_preference, routing, tls, credential);
+        return ConnectionData::sha256(&source);
+    }
+
+    pub fn fingerprint(&self) -> String {
+        let source = format!("{}|{}", self.category.to_string(), self.redacted_connection());
+        return ConnectionData::sha256(&source);
+    }
+
+    fn sha256(source: &str) -> String {
+        let hash = digest::digest(&digest::SHA256, source.as_bytes());
+        return hash.as_ref().iter().map(|b| format!("{:02x}", b)).collect();
+    }
+
+    pub fn id_strategies(&self) -> HashMap<String, EIdStrategy> {
+        return self.id_strategies.clone();
+    }
+
+    pub fn id_strategy(&self, data_base: &str, collection: &str) -> EIdStrategy {
+        let key = format!("{}.{}", data_base, collection);
+        return self.id_strategies.get(&key).cloned()
+            .unwrap_or(EIdStrategy::OBJECTID);
+    }
+
+    pub fn set_id_strategy(&mut self, data_base: &str, collection: &str, strategy: EIdStrategy) -> &mut ConnectionData {
+        let key = format!("{}.{}", data_base, collection);
+        self.id_strategies.insert(key, strategy);
+        return self;
+    }
+
+    pub fn soft_deletes(&self) -> HashMap<String, SoftDeletePolicy> {
+        return self.soft_deletes.clone();
+    }
+
+    pub fn soft_delete(&self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy> {
+        let key = format!("{}.{}", data_base, collection);
+        return self.soft_deletes.get(&key).cloned();
+    }
+
+    pub fn set_soft_delete(&mut self, data_base: &str, collection: &str, policy: SoftDeletePolicy) -> &mut ConnectionData {
+        let key = format!("{}.{}", data_base, collection);
+        self.soft_deletes.insert(key, policy);
+        return self;
+    }
+
+    pub fn remove_soft_delete(&mut self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy> {
+        let key = format!("{}.{}", data_base, collection);
+        return self.soft_deletes.remove(&key);
+    }
+
+}
+
+impl fmt::Debug for ConnectionData {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionData")
+            .field("category", &self.category)
+            .field("connection", &self.redacted_connection())
+            .field("read_preference", &self.read_preference)
+            .field("tls", &self.tls)
+            .field("credential", &self.credential)
+            .field("id_strategies", &self.id_strategies)
+            .field("soft_deletes", &self.soft_deletes)
+            .field("lineage", &self.lineage)
+            .finish()
+    }
+
 }
\ No newline at end of file
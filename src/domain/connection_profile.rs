@@ -0,0 +1,63 @@
+use crate::infrastructure::repository::e_db_repository::EDBRepository;
+
+use super::connection_data::ConnectionData;
+
+#[derive(Debug, Clone)]
+pub struct ConnectionProfile {
+    alias: String,
+    connection_data: ConnectionData,
+    tags: Vec<String>
+}
+
+impl ConnectionProfile {
+
+    pub fn new(alias: String, connection_data: ConnectionData) -> ConnectionProfile {
+        ConnectionProfile {
+            alias,
+            connection_data,
+            tags: Vec::new()
+        }
+    }
+
+    pub fn alias(&self) -> String {
+        return self.alias.clone();
+    }
+
+    pub fn category(&self) -> EDBRepository {
+        return self.connection_data.category();
+    }
+
+    pub fn connection_data(&self) -> ConnectionData {
+        return self.connection_data.clone();
+    }
+
+    pub fn tags(&self) -> Vec<String> {
+        return self.tags.clone();
+    }
+
+    pub fn set_connection_data(&mut self, connection_data: ConnectionData) -> &mut ConnectionProfile {
+        self.connection_data = connection_data;
+        return self;
+    }
+
+    pub fn set_tags(&mut self, tags: Vec<String>) -> &mut ConnectionProfile {
+        self.tags = tags;
+        return self;
+    }
+
+    pub fn add_tag(&mut self, tag: String) -> &mut ConnectionProfile {
+        if !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+        return self;
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        return self.tags.iter().any(|t| t == tag);
+    }
+
+    pub fn is_same(&self, other: &ConnectionProfile) -> bool {
+        return self.alias == other.alias;
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,59 @@
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionTls {
+    ca_file: Option<String>,
+    cert_key_file: Option<String>,
+    allow_invalid_certificates: bool,
+    allow_invalid_hostnames: bool
+}
+
+impl ConnectionTls {
+
+    pub fn new() -> ConnectionTls {
+        ConnectionTls::default()
+    }
+
+    pub fn ca_file(&self) -> Option<String> {
+        return self.ca_file.clone();
+    }
+
+    pub fn cert_key_file(&self) -> Option<String> {
+        return self.cert_key_file.clone();
+    }
+
+    pub fn allow_invalid_certificates(&self) -> bool {
+        return self.allow_invalid_certificates;
+    }
+
+    pub fn allow_invalid_hostnames(&self) -> bool {
+        return self.allow_invalid_hostnames;
+    }
+
+    pub fn set_ca_file(&mut self, ca_file: String) -> &mut ConnectionTls {
+        self.ca_file = Some(ca_file);
+        return self;
+    }
+
+    pub fn set_cert_key_file(&mut self, cert_key_file: String) -> &mut ConnectionTls {
+        self.cert_key_file = Some(cert_key_file);
+        return self;
+    }
+
+    pub fn set_allow_invalid_certificates(&mut self, allow: bool) -> &mut ConnectionTls {
+        self.allow_invalid_certificates = allow;
+        return self;
+    }
+
+    pub fn set_allow_invalid_hostnames(&mut self, allow: bool) -> &mut ConnectionTls {
+        self.allow_invalid_hostnames = allow;
+        return self;
+    }
+
+    pub fn key(&self) -> String {
+        return format!("{}|{}|{}|{}",
+            self.ca_file.clone().unwrap_or_default(),
+            self.cert_key_file.clone().unwrap_or_default(),
+            self.allow_invalid_certificates,
+            self.allow_invalid_hostnames);
+    }
+
+}
\ No newline at end of file
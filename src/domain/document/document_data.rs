@@ -1,4 +1,8 @@
-use super::document_key::DocumentKey;
+use serde_json::Value;
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{document_key::DocumentKey, document_origin::DocumentOrigin};
 
 #[derive(Debug, Clone)]
 pub struct DocumentData {
@@ -6,7 +10,8 @@ pub struct DocumentData {
     collection: String,
     base_key: Option<DocumentKey>,
     keys: Vec<DocumentKey>,
-    document: String
+    document: String,
+    origin: Option<DocumentOrigin>
 }
 
 impl DocumentData {
@@ -14,7 +19,8 @@ impl DocumentData {
     pub fn new(data_base: String, collection: String, base_key: Option<DocumentKey>, keys: Vec<DocumentKey>, document: String) -> Self {
         Self {
             data_base, collection, base_key,
-            keys, document
+            keys, document,
+            origin: None
         }
     }
 
@@ -38,4 +44,39 @@ impl DocumentData {
         self.document.clone()
     }
 
+    pub fn origin(&self) -> Option<DocumentOrigin> {
+        self.origin.clone()
+    }
+
+    pub fn set_origin(&mut self, origin: DocumentOrigin) -> &mut Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn field_values(&self, path: &str) -> Result<Vec<Value>, ConnectException> {
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&self.document);
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut values = vec![json.unwrap()];
+        for key in path.split('.') {
+            values = values.into_iter()
+                .flat_map(|v| match v {
+                    Value::Array(items) => items,
+                    value => vec![value],
+                })
+                .filter_map(|v| v.get(key).cloned())
+                .collect();
+        }
+
+        Ok(values.into_iter()
+            .flat_map(|v| match v {
+                Value::Array(items) => items,
+                value => vec![value],
+            })
+            .filter(|v| !v.is_null())
+            .collect())
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use super::document_data::DocumentData;
+
+#[derive(Debug, Clone)]
+pub struct DocumentDeletePreview {
+    total: usize,
+    samples: Vec<DocumentData>
+}
+
+impl DocumentDeletePreview {
+    
+    pub fn new(total: usize, samples: Vec<DocumentData>) -> Self {
+        Self {
+            total, samples
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn samples(&self) -> Vec<DocumentData> {
+        self.samples.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+#[derive(Debug, Clone)]
+pub struct DocumentFieldSize {
+    path: String,
+    bytes: usize,
+    fields: usize,
+    share: f64
+}
+
+impl DocumentFieldSize {
+
+    pub fn new(path: String, bytes: usize, fields: usize, share: f64) -> Self {
+        Self {
+            path, bytes, fields, share
+        }
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn fields(&self) -> usize {
+        self.fields
+    }
+
+    pub fn share(&self) -> f64 {
+        self.share
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,46 @@
+use super::document_data::DocumentData;
+
+#[derive(Debug, Clone)]
+pub struct DocumentInsertResult {
+    index: usize,
+    document: Option<DocumentData>,
+    error: Option<String>
+}
+
+impl DocumentInsertResult {
+
+    pub fn success(index: usize, document: DocumentData) -> Self {
+        Self {
+            index, document: Some(document), error: None
+        }
+    }
+
+    pub fn failure(index: usize, error: String) -> Self {
+        Self {
+            index, document: None, error: Some(error)
+        }
+    }
+
+    pub fn offset(&self, offset: usize) -> Self {
+        Self {
+            index: self.index + offset, document: self.document.clone(), error: self.error.clone()
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn document(&self) -> Option<DocumentData> {
+        self.document.clone()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::infrastructure::repository::e_db_repository::EDBRepository;
+
+#[derive(Debug, Clone)]
+pub struct DocumentOrigin {
+    connection: String,
+    category: EDBRepository
+}
+
+impl DocumentOrigin {
+
+    pub fn new(connection: String, category: EDBRepository) -> Self {
+        Self {
+            connection, category
+        }
+    }
+
+    pub fn connection(&self) -> String {
+        self.connection.clone()
+    }
+
+    pub fn category(&self) -> EDBRepository {
+        self.category.clone()
+    }
+
+}
\ No newline at end of file
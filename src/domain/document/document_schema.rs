@@ -1,16 +1,39 @@
+use serde_json::Value;
+
 use crate::domain::field::generate::field_data::FieldData;
 
+use super::schema_field::SchemaField;
+
+#[derive(Debug, Clone)]
 pub struct DocumentSchema {
     comments: Vec<String>,
     sw_strict: bool,
-    fields: Vec<FieldData>
+    fields: Vec<FieldData>,
+    validation: Option<Value>,
+    inferred: Vec<SchemaField>,
+    sample_size: usize
 }
 
 impl DocumentSchema {
     
     pub fn new(comments: Vec<String>, sw_strict: bool, fields: Vec<FieldData>) -> Self {
         Self {
-            comments, sw_strict, fields
+            comments, sw_strict, fields,
+            validation: None,
+            inferred: Vec::new(),
+            sample_size: 0
+        }
+    }
+
+    pub fn from_json_schema(validation: Value) -> Self {
+        let sw_strict = validation.get("additionalProperties") == Some(&Value::Bool(false));
+        Self {
+            comments: Vec::new(),
+            sw_strict,
+            fields: Vec::new(),
+            validation: Some(validation),
+            inferred: Vec::new(),
+            sample_size: 0
         }
     }
 
@@ -26,4 +49,27 @@ impl DocumentSchema {
         self.fields.clone()
     }
 
+    pub fn validation(&self) -> Option<Value> {
+        self.validation.clone()
+    }
+
+    pub fn inferred(&self) -> Vec<SchemaField> {
+        self.inferred.clone()
+    }
+
+    pub fn sample_size(&self) -> usize {
+        self.sample_size
+    }
+
+    pub fn set_inferred(&mut self, inferred: Vec<SchemaField>, sample_size: usize) -> &mut Self {
+        self.inferred = inferred;
+        self.sample_size = sample_size;
+        self
+    }
+
+    pub fn set_validation(&mut self, validation: Value) -> &mut Self {
+        self.validation = Some(validation);
+        self
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,51 @@
+use super::{document_field_size::DocumentFieldSize, document_key::DocumentKey};
+
+#[derive(Debug, Clone)]
+pub struct DocumentSize {
+    base_key: Option<DocumentKey>,
+    bytes: usize,
+    limit: usize,
+    fields: Vec<DocumentFieldSize>,
+    hot_spots: Vec<DocumentFieldSize>
+}
+
+impl DocumentSize {
+
+    pub fn new(base_key: Option<DocumentKey>, bytes: usize, limit: usize, fields: Vec<DocumentFieldSize>, hot_spots: Vec<DocumentFieldSize>) -> Self {
+        Self {
+            base_key, bytes, limit, fields, hot_spots
+        }
+    }
+
+    pub fn base_key(&self) -> Option<DocumentKey> {
+        self.base_key.clone()
+    }
+
+    pub fn bytes(&self) -> usize {
+        self.bytes
+    }
+
+    pub fn limit(&self) -> usize {
+        self.limit
+    }
+
+    pub fn usage(&self) -> f64 {
+        if self.limit == 0 {
+            return 0.0;
+        }
+        self.bytes as f64 / self.limit as f64
+    }
+
+    pub fn field_count(&self) -> usize {
+        self.fields.iter().map(|f| f.fields()).sum()
+    }
+
+    pub fn fields(&self) -> Vec<DocumentFieldSize> {
+        self.fields.clone()
+    }
+
+    pub fn hot_spots(&self) -> Vec<DocumentFieldSize> {
+        self.hot_spots.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+#[derive(Debug, Clone)]
+pub struct DocumentUpdateField {
+    field: String,
+    value: Option<String>
+}
+
+impl DocumentUpdateField {
+
+    pub fn set(field: String, value: String) -> Self {
+        Self {
+            field, value: Some(value)
+        }
+    }
+
+    pub fn unset(field: String) -> Self {
+        Self {
+            field, value: None
+        }
+    }
+
+    pub fn field(&self) -> String {
+        self.field.clone()
+    }
+
+    pub fn value(&self) -> Option<String> {
+        self.value.clone()
+    }
+
+    pub fn is_unset(&self) -> bool {
+        self.value.is_none()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use chrono::Utc;
+use uuid::Uuid;
+
+const CROCKFORD_ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum EIdStrategy {
+    OBJECTID,
+    UUIDV4,
+    UUIDV7,
+    ULID,
+    SEQUENTIAL
+}
+
+impl EIdStrategy {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EIdStrategy::OBJECTID => String::from("OBJECTID"),
+            EIdStrategy::UUIDV4 => String::from("UUIDV4"),
+            EIdStrategy::UUIDV7 => String::from("UUIDV7"),
+            EIdStrategy::ULID => String::from("ULID"),
+            EIdStrategy::SEQUENTIAL => String::from("SEQUENTIAL"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EIdStrategy> {
+        match code {
+            "OBJECTID" => Some(EIdStrategy::OBJECTID),
+            "UUIDV4" => Some(EIdStrategy::UUIDV4),
+            "UUIDV7" => Some(EIdStrategy::UUIDV7),
+            "ULID" => Some(EIdStrategy::ULID),
+            "SEQUENTIAL" => Some(EIdStrategy::SEQUENTIAL),
+            _ => None
+        }
+    }
+
+    pub fn generate_key(&self) -> Option<String> {
+        match self {
+            EIdStrategy::UUIDV4 => Some(Uuid::new_v4().hyphenated().to_string()),
+            EIdStrategy::UUIDV7 => Some(uuid_v7()),
+            EIdStrategy::ULID => Some(ulid()),
+            EIdStrategy::OBJECTID | EIdStrategy::SEQUENTIAL => None,
+        }
+    }
+
+}
+
+fn uuid_v7() -> String {
+    let mut bytes = Uuid::new_v4().into_bytes();
+    let timestamp = (Utc::now().timestamp_millis() as u64).to_be_bytes();
+    bytes[..6].copy_from_slice(&timestamp[2..]);
+    bytes[6] = (bytes[6] & 0x0F) | 0x70;
+    bytes[8] = (bytes[8] & 0x3F) | 0x80;
+    Uuid::from_bytes(bytes).hyphenated().to_string()
+}
+
+fn ulid() -> String {
+    let random = u128::from_be_bytes(Uuid::new_v4().into_bytes()) & ((1u128 << 80) - 1);
+    let timestamp = (Utc::now().timestamp_millis() as u128) & ((1u128 << 48) - 1);
+    let value = (timestamp << 80) | random;
+
+    (0..26)
+        .map(|i| CROCKFORD_ALPHABET[((value >> (125 - i * 5)) & 0x1F) as usize] as char)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{thread, time::Duration};
+
+    use uuid::Uuid;
+
+    use super::{EIdStrategy, CROCKFORD_ALPHABET};
+
+    #[test]
+    fn uuid_v7_sets_version_and_variant_bits() {
+        let key = EIdStrategy::UUIDV7.generate_key().unwrap();
+        let uuid = Uuid::parse_str(&key).unwrap();
+
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(uuid.as_bytes()[8] & 0xC0, 0x80);
+    }
+
+    #[test]
+    fn uuid_v7_is_time_ordered() {
+        let first = EIdStrategy::UUIDV7.generate_key().unwrap();
+        thread::sleep(Duration::from_millis(2));
+        let second = EIdStrategy::UUIDV7.generate_key().unwrap();
+
+        assert!(first < second);
+    }
+
+    #[test]
+    fn ulid_uses_crockford_alphabet() {
+        let key = EIdStrategy::ULID.generate_key().unwrap();
+
+        assert_eq!(key.len(), 26);
+        assert!(key.bytes().all(|b| CROCKFORD_ALPHABET.contains(&b)));
+        assert!(key.as_bytes()[0] <= b'7');
+    }
+
+    #[test]
+    fn ulid_is_time_ordered() {
+        let first = EIdStrategy::ULID.generate_key().unwrap();
+        thread::sleep(Duration::from_millis(2));
+        let second = EIdStrategy::ULID.generate_key().unwrap();
+
+        assert!(first < second);
+    }
+
+    #[test]
+    fn server_side_strategies_generate_no_key() {
+        assert!(EIdStrategy::OBJECTID.generate_key().is_none());
+        assert!(EIdStrategy::SEQUENTIAL.generate_key().is_none());
+        assert_eq!(Uuid::parse_str(&EIdStrategy::UUIDV4.generate_key().unwrap()).unwrap().get_version_num(), 4);
+    }
+
+    #[test]
+    fn codes_round_trip() {
+        for strategy in [EIdStrategy::OBJECTID, EIdStrategy::UUIDV4, EIdStrategy::UUIDV7, EIdStrategy::ULID, EIdStrategy::SEQUENTIAL] {
+            assert_eq!(EIdStrategy::from_string(&strategy.to_string()), Some(strategy));
+        }
+        assert_eq!(EIdStrategy::from_string("UUID"), None);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EMergeStrategy {
+    OURS,
+    THEIRS,
+    LASTWRITEWINS
+}
+
+impl EMergeStrategy {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EMergeStrategy::OURS => String::from("OURS"),
+            EMergeStrategy::THEIRS => String::from("THEIRS"),
+            EMergeStrategy::LASTWRITEWINS => String::from("LASTWRITEWINS"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EMergeStrategy> {
+        match code {
+            "OURS" => Some(EMergeStrategy::OURS),
+            "THEIRS" => Some(EMergeStrategy::THEIRS),
+            "LASTWRITEWINS" => Some(EMergeStrategy::LASTWRITEWINS),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
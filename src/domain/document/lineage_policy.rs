@@ -0,0 +1,154 @@
+use serde_json::{json, Map, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::update::update_spec::UpdateSpec};
+
+use super::document_update_field::DocumentUpdateField;
+
+pub const MODIFIED_BY: &str = "_modified_by";
+pub const MODIFIED_AT: &str = "_modified_at";
+pub const TOOL_VERSION: &str = "_tool_version";
+pub const JOB_ID: &str = "_job_id";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineagePolicy {
+    modified_by: String,
+    job_id: Option<String>
+}
+
+impl LineagePolicy {
+
+    pub fn new(modified_by: String) -> Self {
+        Self {
+            modified_by,
+            job_id: None
+        }
+    }
+
+    pub fn modified_by(&self) -> String {
+        self.modified_by.clone()
+    }
+
+    pub fn job_id(&self) -> Option<String> {
+        self.job_id.clone()
+    }
+
+    pub fn set_job_id(&mut self, job_id: String) -> &mut Self {
+        self.job_id = Some(job_id);
+        self
+    }
+
+    pub fn tool_version() -> String {
+        format!("{}/{}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+    }
+
+    pub fn fields(&self, now: u128) -> Map<String, Value> {
+        let mut fields = Map::new();
+        fields.insert(String::from(MODIFIED_BY), Value::String(self.modified_by()));
+        fields.insert(String::from(MODIFIED_AT), json!({ "$date": { "$numberLong": now.to_string() } }));
+        fields.insert(String::from(TOOL_VERSION), Value::String(Self::tool_version()));
+        if let Some(job_id) = &self.job_id {
+            fields.insert(String::from(JOB_ID), Value::String(job_id.clone()));
+        }
+        fields
+    }
+
+    pub fn stamp(&self, document: &str, now: u128) -> Result<String, ConnectException> {
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let Value::Object(mut object) = json.unwrap() else {
+            return Err(ConnectException::new(String::from("Document must be a JSON object.")));
+        };
+
+        object.remove(JOB_ID);
+        object.extend(self.fields(now));
+
+        Ok(Value::Object(object).to_string())
+    }
+
+    pub fn stamp_spec(&self, spec: &UpdateSpec, now: u128) -> UpdateSpec {
+        let mut stamped = spec.clone();
+        for (field, value) in self.fields(now) {
+            stamped.set(field, value);
+        }
+        if self.job_id.is_none() {
+            stamped.unset(String::from(JOB_ID));
+        }
+        stamped
+    }
+
+    pub fn stamp_fields(&self, fields: Vec<DocumentUpdateField>, now: u128) -> Vec<DocumentUpdateField> {
+        let lineage = self.fields(now);
+        let mut stamped: Vec<DocumentUpdateField> = fields.into_iter()
+            .filter(|f| !lineage.contains_key(&f.field()) && f.field() != JOB_ID)
+            .collect();
+        stamped.extend(lineage.into_iter().map(|(field, value)| DocumentUpdateField::set(field, value.to_string())));
+        if self.job_id.is_none() {
+            stamped.push(DocumentUpdateField::unset(String::from(JOB_ID)));
+        }
+        stamped
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::{json, Value};
+
+    use crate::domain::{document::document_update_field::DocumentUpdateField, update::update_spec::UpdateSpec};
+
+    use super::{LineagePolicy, JOB_ID, MODIFIED_AT, MODIFIED_BY, TOOL_VERSION};
+
+    #[test]
+    fn stamp_adds_provenance_and_drops_stale_job_ids() {
+        let lineage = LineagePolicy::new(String::from("dba"));
+
+        let stamped = lineage.stamp(r#"{"name":"ada","_job_id":"old"}"#, 7).unwrap();
+        let stamped: Value = serde_json::from_str(&stamped).unwrap();
+
+        assert_eq!(stamped, json!({
+            "name": "ada",
+            MODIFIED_BY: "dba",
+            MODIFIED_AT: {"$date": {"$numberLong": "7"}},
+            TOOL_VERSION: LineagePolicy::tool_version()
+        }));
+    }
+
+    #[test]
+    fn stamp_spec_sets_lineage_next_to_the_update() {
+        let mut lineage = LineagePolicy::new(String::from("dba"));
+        lineage.set_job_id(String::from("job-1"));
+
+        let mut spec = UpdateSpec::new();
+        spec.set(String::from("status"), json!("closed"));
+
+        let stamped = lineage.stamp_spec(&spec, 7).set_fields();
+        assert_eq!(stamped.get("status"), Some(&json!("closed")));
+        assert_eq!(stamped.get(MODIFIED_BY), Some(&json!("dba")));
+        assert_eq!(stamped.get(JOB_ID), Some(&json!("job-1")));
+    }
+
+    #[test]
+    fn stamp_fields_overrides_lineage_fields_and_unsets_job_id() {
+        let lineage = LineagePolicy::new(String::from("dba"));
+        let fields = vec![
+            DocumentUpdateField::set(String::from("status"), String::from("\"closed\"")),
+            DocumentUpdateField::set(String::from(MODIFIED_BY), String::from("\"someone\"")),
+        ];
+
+        let stamped = lineage.stamp_fields(fields, 7);
+        let by: Vec<Option<String>> = stamped.iter()
+            .filter(|f| f.field() == MODIFIED_BY)
+            .map(|f| f.value())
+            .collect();
+
+        assert_eq!(by, vec![Some(String::from("\"dba\""))]);
+        assert!(stamped.iter().any(|f| f.field() == "status"));
+        assert!(stamped.iter().any(|f| f.field() == JOB_ID && f.is_unset()));
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::e_merge_strategy::EMergeStrategy;
+
+#[derive(Debug, Clone)]
+pub struct DocumentMerge {
+    strategy: EMergeStrategy,
+    ours_timestamps: HashMap<String, i64>,
+    theirs_timestamps: HashMap<String, i64>
+}
+
+impl DocumentMerge {
+
+    pub fn prefer_ours() -> Self {
+        Self::new(EMergeStrategy::OURS, HashMap::new(), HashMap::new())
+    }
+
+    pub fn prefer_theirs() -> Self {
+        Self::new(EMergeStrategy::THEIRS, HashMap::new(), HashMap::new())
+    }
+
+    pub fn last_write_wins(ours_timestamps: HashMap<String, i64>, theirs_timestamps: HashMap<String, i64>) -> Self {
+        Self::new(EMergeStrategy::LASTWRITEWINS, ours_timestamps, theirs_timestamps)
+    }
+
+    pub fn new(strategy: EMergeStrategy, ours_timestamps: HashMap<String, i64>, theirs_timestamps: HashMap<String, i64>) -> Self {
+        Self {
+            strategy, ours_timestamps, theirs_timestamps
+        }
+    }
+
+    pub fn strategy(&self) -> EMergeStrategy {
+        self.strategy.clone()
+    }
+
+    pub fn merge(&self, ours: &str, theirs: &str) -> Result<String, ConnectException> {
+        let ours = Self::parse(ours)?;
+        let theirs = Self::parse(theirs)?;
+        Ok(self.merge_value(ours, theirs).to_string())
+    }
+
+    pub fn merge_value(&self, ours: Value, theirs: Value) -> Value {
+        self.merge_path("", ours, theirs)
+    }
+
+    fn parse(document: &str) -> Result<Value, ConnectException> {
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        Ok(json.unwrap())
+    }
+
+    fn merge_path(&self, path: &str, ours: Value, theirs: Value) -> Value {
+        match (ours, theirs) {
+            (Value::Object(ours), Value::Object(theirs)) => Value::Object(self.merge_object(path, ours, theirs)),
+            (ours, theirs) => {
+                if self.is_ours(path) {
+                    ours
+                } else {
+                    theirs
+                }
+            }
+        }
+    }
+
+    fn merge_object(&self, path: &str, mut ours: Map<String, Value>, mut theirs: Map<String, Value>) -> Map<String, Value> {
+        let mut result = Map::new();
+
+        let mut keys: Vec<String> = ours.keys().cloned().collect();
+        keys.extend(theirs.keys().filter(|k| !ours.contains_key(*k)).cloned());
+
+        for key in keys {
+            let child = if path.is_empty() {
+                key.clone()
+            } else {
+                format!("{}.{}", path, key)
+            };
+
+            let value = match (ours.remove(&key), theirs.remove(&key)) {
+                (Some(ours), Some(theirs)) => self.merge_path(&child, ours, theirs),
+                (Some(ours), None) => ours,
+                (None, Some(theirs)) => theirs,
+                (None, None) => continue,
+            };
+
+            result.insert(key, value);
+        }
+
+        result
+    }
+
+    fn is_ours(&self, path: &str) -> bool {
+        match self.strategy {
+            EMergeStrategy::OURS => true,
+            EMergeStrategy::THEIRS => false,
+            EMergeStrategy::LASTWRITEWINS => {
+                let ours = Self::timestamp(&self.ours_timestamps, path);
+                let theirs = Self::timestamp(&self.theirs_timestamps, path);
+                ours >= theirs
+            },
+        }
+    }
+
+    fn timestamp(timestamps: &HashMap<String, i64>, path: &str) -> i64 {
+        let mut current = path;
+        loop {
+            if let Some(timestamp) = timestamps.get(current) {
+                return *timestamp;
+            }
+            match current.rfind('.') {
+                Some(index) => current = &current[..index],
+                None => return timestamps.get("").copied().unwrap_or_default(),
+            }
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::collections::HashMap;
+
+    use serde_json::json;
+
+    use super::DocumentMerge;
+
+    #[test]
+    fn nested_documents_merge_per_key() {
+        let ours = json!({ "a": { "b": 1, "c": 2 }, "x": 1 });
+        let theirs = json!({ "a": { "b": 10, "d": 4 }, "y": 2 });
+
+        let merged = DocumentMerge::prefer_theirs().merge_value(ours, theirs);
+
+        assert_eq!(merged, json!({ "a": { "b": 10, "c": 2, "d": 4 }, "x": 1, "y": 2 }));
+    }
+
+    #[test]
+    fn arrays_are_replaced_not_merged() {
+        let ours = json!({ "tags": ["a", "b", "c"] });
+        let theirs = json!({ "tags": ["z"] });
+
+        assert_eq!(DocumentMerge::prefer_theirs().merge_value(ours.clone(), theirs.clone()), json!({ "tags": ["z"] }));
+        assert_eq!(DocumentMerge::prefer_ours().merge_value(ours, theirs), json!({ "tags": ["a", "b", "c"] }));
+    }
+
+    #[test]
+    fn null_is_kept_as_a_value_and_missing_keys_are_preserved() {
+        let ours = json!({ "a": null, "b": 1, "only_ours": true });
+        let theirs = json!({ "a": 5, "b": null });
+
+        let merged = DocumentMerge::prefer_ours().merge_value(ours.clone(), theirs.clone());
+        assert_eq!(merged, json!({ "a": null, "b": 1, "only_ours": true }));
+
+        let merged = DocumentMerge::prefer_theirs().merge_value(ours, theirs);
+        assert_eq!(merged, json!({ "a": 5, "b": null, "only_ours": true }));
+    }
+
+    #[test]
+    fn scalar_and_document_conflict_follows_strategy() {
+        let ours = json!({ "a": 1 });
+        let theirs = json!({ "a": { "b": 2 } });
+
+        assert_eq!(DocumentMerge::prefer_ours().merge_value(ours.clone(), theirs.clone()), json!({ "a": 1 }));
+        assert_eq!(DocumentMerge::prefer_theirs().merge_value(ours, theirs), json!({ "a": { "b": 2 } }));
+    }
+
+    #[test]
+    fn last_write_wins_uses_the_closest_timestamp() {
+        let ours_timestamps = HashMap::from([(String::from("a"), 10), (String::from("a.c"), 1)]);
+        let theirs_timestamps = HashMap::from([(String::from("a"), 5), (String::from("a.c"), 20)]);
+
+        let ours = json!({ "a": { "b": 1, "c": 1 } });
+        let theirs = json!({ "a": { "b": 2, "c": 2 } });
+
+        let merged = DocumentMerge::last_write_wins(ours_timestamps, theirs_timestamps).merge_value(ours, theirs);
+
+        assert_eq!(merged, json!({ "a": { "b": 1, "c": 2 } }));
+    }
+
+    #[test]
+    fn invalid_json_is_rejected() {
+        assert!(DocumentMerge::prefer_ours().merge("{", "{}").is_err());
+    }
+
+}
\ No newline at end of file
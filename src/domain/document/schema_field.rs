@@ -0,0 +1,42 @@
+#[derive(Debug, Clone)]
+pub struct SchemaField {
+    path: String,
+    types: Vec<String>,
+    occurrences: usize,
+    optional: bool,
+    examples: Vec<String>
+}
+
+impl SchemaField {
+
+    pub fn new(path: String, types: Vec<String>, occurrences: usize, optional: bool, examples: Vec<String>) -> Self {
+        Self {
+            path, types, occurrences, optional, examples
+        }
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn types(&self) -> Vec<String> {
+        self.types.clone()
+    }
+
+    pub fn occurrences(&self) -> usize {
+        self.occurrences
+    }
+
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+
+    pub fn is_mixed(&self) -> bool {
+        self.types.iter().filter(|t| t.as_str() != "null").count() > 1
+    }
+
+    pub fn examples(&self) -> Vec<String> {
+        self.examples.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,147 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{document_schema::DocumentSchema, schema_version::SchemaVersion};
+
+pub const SCHEMA_FIELD: &str = "_schema";
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaRegistry {
+    schemas: HashMap<(String, String), Vec<SchemaVersion>>
+}
+
+impl SchemaRegistry {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(data_base: &str, collection: &str) -> (String, String) {
+        (String::from(data_base), String::from(collection))
+    }
+
+    pub fn register(&mut self, data_base: &str, collection: &str, schema: DocumentSchema, registered_at: u128) -> u32 {
+        let versions = self.schemas.entry(Self::key(data_base, collection)).or_default();
+        let version = versions.last().map(|v| v.version() + 1).unwrap_or(1);
+        versions.push(SchemaVersion::new(version, schema, registered_at));
+        version
+    }
+
+    pub fn restore(&mut self, data_base: &str, collection: &str, version: SchemaVersion) -> Result<&mut Self, ConnectException> {
+        let versions = self.schemas.entry(Self::key(data_base, collection)).or_default();
+        if versions.iter().any(|v| v.version() == version.version()) {
+            let message = format!("Schema version {} for '{}.{}' is registered twice.", version.version(), data_base, collection);
+            return Err(ConnectException::new(message));
+        }
+
+        versions.push(version);
+        versions.sort_by_key(|v| v.version());
+        Ok(self)
+    }
+
+    pub fn remove(&mut self, data_base: &str, collection: &str) -> Vec<SchemaVersion> {
+        self.schemas.remove(&Self::key(data_base, collection)).unwrap_or_default()
+    }
+
+    pub fn collections(&self) -> Vec<(String, String)> {
+        let mut collections: Vec<(String, String)> = self.schemas.keys().cloned().collect();
+        collections.sort();
+        collections
+    }
+
+    pub fn versions(&self, data_base: &str, collection: &str) -> Vec<SchemaVersion> {
+        self.schemas.get(&Self::key(data_base, collection)).cloned().unwrap_or_default()
+    }
+
+    pub fn version(&self, data_base: &str, collection: &str, version: u32) -> Option<SchemaVersion> {
+        self.schemas.get(&Self::key(data_base, collection))
+            .and_then(|versions| versions.iter().find(|v| v.version() == version).cloned())
+    }
+
+    pub fn latest(&self, data_base: &str, collection: &str) -> Option<SchemaVersion> {
+        self.schemas.get(&Self::key(data_base, collection))
+            .and_then(|versions| versions.last().cloned())
+    }
+
+    pub fn claim(&self, data_base: &str, collection: &str, document: &str) -> Result<String, ConnectException> {
+        let Some(latest) = self.latest(data_base, collection) else {
+            return Ok(String::from(document));
+        };
+
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let mut object: Map<String, Value> = match json.unwrap() {
+            Value::Object(object) => object,
+            _ => return Err(ConnectException::new(String::from("Document must be a JSON object."))),
+        };
+
+        if object.contains_key(SCHEMA_FIELD) {
+            return Ok(String::from(document));
+        }
+
+        object.insert(String::from(SCHEMA_FIELD), Value::from(latest.version()));
+
+        Ok(Value::Object(object).to_string())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::{json, Value};
+
+    use crate::domain::document::document_schema::DocumentSchema;
+
+    use super::SchemaRegistry;
+
+    fn schema() -> DocumentSchema {
+        DocumentSchema::new(Vec::new(), false, Vec::new())
+    }
+
+    #[test]
+    fn versions_increase_per_collection() {
+        let mut registry = SchemaRegistry::new();
+
+        assert_eq!(registry.register("shop", "orders", schema(), 10), 1);
+        assert_eq!(registry.register("shop", "orders", schema(), 20), 2);
+        assert_eq!(registry.register("shop", "customers", schema(), 30), 1);
+
+        assert_eq!(registry.latest("shop", "orders").map(|v| v.registered_at()), Some(20));
+        assert!(registry.version("shop", "orders", 3).is_none());
+    }
+
+    #[test]
+    fn restore_rejects_duplicated_versions() {
+        let mut registry = SchemaRegistry::new();
+        registry.register("shop", "orders", schema(), 10);
+
+        let version = registry.version("shop", "orders", 1).unwrap();
+        assert!(registry.restore("shop", "orders", version).is_err());
+    }
+
+    #[test]
+    fn claim_stamps_the_latest_version() {
+        let mut registry = SchemaRegistry::new();
+
+        let document = r#"{"name":"ada"}"#;
+        assert_eq!(registry.claim("shop", "orders", document).unwrap(), document);
+
+        registry.register("shop", "orders", schema(), 10);
+        registry.register("shop", "orders", schema(), 20);
+
+        let claimed: Value = serde_json::from_str(&registry.claim("shop", "orders", document).unwrap()).unwrap();
+        assert_eq!(claimed, json!({"name": "ada", "_schema": 2}));
+
+        let pinned = r#"{"name":"ada","_schema":1}"#;
+        assert_eq!(registry.claim("shop", "orders", pinned).unwrap(), pinned);
+    }
+
+}
\ No newline at end of file
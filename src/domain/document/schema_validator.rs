@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{document_schema::DocumentSchema, schema_violation::SchemaViolation};
+
+#[derive(Debug, Clone, Default)]
+pub struct SchemaValidator {
+    schemas: HashMap<String, DocumentSchema>
+}
+
+impl SchemaValidator {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn key(data_base: &str, collection: &str) -> String {
+        format!("{}.{}", data_base, collection)
+    }
+
+    pub fn register(&mut self, data_base: &str, collection: &str, schema: DocumentSchema) -> Result<&mut Self, ConnectException> {
+        if schema.validation().map(|v| !v.is_object()).unwrap_or(true) {
+            let message = format!("Schema for '{}' has no JSON Schema object to validate with.", Self::key(data_base, collection));
+            return Err(ConnectException::new(message));
+        }
+        self.schemas.insert(Self::key(data_base, collection), schema);
+        Ok(self)
+    }
+
+    pub fn remove(&mut self, data_base: &str, collection: &str) -> Option<DocumentSchema> {
+        self.schemas.remove(&Self::key(data_base, collection))
+    }
+
+    pub fn schema(&self, data_base: &str, collection: &str) -> Option<DocumentSchema> {
+        self.schemas.get(&Self::key(data_base, collection)).cloned()
+    }
+
+    pub fn validate(&self, data_base: &str, collection: &str, document: &str) -> Result<Vec<SchemaViolation>, ConnectException> {
+        let Some(validation) = self.schemas.get(&Self::key(data_base, collection)).and_then(|s| s.validation()) else {
+            return Ok(Vec::new());
+        };
+
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let mut violations = Vec::new();
+        Self::validate_value(&validation, &json.unwrap(), "", &mut violations);
+
+        Ok(violations)
+    }
+
+    fn validate_value(schema: &Value, value: &Value, path: &str, violations: &mut Vec<SchemaViolation>) {
+        let Some(schema) = schema.as_object() else {
+            return;
+        };
+
+        if let Some(expected) = schema.get("type").or_else(|| schema.get("bsonType")) {
+            let types: Vec<&str> = match expected {
+                Value::String(code) => vec![code.as_str()],
+                Value::Array(codes) => codes.iter().filter_map(|c| c.as_str()).collect(),
+                _ => Vec::new(),
+            };
+            if !types.is_empty() && !types.iter().any(|t| Self::matches_type(t, value)) {
+                let message = format!("expected {}, found {}", types.join(" or "), Self::type_name(value));
+                violations.push(SchemaViolation::new(String::from(path), String::from("type"), message));
+                return;
+            }
+        }
+
+        if let Some(Value::Array(options)) = schema.get("enum") {
+            if !options.contains(value) {
+                violations.push(SchemaViolation::new(String::from(path), String::from("enum"), String::from("value is not one of the allowed values")));
+            }
+        }
+
+        if let Some(constant) = schema.get("const") {
+            if constant != value {
+                violations.push(SchemaViolation::new(String::from(path), String::from("const"), format!("expected {}", constant)));
+            }
+        }
+
+        match value {
+            Value::Object(object) if !Self::is_extended(object) => Self::validate_object(schema, object, path, violations),
+            Value::Array(items) => Self::validate_array(schema, items, path, violations),
+            Value::String(text) => Self::validate_string(schema, text, path, violations),
+            Value::Number(number) => Self::validate_number(schema, number.as_f64().unwrap_or_default(), path, violations),
+            _ => {},
+        }
+    }
+
+    fn validate_object(schema: &Map<String, Value>, object: &Map<String, Value>, path: &str, violations: &mut Vec<SchemaViolation>) {
+        if let Some(Value::Array(required)) = schema.get("required") {
+            for field in required.iter().filter_map(|r| r.as_str()) {
+                if !object.contains_key(field) {
+                    violations.push(SchemaViolation::new(Self::child(path, field), String::from("required"), String::from("field is required")));
+                }
+            }
+        }
+
+        let properties = schema.get("properties").and_then(|p| p.as_object());
+        for (field, value) in object {
+            let child = Self::child(path, field);
+            match properties.and_then(|p| p.get(field)) {
+                Some(property) => Self::validate_value(property, value, &child, violations),
+                None => match schema.get("additionalProperties") {
+                    Some(Value::Bool(false)) => {
+                        violations.push(SchemaViolation::new(child, String::from("additionalProperties"), String::from("field is not allowed")));
+                    },
+                    Some(additional) if additional.is_object() => Self::validate_value(additional, value, &child, violations),
+                    _ => {},
+                },
+            }
+        }
+    }
+
+    fn validate_array(schema: &Map<String, Value>, items: &[Value], path: &str, violations: &mut Vec<SchemaViolation>) {
+        if let Some(min) = schema.get("minItems").and_then(|m| m.as_u64()) {
+            if (items.len() as u64) < min {
+                violations.push(SchemaViolation::new(String::from(path), String::from("minItems"), format!("expected at least {} items, found {}", min, items.len())));
+            }
+        }
+
+        if let Some(max) = schema.get("maxItems").and_then(|m| m.as_u64()) {
+            if (items.len() as u64) > max {
+                violations.push(SchemaViolation::new(String::from(path), String::from("maxItems"), format!("expected at most {} items, found {}", max, items.len())));
+            }
+        }
+
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                Self::validate_value(item_schema, item, &Self::child(path, &index.to_string()), violations);
+            }
+        }
+    }
+
+    fn validate_string(schema: &Map<String, Value>, text: &str, path: &str, violations: &mut Vec<SchemaViolation>) {
+        let length = text.chars().count() as u64;
+
+        if let Some(min) = schema.get("minLength").and_then(|m| m.as_u64()) {
+            if length < min {
+                violations.push(SchemaViolation::new(String::from(path), String::from("minLength"), format!("expected at least {} characters, found {}", min, length)));
+            }
+        }
+
+        if let Some(max) = schema.get("maxLength").and_then(|m| m.as_u64()) {
+            if length > max {
+                violations.push(SchemaViolation::new(String::from(path), String::from("maxLength"), format!("expected at most {} characters, found {}", max, length)));
+            }
+        }
+    }
+
+    fn validate_number(schema: &Map<String, Value>, number: f64, path: &str, violations: &mut Vec<SchemaViolation>) {
+        if let Some(minimum) = schema.get("minimum").and_then(|m| m.as_f64()) {
+            let exclusive = schema.get("exclusiveMinimum") == Some(&Value::Bool(true));
+            if number < minimum || (exclusive && number == minimum) {
+                violations.push(SchemaViolation::new(String::from(path), String::from("minimum"), format!("value {} is below the minimum {}", number, minimum)));
+            }
+        }
+
+        if let Some(maximum) = schema.get("maximum").and_then(|m| m.as_f64()) {
+            let exclusive = schema.get("exclusiveMaximum") == Some(&Value::Bool(true));
+            if number > maximum || (exclusive && number == maximum) {
+                violations.push(SchemaViolation::new(String::from(path), String::from("maximum"), format!("value {} is above the maximum {}", number, maximum)));
+            }
+        }
+    }
+
+    fn matches_type(code: &str, value: &Value) -> bool {
+        match code {
+            "object" => value.as_object().map(|o| !Self::is_extended(o)).unwrap_or(false),
+            "array" => value.is_array(),
+            "string" => value.is_string(),
+            "boolean" | "bool" => value.is_boolean(),
+            "null" => value.is_null(),
+            "number" => value.is_number() || Self::is_extended_type(value, &["$numberDouble", "$numberDecimal", "$numberInt", "$numberLong"]),
+            "integer" | "int" | "long" => value.is_i64() || value.is_u64() || Self::is_extended_type(value, &["$numberInt", "$numberLong"]),
+            "double" => value.is_f64() || Self::is_extended_type(value, &["$numberDouble"]),
+            "decimal" => Self::is_extended_type(value, &["$numberDecimal"]),
+            "objectId" => Self::is_extended_type(value, &["$oid"]),
+            "date" => Self::is_extended_type(value, &["$date"]),
+            _ => true,
+        }
+    }
+
+    fn type_name(value: &Value) -> String {
+        let name = match value {
+            Value::Null => "null",
+            Value::Bool(_) => "boolean",
+            Value::Number(number) if number.is_f64() => "number",
+            Value::Number(_) => "integer",
+            Value::String(_) => "string",
+            Value::Array(_) => "array",
+            Value::Object(object) => match object.keys().next().map(|k| k.as_str()) {
+                Some("$oid") if Self::is_extended(object) => "objectId",
+                Some("$date") if Self::is_extended(object) => "date",
+                _ => "object",
+            },
+        };
+        String::from(name)
+    }
+
+    fn is_extended(object: &Map<String, Value>) -> bool {
+        object.len() == 1 && object.keys().all(|k| k.starts_with('$'))
+    }
+
+    fn is_extended_type(value: &Value, keys: &[&str]) -> bool {
+        match value.as_object() {
+            Some(object) => Self::is_extended(object) && keys.iter().any(|k| object.contains_key(*k)),
+            None => false,
+        }
+    }
+
+    fn child(path: &str, field: &str) -> String {
+        if path.is_empty() {
+            return String::from(field);
+        }
+        format!("{}.{}", path, field)
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,30 @@
+use super::document_schema::DocumentSchema;
+
+#[derive(Debug, Clone)]
+pub struct SchemaVersion {
+    version: u32,
+    schema: DocumentSchema,
+    registered_at: u128
+}
+
+impl SchemaVersion {
+
+    pub fn new(version: u32, schema: DocumentSchema, registered_at: u128) -> Self {
+        Self {
+            version, schema, registered_at
+        }
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn schema(&self) -> DocumentSchema {
+        self.schema.clone()
+    }
+
+    pub fn registered_at(&self) -> u128 {
+        self.registered_at
+    }
+
+}
\ No newline at end of file
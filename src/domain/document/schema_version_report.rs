@@ -0,0 +1,134 @@
+use serde_json::Value;
+
+#[derive(Debug, Clone)]
+pub struct SchemaVersionReport {
+    data_base: String,
+    collection: String,
+    latest: Option<u32>,
+    versions: Vec<(u32, usize)>,
+    unversioned: usize,
+    unknown: Vec<(String, usize)>
+}
+
+impl SchemaVersionReport {
+
+    /// Classifies the `_schema` claims counted in a collection. Claims on a
+    /// registered version are reported per version (including versions with
+    /// no documents), missing claims as unversioned and anything else as unknown.
+    pub fn from_claims(data_base: String, collection: String, registered: &[u32], claims: Vec<(Value, usize)>) -> Self {
+        let mut versions: Vec<(u32, usize)> = registered.iter().map(|v| (*v, 0)).collect();
+        let mut unversioned = 0;
+        let mut unknown = Vec::new();
+
+        for (claim, count) in claims {
+            if claim.is_null() {
+                unversioned += count;
+                continue;
+            }
+
+            let entry = Self::parse_version(&claim)
+                .and_then(|v| versions.iter_mut().find(|(version, _)| *version == v));
+            match entry {
+                Some((_, total)) => *total += count,
+                None => unknown.push((claim.to_string(), count)),
+            }
+        }
+
+        versions.sort_by_key(|(version, _)| *version);
+
+        Self {
+            data_base, collection,
+            latest: registered.iter().max().cloned(),
+            versions, unversioned, unknown
+        }
+    }
+
+    pub fn parse_version(value: &Value) -> Option<u32> {
+        if let Some(version) = value.as_u64() {
+            return u32::try_from(version).ok();
+        }
+
+        let object = value.as_object()?;
+        let number = object.get("$numberInt").or_else(|| object.get("$numberLong"))?;
+        number.as_str().and_then(|n| n.parse().ok())
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn latest(&self) -> Option<u32> {
+        self.latest
+    }
+
+    pub fn versions(&self) -> Vec<(u32, usize)> {
+        self.versions.clone()
+    }
+
+    pub fn unversioned(&self) -> usize {
+        self.unversioned
+    }
+
+    pub fn unknown(&self) -> Vec<(String, usize)> {
+        self.unknown.clone()
+    }
+
+    pub fn total(&self) -> usize {
+        let versioned: usize = self.versions.iter().map(|(_, count)| count).sum();
+        let unknown: usize = self.unknown.iter().map(|(_, count)| count).sum();
+        versioned + unknown + self.unversioned
+    }
+
+    pub fn outdated(&self) -> usize {
+        let Some(latest) = self.latest else {
+            return 0;
+        };
+        self.versions.iter()
+            .filter(|(version, _)| *version < latest)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::{json, Value};
+
+    use super::SchemaVersionReport;
+
+    #[test]
+    fn claims_are_classified_by_registered_version() {
+        let claims = vec![
+            (json!(1), 4),
+            (json!({"$numberLong": "2"}), 6),
+            (Value::Null, 3),
+            (json!(9), 1),
+            (json!("v2"), 2),
+        ];
+
+        let report = SchemaVersionReport::from_claims(String::from("shop"), String::from("orders"), &[1, 2, 3], claims);
+
+        assert_eq!(report.latest(), Some(3));
+        assert_eq!(report.versions(), vec![(1, 4), (2, 6), (3, 0)]);
+        assert_eq!(report.unversioned(), 3);
+        assert_eq!(report.unknown(), vec![(String::from("9"), 1), (String::from("\"v2\""), 2)]);
+        assert_eq!(report.total(), 16);
+        assert_eq!(report.outdated(), 10);
+    }
+
+    #[test]
+    fn unregistered_collections_have_no_outdated_documents() {
+        let report = SchemaVersionReport::from_claims(String::from("shop"), String::from("orders"), &[], vec![(Value::Null, 5)]);
+
+        assert_eq!(report.latest(), None);
+        assert_eq!(report.unversioned(), 5);
+        assert_eq!(report.outdated(), 0);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+#[derive(Debug, Clone)]
+pub struct SchemaViolation {
+    path: String,
+    keyword: String,
+    message: String
+}
+
+impl SchemaViolation {
+
+    pub fn new(path: String, keyword: String, message: String) -> Self {
+        Self {
+            path, keyword, message
+        }
+    }
+
+    pub fn path(&self) -> String {
+        self.path.clone()
+    }
+
+    pub fn keyword(&self) -> String {
+        self.keyword.clone()
+    }
+
+    pub fn message(&self) -> String {
+        self.message.clone()
+    }
+
+    pub fn describe(&self) -> String {
+        let path = if self.path.is_empty() { "<root>" } else { &self.path };
+        format!("{}: {}", path, self.message)
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,134 @@
+use serde_json::json;
+
+use crate::domain::{filter::filter_element::FilterElement, update::update_spec::UpdateSpec};
+
+pub const DEFAULT_FIELD: &str = "deleted_at";
+pub const DEFAULT_RETENTION: u64 = 30 * 24 * 60 * 60 * 1000;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SoftDeletePolicy {
+    field: String,
+    retention: u64
+}
+
+impl SoftDeletePolicy {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from(field: String, retention: u64) -> Self {
+        Self {
+            field, retention
+        }
+    }
+
+    pub fn field(&self) -> String {
+        self.field.clone()
+    }
+
+    pub fn retention(&self) -> u64 {
+        self.retention
+    }
+
+    pub fn set_field(&mut self, field: String) -> &mut Self {
+        self.field = field;
+        self
+    }
+
+    pub fn set_retention(&mut self, retention: u64) -> &mut Self {
+        self.retention = retention;
+        self
+    }
+
+    pub fn active_filter(&self, filter: Option<FilterElement>) -> FilterElement {
+        self.scoped_filter(filter, FilterElement::exists(self.field(), false))
+    }
+
+    pub fn deleted_filter(&self, filter: Option<FilterElement>) -> FilterElement {
+        self.scoped_filter(filter, FilterElement::exists(self.field(), true))
+    }
+
+    pub fn purge_filter(&self, now: u128) -> FilterElement {
+        let cutoff = now.saturating_sub(self.retention as u128);
+        let stage = json!({
+            "$match": {
+                self.field.clone(): { "$lt": { "$date": { "$numberLong": cutoff.to_string() } } }
+            }
+        });
+        FilterElement::query(stage.to_string(), Vec::new())
+    }
+
+    pub fn deletion(&self, now: u128) -> UpdateSpec {
+        let mut spec = UpdateSpec::new();
+        spec.set(self.field(), json!({ "$date": { "$numberLong": now.to_string() } }));
+        spec
+    }
+
+    pub fn restoration(&self) -> UpdateSpec {
+        let mut spec = UpdateSpec::new();
+        spec.unset(self.field());
+        spec
+    }
+
+    fn scoped_filter(&self, filter: Option<FilterElement>, condition: FilterElement) -> FilterElement {
+        let mut scoped = FilterElement::new();
+        scoped.push(condition);
+        if let Some(filter) = filter {
+            scoped.push(filter);
+        }
+        scoped
+    }
+
+}
+
+impl Default for SoftDeletePolicy {
+
+    fn default() -> Self {
+        Self::from(String::from(DEFAULT_FIELD), DEFAULT_RETENTION)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use mongodb::bson::{doc, DateTime};
+    use serde_json::json;
+
+    use crate::domain::filter::filter_element::FilterElement;
+
+    use super::{SoftDeletePolicy, DEFAULT_FIELD};
+
+    #[test]
+    fn deletion_stamps_and_restoration_clears_the_field() {
+        let policy = SoftDeletePolicy::from(String::from("removed"), 1000);
+
+        let deletion = policy.deletion(42);
+        assert_eq!(deletion.set_fields().get("removed"), Some(&json!({"$date": {"$numberLong": "42"}})));
+
+        assert_eq!(policy.restoration().unset_fields(), vec![String::from("removed")]);
+    }
+
+    #[test]
+    fn active_filter_hides_deleted_documents() {
+        let policy = SoftDeletePolicy::new();
+        let user = FilterElement::string(String::from("status"), String::from("open"), Vec::new());
+
+        let pipeline = policy.active_filter(Some(user)).as_mongo_agregate().unwrap();
+        assert_eq!(pipeline, vec![doc! {
+            "$match": {"$and": [{"status": "open"}, {DEFAULT_FIELD: {"$exists": false}}]}
+        }]);
+    }
+
+    #[test]
+    fn purge_filter_honours_the_retention_window() {
+        let policy = SoftDeletePolicy::from(String::from(DEFAULT_FIELD), 1000);
+
+        let pipeline = policy.purge_filter(5000).as_mongo_agregate().unwrap();
+        assert_eq!(pipeline, vec![doc! {
+            "$match": {DEFAULT_FIELD: {"$lt": DateTime::from_millis(4000)}}
+        }]);
+    }
+
+}
\ No newline at end of file
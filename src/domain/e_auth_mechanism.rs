@@ -0,0 +1,30 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EAuthMechanism {
+    SCRAMSHA1,
+    SCRAMSHA256,
+    X509,
+    PLAIN
+}
+
+impl EAuthMechanism {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EAuthMechanism::SCRAMSHA1 => String::from("SCRAMSHA1"),
+            EAuthMechanism::SCRAMSHA256 => String::from("SCRAMSHA256"),
+            EAuthMechanism::X509 => String::from("X509"),
+            EAuthMechanism::PLAIN => String::from("PLAIN"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EAuthMechanism> {
+        match code {
+            "SCRAMSHA1" => Some(EAuthMechanism::SCRAMSHA1),
+            "SCRAMSHA256" => Some(EAuthMechanism::SCRAMSHA256),
+            "X509" => Some(EAuthMechanism::X509),
+            "PLAIN" => Some(EAuthMechanism::PLAIN),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,33 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EReadPreference {
+    PRIMARY,
+    PRIMARYPREFERRED,
+    SECONDARY,
+    SECONDARYPREFERRED,
+    NEAREST
+}
+
+impl EReadPreference {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EReadPreference::PRIMARY => String::from("PRIMARY"),
+            EReadPreference::PRIMARYPREFERRED => String::from("PRIMARYPREFERRED"),
+            EReadPreference::SECONDARY => String::from("SECONDARY"),
+            EReadPreference::SECONDARYPREFERRED => String::from("SECONDARYPREFERRED"),
+            EReadPreference::NEAREST => String::from("NEAREST"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EReadPreference> {
+        match code {
+            "PRIMARY" => Some(EReadPreference::PRIMARY),
+            "PRIMARYPREFERRED" => Some(EReadPreference::PRIMARYPREFERRED),
+            "SECONDARY" => Some(EReadPreference::SECONDARY),
+            "SECONDARYPREFERRED" => Some(EReadPreference::SECONDARYPREFERRED),
+            "NEAREST" => Some(EReadPreference::NEAREST),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum EEnvironmentAction {
+    READ,
+    WRITE,
+    SEED,
+    DESTRUCTIVE
+}
+
+impl EEnvironmentAction {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EEnvironmentAction::READ => String::from("READ"),
+            EEnvironmentAction::WRITE => String::from("WRITE"),
+            EEnvironmentAction::SEED => String::from("SEED"),
+            EEnvironmentAction::DESTRUCTIVE => String::from("DESTRUCTIVE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EEnvironmentAction> {
+        match code {
+            "READ" => Some(EEnvironmentAction::READ),
+            "WRITE" => Some(EEnvironmentAction::WRITE),
+            "SEED" => Some(EEnvironmentAction::SEED),
+            "DESTRUCTIVE" => Some(EEnvironmentAction::DESTRUCTIVE),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
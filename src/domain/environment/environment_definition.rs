@@ -0,0 +1,122 @@
+use crate::{commons::exception::connect_exception::ConnectException, domain::mapping::document_mapping::DocumentMapping};
+
+use super::{e_environment_action::EEnvironmentAction, environment_policy::EnvironmentPolicy};
+
+#[derive(Debug, Clone)]
+pub struct Environment {
+    name: String,
+    connections: Vec<String>,
+    policy: EnvironmentPolicy,
+    tier: usize,
+    masking: Option<DocumentMapping>
+}
+
+impl Environment {
+
+    pub fn new(name: String, policy: EnvironmentPolicy) -> Environment {
+        Environment {
+            name,
+            connections: Vec::new(),
+            policy,
+            tier: 0,
+            masking: None
+        }
+    }
+
+    pub fn development() -> Environment {
+        Environment::new(String::from("dev"), EnvironmentPolicy::development())
+    }
+
+    pub fn staging() -> Environment {
+        let mut environment = Environment::new(String::from("staging"), EnvironmentPolicy::staging());
+        environment.tier = 1;
+        return environment;
+    }
+
+    pub fn production() -> Environment {
+        let mut environment = Environment::new(String::from("prod"), EnvironmentPolicy::production());
+        environment.tier = 2;
+        return environment;
+    }
+
+    pub fn name(&self) -> String {
+        return self.name.clone();
+    }
+
+    pub fn connections(&self) -> Vec<String> {
+        return self.connections.clone();
+    }
+
+    pub fn policy(&self) -> EnvironmentPolicy {
+        return self.policy.clone();
+    }
+
+    pub fn tier(&self) -> usize {
+        return self.tier;
+    }
+
+    pub fn masking(&self) -> Option<DocumentMapping> {
+        return self.masking.clone();
+    }
+
+    pub fn set_policy(&mut self, policy: EnvironmentPolicy) -> &mut Environment {
+        self.policy = policy;
+        return self;
+    }
+
+    pub fn set_tier(&mut self, tier: usize) -> &mut Environment {
+        self.tier = tier;
+        return self;
+    }
+
+    pub fn set_masking(&mut self, masking: DocumentMapping) -> &mut Environment {
+        self.masking = Some(masking);
+        return self;
+    }
+
+    pub fn is_lower_than(&self, other: &Environment) -> bool {
+        return self.tier < other.tier;
+    }
+
+    pub fn add_connection(&mut self, alias: String) -> &mut Environment {
+        if !self.connections.contains(&alias) {
+            self.connections.push(alias);
+        }
+        return self;
+    }
+
+    pub fn remove_connection(&mut self, alias: &str) -> &mut Environment {
+        self.connections.retain(|c| c != alias);
+        return self;
+    }
+
+    pub fn contains(&self, alias: &str) -> bool {
+        return self.connections.iter().any(|c| c == alias);
+    }
+
+    pub fn authorize(&self, action: EEnvironmentAction, confirmation: Option<&str>, force: bool) -> Result<(), ConnectException> {
+        if action == EEnvironmentAction::READ {
+            return Ok(());
+        }
+
+        if action == EEnvironmentAction::SEED && !self.policy.allows_seeding() {
+            return Err(ConnectException::new(format!("Seeding is not allowed in environment '{}'.", self.name)));
+        }
+
+        if self.policy.is_read_only() && !force {
+            return Err(ConnectException::new(format!("Environment '{}' is read-only, force is required to write.", self.name)));
+        }
+
+        if action == EEnvironmentAction::DESTRUCTIVE && self.policy.requires_confirmation() {
+            if confirmation != Some(self.name.as_str()) {
+                return Err(ConnectException::new(format!("Destructive actions in '{}' require the environment name as confirmation.", self.name)));
+            }
+            if !force {
+                return Err(ConnectException::new(format!("Destructive actions in '{}' require force.", self.name)));
+            }
+        }
+
+        return Ok(());
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,57 @@
+#[derive(Debug, Clone)]
+pub struct EnvironmentPolicy {
+    read_only: bool,
+    allow_seeding: bool,
+    require_confirmation: bool
+}
+
+impl EnvironmentPolicy {
+
+    pub fn new(read_only: bool, allow_seeding: bool, require_confirmation: bool) -> EnvironmentPolicy {
+        EnvironmentPolicy {
+            read_only,
+            allow_seeding,
+            require_confirmation
+        }
+    }
+
+    pub fn development() -> EnvironmentPolicy {
+        EnvironmentPolicy::new(false, true, false)
+    }
+
+    pub fn staging() -> EnvironmentPolicy {
+        EnvironmentPolicy::new(false, true, true)
+    }
+
+    pub fn production() -> EnvironmentPolicy {
+        EnvironmentPolicy::new(true, false, true)
+    }
+
+    pub fn is_read_only(&self) -> bool {
+        return self.read_only;
+    }
+
+    pub fn allows_seeding(&self) -> bool {
+        return self.allow_seeding;
+    }
+
+    pub fn requires_confirmation(&self) -> bool {
+        return self.require_confirmation;
+    }
+
+    pub fn set_read_only(&mut self, read_only: bool) -> &mut EnvironmentPolicy {
+        self.read_only = read_only;
+        return self;
+    }
+
+    pub fn set_allow_seeding(&mut self, allow_seeding: bool) -> &mut EnvironmentPolicy {
+        self.allow_seeding = allow_seeding;
+        return self;
+    }
+
+    pub fn set_require_confirmation(&mut self, require_confirmation: bool) -> &mut EnvironmentPolicy {
+        self.require_confirmation = require_confirmation;
+        return self;
+    }
+
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use super::filter_element::FilterElement;
+use super::{filter_element::FilterElement, sort_field::SortField};
 
 #[derive(Clone)]
 pub struct DocumentQuery {
@@ -6,7 +6,9 @@ pub struct DocumentQuery {
     collection: String,
     skip: Option<usize>,
     limit: Option<usize>,
-    filter: Option<FilterElement>
+    filter: Option<FilterElement>,
+    sort: Vec<SortField>,
+    projection: Vec<String>
 }
 
 impl DocumentQuery {
@@ -21,7 +23,9 @@ impl DocumentQuery {
             collection: collection,
             limit: limit,
             skip: skip,
-            filter: filter
+            filter: filter,
+            sort: Vec::new(),
+            projection: Vec::new()
         }
     }
 
@@ -45,4 +49,32 @@ impl DocumentQuery {
         return self.filter.clone();
     }
 
+    pub fn sort(&self) -> Vec<SortField> {
+        return self.sort.clone();
+    }
+
+    pub fn projection(&self) -> Vec<String> {
+        return self.projection.clone();
+    }
+
+    pub fn set_limit(&mut self, limit: Option<usize>) -> &mut Self {
+        self.limit = limit;
+        return self;
+    }
+
+    pub fn set_filter(&mut self, filter: Option<FilterElement>) -> &mut Self {
+        self.filter = filter;
+        return self;
+    }
+
+    pub fn set_sort(&mut self, sort: Vec<SortField>) -> &mut Self {
+        self.sort = sort;
+        return self;
+    }
+
+    pub fn set_projection(&mut self, projection: Vec<String>) -> &mut Self {
+        self.projection = projection;
+        return self;
+    }
+
 }
\ No newline at end of file
@@ -6,6 +6,43 @@ pub enum EFilterCategory {
     STRING,
     BOOLEAN,
     NUMERIC,
+    LIST,
     COLLECTION,
     ROOT,
+    SNIPPET,
+}
+
+impl EFilterCategory {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EFilterCategory::IDSTRING => String::from("IDSTRING"),
+            EFilterCategory::IDNUMERIC => String::from("IDNUMERIC"),
+            EFilterCategory::QUERY => String::from("QUERY"),
+            EFilterCategory::STRING => String::from("STRING"),
+            EFilterCategory::BOOLEAN => String::from("BOOLEAN"),
+            EFilterCategory::NUMERIC => String::from("NUMERIC"),
+            EFilterCategory::LIST => String::from("LIST"),
+            EFilterCategory::COLLECTION => String::from("COLLECTION"),
+            EFilterCategory::ROOT => String::from("ROOT"),
+            EFilterCategory::SNIPPET => String::from("SNIPPET"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EFilterCategory> {
+        match code {
+            "IDSTRING" => Some(EFilterCategory::IDSTRING),
+            "IDNUMERIC" => Some(EFilterCategory::IDNUMERIC),
+            "QUERY" => Some(EFilterCategory::QUERY),
+            "STRING" => Some(EFilterCategory::STRING),
+            "BOOLEAN" => Some(EFilterCategory::BOOLEAN),
+            "NUMERIC" => Some(EFilterCategory::NUMERIC),
+            "LIST" => Some(EFilterCategory::LIST),
+            "COLLECTION" => Some(EFilterCategory::COLLECTION),
+            "ROOT" => Some(EFilterCategory::ROOT),
+            "SNIPPET" => Some(EFilterCategory::SNIPPET),
+            _ => None
+        }
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,48 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EFilterOperator {
+    EQ,
+    GT,
+    GTE,
+    LT,
+    LTE,
+    IN,
+    NOTIN,
+    REGEX,
+    EXISTS,
+    BETWEEN
+}
+
+impl EFilterOperator {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EFilterOperator::EQ => String::from("EQ"),
+            EFilterOperator::GT => String::from("GT"),
+            EFilterOperator::GTE => String::from("GTE"),
+            EFilterOperator::LT => String::from("LT"),
+            EFilterOperator::LTE => String::from("LTE"),
+            EFilterOperator::IN => String::from("IN"),
+            EFilterOperator::NOTIN => String::from("NOTIN"),
+            EFilterOperator::REGEX => String::from("REGEX"),
+            EFilterOperator::EXISTS => String::from("EXISTS"),
+            EFilterOperator::BETWEEN => String::from("BETWEEN"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EFilterOperator> {
+        match code {
+            "EQ" => Some(EFilterOperator::EQ),
+            "GT" => Some(EFilterOperator::GT),
+            "GTE" => Some(EFilterOperator::GTE),
+            "LT" => Some(EFilterOperator::LT),
+            "LTE" => Some(EFilterOperator::LTE),
+            "IN" => Some(EFilterOperator::IN),
+            "NOTIN" => Some(EFilterOperator::NOTIN),
+            "REGEX" => Some(EFilterOperator::REGEX),
+            "EXISTS" => Some(EFilterOperator::EXISTS),
+            "BETWEEN" => Some(EFilterOperator::BETWEEN),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
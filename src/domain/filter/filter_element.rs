@@ -1,4 +1,6 @@
-use super::{e_filter_category::EFilterCategory, filter_value_attribute::FilterValueAttribute, filter_value::FilterValue};
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{e_filter_category::EFilterCategory, e_filter_operator::EFilterOperator, filter_value_attribute::FilterValueAttribute, filter_value::FilterValue, filter_snippets::FilterSnippets};
 
 #[derive(Clone)]
 pub struct FilterElement {
@@ -6,6 +8,7 @@ pub struct FilterElement {
     value: FilterValue,
     direction: bool,
     negation: bool,
+    operator: EFilterOperator,
 }
 
 impl FilterElement {
@@ -20,6 +23,11 @@ impl FilterElement {
         return FilterElement::from(String::new(), f_value, true, false);
     }
 
+    pub fn snippet(name: String) -> FilterElement {
+        let f_value = FilterValue::snippet(name);
+        return FilterElement::from(String::new(), f_value, true, false);
+    }
+
     pub fn id_string(key: String, value: String, attributes: Vec<FilterValueAttribute>) -> FilterElement {
         let f_value = FilterValue::id_string(value, attributes);
         return FilterElement::from(key, f_value, true, false);
@@ -65,6 +73,33 @@ impl FilterElement {
         return FilterElement::from(key, f_value, true, false);
     }
 
+    pub fn compare(key: String, operator: EFilterOperator, value: FilterValue) -> FilterElement {
+        let mut filter = FilterElement::from_value(key, value);
+        filter.operator = operator;
+        return filter;
+    }
+
+    pub fn list(key: String, operator: EFilterOperator, values: Vec<FilterValue>) -> FilterElement {
+        let children = values.into_iter()
+            .map(|v| FilterElement::from_value(String::new(), v))
+            .collect();
+        return FilterElement::compare(key, operator, FilterValue::list(children));
+    }
+
+    pub fn between(key: String, from: FilterValue, to: FilterValue) -> FilterElement {
+        return FilterElement::list(key, EFilterOperator::BETWEEN, vec![from, to]);
+    }
+
+    pub fn regex(key: String, pattern: String) -> FilterElement {
+        let f_value = FilterValue::string(pattern, Vec::new());
+        return FilterElement::compare(key, EFilterOperator::REGEX, f_value);
+    }
+
+    pub fn exists(key: String, exists: bool) -> FilterElement {
+        let f_value = FilterValue::bool(exists, Vec::new());
+        return FilterElement::compare(key, EFilterOperator::EXISTS, f_value);
+    }
+
     fn from_value(key: String, value: FilterValue) -> FilterElement {
         return FilterElement::from(key, value, true, false);
     }
@@ -74,7 +109,8 @@ impl FilterElement {
             key,
             value,
             direction,
-            negation
+            negation,
+            operator: EFilterOperator::EQ
         };
     }
 
@@ -177,6 +213,10 @@ impl FilterElement {
         return &self.value;
     }
 
+    pub fn operator(&self) -> EFilterOperator {
+        return self.operator.clone();
+    }
+
     pub fn is_negate(&self) -> bool {
         return self.negation;
     }
@@ -189,4 +229,92 @@ impl FilterElement {
         return self.clone();
     } 
 
+    pub fn fields(&self) -> Vec<String> {
+        let mut fields = Vec::new();
+        self.collect_fields(&mut fields);
+        return fields;
+    }
+
+    fn collect_fields(&self, fields: &mut Vec<String>) {
+        match self.value.category() {
+            EFilterCategory::ROOT | EFilterCategory::COLLECTION => {
+                for child in self.value.children() {
+                    child.collect_fields(fields);
+                }
+            },
+            EFilterCategory::QUERY => {},
+            _ => {
+                if !self.key.is_empty() && !fields.contains(&self.key) {
+                    fields.push(self.key.clone());
+                }
+            },
+        }
+    }
+
+    pub fn expand(&self, snippets: &FilterSnippets) -> Result<FilterElement, ConnectException> {
+        return self._expand(snippets, &mut Vec::new());
+    }
+
+    fn _expand(&self, snippets: &FilterSnippets, visiting: &mut Vec<String>) -> Result<FilterElement, ConnectException> {
+        match self.value.category() {
+            EFilterCategory::SNIPPET => {
+                let name = self.value.value();
+                if visiting.contains(&name) {
+                    let message = format!("Filter snippet '@{}' references itself: {}.", name, visiting.join(" -> "));
+                    return Err(ConnectException::new(message));
+                }
+                if self.negation {
+                    return Err(ConnectException::new(format!("Filter snippet '@{}' cannot be negated.", name)));
+                }
+                let Some(snippet) = snippets.find(&name) else {
+                    return Err(ConnectException::new(format!("Unknown filter snippet '@{}'.", name)));
+                };
+
+                visiting.push(name);
+                let mut expanded = snippet._expand(snippets, visiting)?;
+                visiting.pop();
+
+                if expanded.value.category() == EFilterCategory::ROOT {
+                    expanded.value = FilterValue::collection(expanded.value.children());
+                }
+                expanded.direction = self.direction;
+
+                return Ok(expanded);
+            },
+            EFilterCategory::ROOT | EFilterCategory::COLLECTION => {
+                let mut children = Vec::new();
+                for child in self.value.children() {
+                    children.push(child._expand(snippets, visiting)?);
+                }
+
+                let mut expanded = self.clone();
+                expanded.value = match self.value.category() {
+                    EFilterCategory::ROOT => FilterValue::root_collection(children),
+                    _ => FilterValue::collection(children),
+                };
+
+                return Ok(expanded);
+            },
+            _ => return Ok(self.clone()),
+        }
+    }
+
+    pub fn snippets(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        self.collect_snippets(&mut names);
+        return names;
+    }
+
+    fn collect_snippets(&self, names: &mut Vec<String>) {
+        match self.value.category() {
+            EFilterCategory::ROOT | EFilterCategory::COLLECTION => {
+                for child in self.value.children() {
+                    child.collect_snippets(names);
+                }
+            },
+            EFilterCategory::SNIPPET if !names.contains(&self.value.value()) => names.push(self.value.value()),
+            _ => {},
+        }
+    }
+
 }
\ No newline at end of file
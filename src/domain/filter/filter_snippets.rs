@@ -0,0 +1,67 @@
+use std::collections::HashMap;
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+use super::{document_query::DocumentQuery, filter_element::FilterElement};
+
+#[derive(Clone, Default)]
+pub struct FilterSnippets {
+    snippets: HashMap<String, FilterElement>
+}
+
+impl FilterSnippets {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register(&mut self, name: &str, filter: FilterElement) -> Result<&mut Self, ConnectException> {
+        let name = name.trim_start_matches('@');
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            let message = format!("Invalid filter snippet name '{}', only letters, digits and '_' are allowed.", name);
+            return Err(ConnectException::new(message));
+        }
+
+        let mut snippets = self.clone();
+        snippets.snippets.insert(String::from(name), filter.clone());
+        filter.expand(&snippets)?;
+
+        self.snippets.insert(String::from(name), filter);
+        Ok(self)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<FilterElement> {
+        self.snippets.remove(name.trim_start_matches('@'))
+    }
+
+    pub fn find(&self, name: &str) -> Option<FilterElement> {
+        self.snippets.get(name.trim_start_matches('@')).cloned()
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = self.snippets.keys().cloned().collect();
+        names.sort();
+        names
+    }
+
+    pub fn dependents(&self, name: &str) -> Vec<String> {
+        let name = name.trim_start_matches('@');
+        let mut dependents: Vec<String> = self.snippets.iter()
+            .filter(|(_, f)| f.snippets().iter().any(|s| s == name))
+            .map(|(k, _)| k.clone())
+            .collect();
+        dependents.sort();
+        dependents
+    }
+
+    pub fn expand(&self, query: &DocumentQuery) -> Result<DocumentQuery, ConnectException> {
+        let filter = match query.filter() {
+            Some(filter) => Some(filter.expand(self)?),
+            None => None,
+        };
+        let mut expanded = query.clone();
+        expanded.set_filter(filter);
+        Ok(expanded)
+    }
+
+}
\ No newline at end of file
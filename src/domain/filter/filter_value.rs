@@ -20,6 +20,10 @@ impl FilterValue {
         return FilterValue::from_value(EFilterCategory::QUERY, value, attributes);
     }
 
+    pub fn snippet(name: String) -> FilterValue {
+        return FilterValue::from_value(EFilterCategory::SNIPPET, name, Vec::new());
+    }
+
     pub fn id_string(value: String, attributes: Vec<FilterValueAttribute>) -> FilterValue {
         return FilterValue::from_value(EFilterCategory::IDSTRING, value, attributes);
     }
@@ -60,6 +64,10 @@ impl FilterValue {
         return FilterValue::collection(Vec::from(vec![value]));
     }
 
+    pub fn list(value: Vec<FilterElement>) -> FilterValue {
+        return FilterValue::from(EFilterCategory::LIST, String::new(), Vec::new(), value);
+    }
+
     pub fn root_collection(value: Vec<FilterElement>) -> FilterValue {
         return FilterValue::from(EFilterCategory::ROOT, String::new(), Vec::new(), value);
     }
@@ -72,7 +80,7 @@ impl FilterValue {
         return FilterValue::from(category, value, attributes, Vec::new());
     }
 
-    fn from(category: EFilterCategory, value: String, attributes: Vec<FilterValueAttribute>, children: Vec<FilterElement>) -> FilterValue {
+    pub fn from(category: EFilterCategory, value: String, attributes: Vec<FilterValueAttribute>, children: Vec<FilterElement>) -> FilterValue {
         return FilterValue {
             category,
             value,
@@ -0,0 +1,42 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SortField {
+    field: String,
+    #[serde(default)]
+    descending: bool
+}
+
+impl SortField {
+
+    pub fn ascending(field: String) -> SortField {
+        SortField {
+            field,
+            descending: false
+        }
+    }
+
+    pub fn descending(field: String) -> SortField {
+        SortField {
+            field,
+            descending: true
+        }
+    }
+
+    pub fn field(&self) -> String {
+        return self.field.clone();
+    }
+
+    pub fn is_descending(&self) -> bool {
+        return self.descending;
+    }
+
+    pub fn as_json(&self) -> Value {
+        return json!({
+            "field": self.field,
+            "descending": self.descending
+        });
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,44 @@
+const MAX_SCORE: u8 = 100;
+
+#[derive(Debug, Clone)]
+pub struct HealthScore {
+    score: u8,
+    reasons: Vec<String>
+}
+
+impl HealthScore {
+
+    pub fn new() -> Self {
+        Self {
+            score: MAX_SCORE, reasons: Vec::new()
+        }
+    }
+
+    pub fn penalize(&mut self, penalty: u8, reason: String) -> &mut Self {
+        let penalty = penalty.min(self.score);
+        self.score -= penalty;
+        self.reasons.push(format!("{} (-{})", reason, penalty));
+        self
+    }
+
+    pub fn score(&self) -> u8 {
+        self.score
+    }
+
+    pub fn reasons(&self) -> Vec<String> {
+        self.reasons.clone()
+    }
+
+    pub fn is_healthy(&self) -> bool {
+        self.reasons.is_empty()
+    }
+
+}
+
+impl Default for HealthScore {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,58 @@
+use crate::{commons::{configuration::definition::help::help, exception::connect_exception::ConnectException}, domain::environment::e_environment_action::EEnvironmentAction};
+
+use super::help_entry::HelpEntry;
+
+#[derive(Debug, Clone)]
+pub struct HelpCatalog {
+    entries: Vec<HelpEntry>
+}
+
+impl HelpCatalog {
+
+    pub fn new(entries: Vec<HelpEntry>) -> Self {
+        Self { entries }
+    }
+
+    pub fn load() -> Result<Self, ConnectException> {
+        let entries: Result<Vec<HelpEntry>, serde_json::Error> = serde_json::from_str(&help());
+        if let Err(error) = entries {
+            return Err(ConnectException::new(format!("Invalid help catalog: {}", error)));
+        }
+        Ok(Self::new(entries.unwrap()))
+    }
+
+    pub fn entries(&self) -> Vec<HelpEntry> {
+        self.entries.clone()
+    }
+
+    pub fn find(&self, code: &str) -> Option<HelpEntry> {
+        self.entries.iter()
+            .find(|e| e.code().eq_ignore_ascii_case(code))
+            .cloned()
+    }
+
+    pub fn search(&self, text: &str) -> Vec<HelpEntry> {
+        let text = text.to_lowercase();
+        self.entries.iter()
+            .filter(|e| e.code().to_lowercase().contains(&text)
+                || e.title().to_lowercase().contains(&text)
+                || e.summary().to_lowercase().contains(&text))
+            .cloned()
+            .collect()
+    }
+
+    pub fn for_backend(&self, backend: &str) -> Vec<HelpEntry> {
+        self.entries.iter()
+            .filter(|e| e.is_supported(backend))
+            .cloned()
+            .collect()
+    }
+
+    pub fn for_safety(&self, safety: &EEnvironmentAction) -> Vec<HelpEntry> {
+        self.entries.iter()
+            .filter(|e| &e.safety() == safety)
+            .cloned()
+            .collect()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use serde::Deserialize;
+
+use crate::domain::environment::e_environment_action::EEnvironmentAction;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct HelpEntry {
+    code: String,
+    title: String,
+    summary: String,
+    backends: Vec<String>,
+    safety: EEnvironmentAction,
+    notes: Vec<String>,
+    related: Vec<String>
+}
+
+impl HelpEntry {
+
+    pub fn new(code: String, title: String, summary: String, backends: Vec<String>, safety: EEnvironmentAction, notes: Vec<String>, related: Vec<String>) -> Self {
+        Self {
+            code, title, summary,
+            backends, safety,
+            notes, related
+        }
+    }
+
+    pub fn code(&self) -> String {
+        self.code.clone()
+    }
+
+    pub fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    pub fn summary(&self) -> String {
+        self.summary.clone()
+    }
+
+    pub fn backends(&self) -> Vec<String> {
+        self.backends.clone()
+    }
+
+    pub fn safety(&self) -> EEnvironmentAction {
+        self.safety.clone()
+    }
+
+    pub fn notes(&self) -> Vec<String> {
+        self.notes.clone()
+    }
+
+    pub fn related(&self) -> Vec<String> {
+        self.related.clone()
+    }
+
+    pub fn is_supported(&self, backend: &str) -> bool {
+        self.backends.iter().any(|b| b == backend)
+    }
+
+    pub fn describe(&self) -> String {
+        let mut lines = vec![
+            format!("{} ({})", self.title, self.code),
+            self.summary.clone(),
+            format!("Backends: {}", self.backends.join(", ")),
+            format!("Safety: {}", self.safety.to_string()),
+        ];
+        lines.extend(self.notes.iter().map(|n| format!("Note: {}", n)));
+        if !self.related.is_empty() {
+            lines.push(format!("See also: {}", self.related.join(", ")));
+        }
+        lines.join("\n")
+    }
+
+}
\ No newline at end of file
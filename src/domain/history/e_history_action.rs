@@ -0,0 +1,97 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EHistoryAction {
+    FINDQUERY,
+    FINDPAGE,
+    FINDALL,
+    FIND,
+    FINDSTREAM,
+    AGGREGATE,
+    CHAIN,
+    COUNT,
+    EXISTS,
+    SIZE,
+    INSERT,
+    INSERTMANY,
+    UPDATE,
+    UPDATETRANSACTION,
+    PATCH,
+    BULKSET,
+    BULKSETPREVIEW,
+    UPSERT,
+    UPDATEREVISION,
+    DELETE,
+    DELETETRANSACTION,
+    DELETEPREVIEW,
+    RESTORE,
+    PURGE,
+    MOVE
+}
+
+impl EHistoryAction {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EHistoryAction::FINDQUERY => String::from("FINDQUERY"),
+            EHistoryAction::FINDPAGE => String::from("FINDPAGE"),
+            EHistoryAction::FINDALL => String::from("FINDALL"),
+            EHistoryAction::FIND => String::from("FIND"),
+            EHistoryAction::FINDSTREAM => String::from("FINDSTREAM"),
+            EHistoryAction::AGGREGATE => String::from("AGGREGATE"),
+            EHistoryAction::CHAIN => String::from("CHAIN"),
+            EHistoryAction::COUNT => String::from("COUNT"),
+            EHistoryAction::EXISTS => String::from("EXISTS"),
+            EHistoryAction::SIZE => String::from("SIZE"),
+            EHistoryAction::INSERT => String::from("INSERT"),
+            EHistoryAction::INSERTMANY => String::from("INSERTMANY"),
+            EHistoryAction::UPDATE => String::from("UPDATE"),
+            EHistoryAction::UPDATETRANSACTION => String::from("UPDATETRANSACTION"),
+            EHistoryAction::PATCH => String::from("PATCH"),
+            EHistoryAction::BULKSET => String::from("BULKSET"),
+            EHistoryAction::BULKSETPREVIEW => String::from("BULKSETPREVIEW"),
+            EHistoryAction::UPSERT => String::from("UPSERT"),
+            EHistoryAction::UPDATEREVISION => String::from("UPDATEREVISION"),
+            EHistoryAction::DELETE => String::from("DELETE"),
+            EHistoryAction::DELETETRANSACTION => String::from("DELETETRANSACTION"),
+            EHistoryAction::DELETEPREVIEW => String::from("DELETEPREVIEW"),
+            EHistoryAction::RESTORE => String::from("RESTORE"),
+            EHistoryAction::PURGE => String::from("PURGE"),
+            EHistoryAction::MOVE => String::from("MOVE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EHistoryAction> {
+        match code {
+            "FINDQUERY" => Some(EHistoryAction::FINDQUERY),
+            "FINDPAGE" => Some(EHistoryAction::FINDPAGE),
+            "FINDALL" => Some(EHistoryAction::FINDALL),
+            "FIND" => Some(EHistoryAction::FIND),
+            "FINDSTREAM" => Some(EHistoryAction::FINDSTREAM),
+            "AGGREGATE" => Some(EHistoryAction::AGGREGATE),
+            "CHAIN" => Some(EHistoryAction::CHAIN),
+            "COUNT" => Some(EHistoryAction::COUNT),
+            "EXISTS" => Some(EHistoryAction::EXISTS),
+            "SIZE" => Some(EHistoryAction::SIZE),
+            "INSERT" => Some(EHistoryAction::INSERT),
+            "INSERTMANY" => Some(EHistoryAction::INSERTMANY),
+            "UPDATE" => Some(EHistoryAction::UPDATE),
+            "UPDATETRANSACTION" => Some(EHistoryAction::UPDATETRANSACTION),
+            "PATCH" => Some(EHistoryAction::PATCH),
+            "BULKSET" => Some(EHistoryAction::BULKSET),
+            "BULKSETPREVIEW" => Some(EHistoryAction::BULKSETPREVIEW),
+            "UPSERT" => Some(EHistoryAction::UPSERT),
+            "UPDATEREVISION" => Some(EHistoryAction::UPDATEREVISION),
+            "DELETE" => Some(EHistoryAction::DELETE),
+            "DELETETRANSACTION" => Some(EHistoryAction::DELETETRANSACTION),
+            "DELETEPREVIEW" => Some(EHistoryAction::DELETEPREVIEW),
+            "RESTORE" => Some(EHistoryAction::RESTORE),
+            "PURGE" => Some(EHistoryAction::PURGE),
+            "MOVE" => Some(EHistoryAction::MOVE),
+            _ => None
+        }
+    }
+
+    pub fn is_replayable(&self) -> bool {
+        matches!(self, EHistoryAction::FINDQUERY | EHistoryAction::FINDPAGE | EHistoryAction::FINDALL | EHistoryAction::FIND | EHistoryAction::AGGREGATE)
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,111 @@
+use crate::domain::filter::document_query::DocumentQuery;
+
+use super::e_history_action::EHistoryAction;
+
+#[derive(Clone)]
+pub struct HistoryEntry {
+    id: u64,
+    action: EHistoryAction,
+    query: DocumentQuery,
+    offset: Option<u64>,
+    limit: Option<u64>,
+    pipeline: Option<String>,
+    timestamp: u128,
+    duration: u128,
+    count: Option<usize>,
+    error: Option<String>
+}
+
+impl HistoryEntry {
+
+    pub fn new(action: EHistoryAction, query: DocumentQuery, timestamp: u128) -> Self {
+        Self {
+            id: 0,
+            action,
+            query,
+            offset: None,
+            limit: None,
+            pipeline: None,
+            timestamp,
+            duration: 0,
+            count: None,
+            error: None
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn action(&self) -> EHistoryAction {
+        self.action.clone()
+    }
+
+    pub fn query(&self) -> DocumentQuery {
+        self.query.clone()
+    }
+
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    pub fn limit(&self) -> Option<u64> {
+        self.limit
+    }
+
+    pub fn pipeline(&self) -> Option<String> {
+        self.pipeline.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn duration(&self) -> u128 {
+        self.duration
+    }
+
+    pub fn count(&self) -> Option<usize> {
+        self.count
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    pub fn set_id(&mut self, id: u64) -> &mut Self {
+        self.id = id;
+        self
+    }
+
+    pub fn set_page(&mut self, offset: u64, limit: u64) -> &mut Self {
+        self.offset = Some(offset);
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn set_pipeline(&mut self, pipeline: String) -> &mut Self {
+        self.pipeline = Some(pipeline);
+        self
+    }
+
+    pub fn set_duration(&mut self, duration: u128) -> &mut Self {
+        self.duration = duration;
+        self
+    }
+
+    pub fn set_count(&mut self, count: usize) -> &mut Self {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn set_error(&mut self, error: String) -> &mut Self {
+        self.error = Some(error);
+        self
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,85 @@
+use std::collections::VecDeque;
+
+use super::history_entry::HistoryEntry;
+
+const DEFAULT_CAPACITY: usize = 200;
+
+#[derive(Clone)]
+pub struct QueryHistory {
+    capacity: usize,
+    sequence: u64,
+    entries: VecDeque<HistoryEntry>
+}
+
+impl Default for QueryHistory {
+
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+
+}
+
+impl QueryHistory {
+
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            sequence: 0,
+            entries: VecDeque::new()
+        }
+    }
+
+    pub fn from(capacity: usize, entries: Vec<HistoryEntry>) -> Self {
+        let mut history = Self::new(capacity);
+        history.sequence = entries.iter().map(|e| e.id()).max().unwrap_or_default();
+        history.entries = VecDeque::from(entries);
+        history.trim();
+        history
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub fn set_capacity(&mut self, capacity: usize) -> &mut Self {
+        self.capacity = capacity.max(1);
+        self.trim();
+        self
+    }
+
+    pub fn push(&mut self, mut entry: HistoryEntry) -> u64 {
+        self.sequence += 1;
+        entry.set_id(self.sequence);
+        self.entries.push_back(entry);
+        self.trim();
+        self.sequence
+    }
+
+    pub fn entries(&self) -> Vec<HistoryEntry> {
+        self.entries.iter().cloned().collect()
+    }
+
+    pub fn find(&self, id: u64) -> Option<HistoryEntry> {
+        self.entries.iter().find(|e| e.id() == id).cloned()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) -> &mut Self {
+        self.entries.clear();
+        self
+    }
+
+    fn trim(&mut self) {
+        while self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,43 @@
+#[derive(Debug, Clone)]
+pub struct HostLatency {
+    host: String,
+    latency: Option<i64>,
+    region: Option<String>,
+    error: Option<String>
+}
+
+impl HostLatency {
+
+    pub fn reachable(host: String, latency: i64, region: Option<String>) -> Self {
+        Self {
+            host, latency: Some(latency), region, error: None
+        }
+    }
+
+    pub fn unreachable(host: String, error: String) -> Self {
+        Self {
+            host, latency: None, region: None, error: Some(error)
+        }
+    }
+
+    pub fn host(&self) -> String {
+        self.host.clone()
+    }
+
+    pub fn latency(&self) -> Option<i64> {
+        self.latency
+    }
+
+    pub fn region(&self) -> Option<String> {
+        self.region.clone()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn is_reachable(&self) -> bool {
+        self.error.is_none()
+    }
+
+}
\ No newline at end of file
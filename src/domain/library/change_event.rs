@@ -0,0 +1,40 @@
+use crate::domain::document::document_data::DocumentData;
+
+use super::e_change_kind::EChangeKind;
+
+#[derive(Debug, Clone)]
+pub struct ChangeEvent {
+    kind: EChangeKind,
+    query: String,
+    document: DocumentData,
+    timestamp: u128
+}
+
+impl ChangeEvent {
+
+    pub fn new(kind: EChangeKind, query: String, document: DocumentData, timestamp: u128) -> ChangeEvent {
+        ChangeEvent {
+            kind,
+            query,
+            document,
+            timestamp
+        }
+    }
+
+    pub fn kind(&self) -> EChangeKind {
+        return self.kind.clone();
+    }
+
+    pub fn query(&self) -> String {
+        return self.query.clone();
+    }
+
+    pub fn document(&self) -> DocumentData {
+        return self.document.clone();
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        return self.timestamp;
+    }
+
+}
\ No newline at end of file
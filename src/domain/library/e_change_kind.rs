@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EChangeKind {
+    ADDED,
+    REMOVED
+}
+
+impl EChangeKind {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EChangeKind::ADDED => String::from("ADDED"),
+            EChangeKind::REMOVED => String::from("REMOVED"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EChangeKind> {
+        match code {
+            "ADDED" => Some(EChangeKind::ADDED),
+            "REMOVED" => Some(EChangeKind::REMOVED),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum ESavedQueryKind {
+    FILTER,
+    PIPELINE
+}
+
+impl ESavedQueryKind {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ESavedQueryKind::FILTER => String::from("FILTER"),
+            ESavedQueryKind::PIPELINE => String::from("PIPELINE"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<ESavedQueryKind> {
+        match code {
+            "FILTER" => Some(ESavedQueryKind::FILTER),
+            "PIPELINE" => Some(ESavedQueryKind::PIPELINE),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
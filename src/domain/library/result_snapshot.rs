@@ -0,0 +1,143 @@
+use std::{collections::hash_map::DefaultHasher, hash::{Hash, Hasher}};
+
+use crate::domain::document::document_data::DocumentData;
+
+/// Hashes of one polling result. The result hash lets a poll skip the diff
+/// when nothing changed; otherwise documents are matched by content, so a
+/// modified document shows up as one removal and one addition.
+#[derive(Debug, Clone, Default)]
+pub struct ResultSnapshot {
+    hash: u64,
+    documents: Vec<(u64, DocumentData)>
+}
+
+impl ResultSnapshot {
+
+    pub fn from(documents: Vec<DocumentData>) -> ResultSnapshot {
+        let mut documents: Vec<(u64, DocumentData)> = documents.into_iter()
+            .map(|d| (Self::hash_of(&d.document()), d))
+            .collect();
+        documents.sort_by_key(|(hash, _)| *hash);
+
+        let mut hasher = DefaultHasher::new();
+        for (hash, _) in &documents {
+            hash.hash(&mut hasher);
+        }
+
+        ResultSnapshot {
+            hash: hasher.finish(),
+            documents
+        }
+    }
+
+    pub fn hash(&self) -> u64 {
+        return self.hash;
+    }
+
+    pub fn len(&self) -> usize {
+        return self.documents.len();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.documents.is_empty();
+    }
+
+    pub fn diff(&self, next: &ResultSnapshot) -> (Vec<DocumentData>, Vec<DocumentData>) {
+        if self.hash == next.hash && self.len() == next.len() {
+            return (Vec::new(), Vec::new());
+        }
+
+        let mut added = Vec::new();
+        let mut removed = Vec::new();
+
+        let mut previous = self.documents.iter().peekable();
+        let mut current = next.documents.iter().peekable();
+        loop {
+            match (previous.peek(), current.peek()) {
+                (Some((old, _)), Some((new, _))) if old == new => {
+                    previous.next();
+                    current.next();
+                },
+                (Some((old, document)), Some((new, _))) if old < new => {
+                    removed.push(document.clone());
+                    previous.next();
+                },
+                (_, Some((_, document))) => {
+                    added.push(document.clone());
+                    current.next();
+                },
+                (Some((_, document)), None) => {
+                    removed.push(document.clone());
+                    previous.next();
+                },
+                (None, None) => break,
+            }
+        }
+
+        return (added, removed);
+    }
+
+    fn hash_of(document: &str) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        document.hash(&mut hasher);
+        return hasher.finish();
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::domain::document::document_data::DocumentData;
+
+    use super::ResultSnapshot;
+
+    fn document(json: &str) -> DocumentData {
+        DocumentData::new(String::from("shop"), String::from("orders"), None, Vec::new(), String::from(json))
+    }
+
+    fn snapshot(documents: &[&str]) -> ResultSnapshot {
+        ResultSnapshot::from(documents.iter().map(|d| document(d)).collect())
+    }
+
+    fn contents(documents: Vec<DocumentData>) -> Vec<String> {
+        let mut contents: Vec<String> = documents.iter().map(|d| d.document()).collect();
+        contents.sort();
+        contents
+    }
+
+    #[test]
+    fn same_result_in_any_order_has_no_changes() {
+        let previous = snapshot(&["{\"a\":1}", "{\"a\":2}"]);
+        let next = snapshot(&["{\"a\":2}", "{\"a\":1}"]);
+
+        assert_eq!(previous.hash(), next.hash());
+        let (added, removed) = previous.diff(&next);
+        assert!(added.is_empty() && removed.is_empty());
+    }
+
+    #[test]
+    fn diff_reports_added_and_removed_documents() {
+        let previous = snapshot(&["{\"a\":1}", "{\"a\":2}", "{\"a\":3}"]);
+        let next = snapshot(&["{\"a\":2}", "{\"a\":4}", "{\"a\":5}"]);
+
+        let (added, removed) = previous.diff(&next);
+        assert_eq!(contents(added), vec!["{\"a\":4}", "{\"a\":5}"]);
+        assert_eq!(contents(removed), vec!["{\"a\":1}", "{\"a\":3}"]);
+    }
+
+    #[test]
+    fn duplicated_documents_are_counted() {
+        let previous = snapshot(&["{\"a\":1}"]);
+        let next = snapshot(&["{\"a\":1}", "{\"a\":1}"]);
+
+        let (added, removed) = previous.diff(&next);
+        assert_eq!(contents(added), vec!["{\"a\":1}"]);
+        assert!(removed.is_empty());
+
+        let (added, removed) = next.diff(&ResultSnapshot::default());
+        assert!(added.is_empty());
+        assert_eq!(removed.len(), 2);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use super::e_saved_query_kind::ESavedQueryKind;
+
+#[derive(Debug, Clone)]
+pub struct SavedQuery {
+    name: String,
+    connection: String,
+    data_base: String,
+    collection: String,
+    kind: ESavedQueryKind,
+    body: String,
+    description: Option<String>
+}
+
+impl SavedQuery {
+
+    pub fn new(name: String, connection: String, data_base: String, collection: String, kind: ESavedQueryKind, body: String) -> SavedQuery {
+        SavedQuery {
+            name,
+            connection,
+            data_base,
+            collection,
+            kind,
+            body,
+            description: None
+        }
+    }
+
+    pub fn name(&self) -> String {
+        return self.name.clone();
+    }
+
+    pub fn connection(&self) -> String {
+        return self.connection.clone();
+    }
+
+    pub fn data_base(&self) -> String {
+        return self.data_base.clone();
+    }
+
+    pub fn collection(&self) -> String {
+        return self.collection.clone();
+    }
+
+    pub fn kind(&self) -> ESavedQueryKind {
+        return self.kind.clone();
+    }
+
+    pub fn body(&self) -> String {
+        return self.body.clone();
+    }
+
+    pub fn description(&self) -> Option<String> {
+        return self.description.clone();
+    }
+
+    pub fn set_body(&mut self, body: String) -> &mut SavedQuery {
+        self.body = body;
+        return self;
+    }
+
+    pub fn set_description(&mut self, description: String) -> &mut SavedQuery {
+        self.description = Some(description);
+        return self;
+    }
+
+    pub fn belongs_to(&self, connection: &str, data_base: &str, collection: &str) -> bool {
+        return self.connection == connection && self.data_base == data_base && self.collection == collection;
+    }
+
+    pub fn is_same(&self, other: &SavedQuery) -> bool {
+        return self.name == other.name && self.belongs_to(&other.connection, &other.data_base, &other.collection);
+    }
+
+}
\ No newline at end of file
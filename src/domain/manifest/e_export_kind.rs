@@ -0,0 +1,27 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EExportKind {
+    DUMP,
+    NDJSON,
+    AVRO
+}
+
+impl EExportKind {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EExportKind::DUMP => String::from("DUMP"),
+            EExportKind::NDJSON => String::from("NDJSON"),
+            EExportKind::AVRO => String::from("AVRO"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EExportKind> {
+        match code {
+            "DUMP" => Some(EExportKind::DUMP),
+            "NDJSON" => Some(EExportKind::NDJSON),
+            "AVRO" => Some(EExportKind::AVRO),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
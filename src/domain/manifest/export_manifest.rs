@@ -0,0 +1,139 @@
+use crate::domain::document::document_origin::DocumentOrigin;
+
+use super::{e_export_kind::EExportKind, manifest_file::ManifestFile};
+
+pub const MANIFEST_VERSION: u64 = 1;
+
+#[derive(Debug, Clone)]
+pub struct ExportManifest {
+    version: u64,
+    tool: String,
+    tool_version: String,
+    kind: EExportKind,
+    fingerprint: String,
+    origin: Option<DocumentOrigin>,
+    data_base: String,
+    collection: String,
+    filter: Option<String>,
+    documents: usize,
+    indexes: usize,
+    files: Vec<ManifestFile>,
+    started_at: u128,
+    finished_at: u128
+}
+
+impl ExportManifest {
+
+    pub fn new(kind: EExportKind, fingerprint: String, data_base: String, collection: String, started_at: u128) -> Self {
+        Self {
+            version: MANIFEST_VERSION,
+            tool: String::from(env!("CARGO_PKG_NAME")),
+            tool_version: String::from(env!("CARGO_PKG_VERSION")),
+            kind,
+            fingerprint,
+            origin: None,
+            data_base,
+            collection,
+            filter: None,
+            documents: 0,
+            indexes: 0,
+            files: Vec::new(),
+            started_at,
+            finished_at: started_at
+        }
+    }
+
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    pub fn tool(&self) -> String {
+        self.tool.clone()
+    }
+
+    pub fn tool_version(&self) -> String {
+        self.tool_version.clone()
+    }
+
+    pub fn kind(&self) -> EExportKind {
+        self.kind.clone()
+    }
+
+    pub fn fingerprint(&self) -> String {
+        self.fingerprint.clone()
+    }
+
+    pub fn origin(&self) -> Option<DocumentOrigin> {
+        self.origin.clone()
+    }
+
+    pub fn data_base(&self) -> String {
+        self.data_base.clone()
+    }
+
+    pub fn collection(&self) -> String {
+        self.collection.clone()
+    }
+
+    pub fn filter(&self) -> Option<String> {
+        self.filter.clone()
+    }
+
+    pub fn documents(&self) -> usize {
+        self.documents
+    }
+
+    pub fn indexes(&self) -> usize {
+        self.indexes
+    }
+
+    pub fn files(&self) -> Vec<ManifestFile> {
+        self.files.clone()
+    }
+
+    pub fn started_at(&self) -> u128 {
+        self.started_at
+    }
+
+    pub fn finished_at(&self) -> u128 {
+        self.finished_at
+    }
+
+    pub fn set_version(&mut self, version: u64) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    pub fn set_tool(&mut self, tool: String, tool_version: String) -> &mut Self {
+        self.tool = tool;
+        self.tool_version = tool_version;
+        self
+    }
+
+    pub fn set_origin(&mut self, origin: DocumentOrigin) -> &mut Self {
+        self.origin = Some(origin);
+        self
+    }
+
+    pub fn set_filter(&mut self, filter: String) -> &mut Self {
+        self.filter = Some(filter);
+        self
+    }
+
+    pub fn set_counts(&mut self, documents: usize, indexes: usize) -> &mut Self {
+        self.documents = documents;
+        self.indexes = indexes;
+        self
+    }
+
+    pub fn push_file(&mut self, file: ManifestFile) -> &mut Self {
+        self.files.push(file);
+        self
+    }
+
+    pub fn finish(&mut self, finished_at: u128) -> &mut Self {
+        self.finished_at = finished_at;
+        self
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+use super::export_manifest::ExportManifest;
+
+#[derive(Debug, Clone)]
+pub struct ManifestCheck {
+    manifest: ExportManifest,
+    issues: Vec<String>
+}
+
+impl ManifestCheck {
+
+    pub fn new(manifest: ExportManifest, issues: Vec<String>) -> Self {
+        Self {
+            manifest, issues
+        }
+    }
+
+    pub fn manifest(&self) -> ExportManifest {
+        self.manifest.clone()
+    }
+
+    pub fn issues(&self) -> Vec<String> {
+        self.issues.clone()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+#[derive(Debug, Clone, PartialEq)]
+pub struct ManifestFile {
+    name: String,
+    size: u64,
+    checksum: String
+}
+
+impl ManifestFile {
+
+    pub fn new(name: String, size: u64, checksum: String) -> Self {
+        Self {
+            name, size, checksum
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    pub fn checksum(&self) -> String {
+        self.checksum.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,281 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::e_json_type::EJSONType};
+
+use super::{document_mapping_preview::DocumentMappingPreview, e_mapping_operation::EMappingOperation, field_mapping::FieldMapping};
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct DocumentMapping {
+    mappings: Vec<FieldMapping>
+}
+
+impl DocumentMapping {
+
+    pub fn new(mappings: Vec<FieldMapping>) -> Self {
+        Self {
+            mappings
+        }
+    }
+
+    pub fn from_string(definition: &str) -> Result<Self, ConnectException> {
+        let mapping: Result<DocumentMapping, _> = serde_json::from_str(definition);
+        if let Err(error) = mapping {
+            let exception = ConnectException::new(format!("Invalid mapping format: {}", error));
+            return Err(exception);
+        }
+
+        let mapping = mapping.unwrap();
+        mapping.validate()?;
+
+        Ok(mapping)
+    }
+
+    pub fn mappings(&self) -> Vec<FieldMapping> {
+        self.mappings.clone()
+    }
+
+    pub fn push(&mut self, mapping: FieldMapping) -> &mut Self {
+        self.mappings.push(mapping);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ConnectException> {
+        for (index, mapping) in self.mappings.iter().enumerate() {
+            let fields = mapping.fields().len();
+            let targets = mapping.targets().len();
+
+            let valid = match mapping.operation() {
+                EMappingOperation::RENAME => fields == 1 && targets == 1,
+                EMappingOperation::CAST => fields == 1 && EJSONType::from_string(&mapping.value()).is_some(),
+                EMappingOperation::SPLIT => fields == 1 && targets > 0 && !mapping.value().is_empty(),
+                EMappingOperation::MERGE => fields > 0 && targets == 1,
+                EMappingOperation::DEFAULT => targets == 1 && serde_json::from_str::<Value>(&mapping.value()).is_ok(),
+                EMappingOperation::DROP => fields > 0,
+                EMappingOperation::MASK => fields > 0 && serde_json::from_str::<Value>(&mapping.value()).is_ok(),
+            };
+
+            if !valid {
+                let message = format!("Invalid {} mapping at position {}.", mapping.operation().to_string(), index);
+                return Err(ConnectException::new(message));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&self, document: &str) -> Result<String, ConnectException> {
+        let json: Result<Value, _> = serde_json::from_str(document);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let value = self.apply_value(json.unwrap())?;
+
+        Ok(value.to_string())
+    }
+
+    pub fn apply_value(&self, document: Value) -> Result<Value, ConnectException> {
+        let mut object = match document {
+            Value::Object(object) => object,
+            _ => return Err(ConnectException::new(String::from("Only JSON objects can be mapped."))),
+        };
+
+        for mapping in &self.mappings {
+            self.apply_mapping(&mut object, mapping)?;
+        }
+
+        Ok(Value::Object(object))
+    }
+
+    pub fn preview(&self, documents: Vec<String>) -> Vec<DocumentMappingPreview> {
+        documents.into_iter()
+            .map(|document| match self.apply(&document) {
+                Ok(mapped) => DocumentMappingPreview::new(document, Some(mapped), None),
+                Err(error) => DocumentMappingPreview::new(document, None, Some(error.message())),
+            })
+            .collect()
+    }
+
+    fn apply_mapping(&self, object: &mut Map<String, Value>, mapping: &FieldMapping) -> Result<(), ConnectException> {
+        let fields = mapping.fields();
+        let targets = mapping.targets();
+
+        match mapping.operation() {
+            EMappingOperation::RENAME => {
+                if let Some(value) = object.remove(&fields[0]) {
+                    object.insert(targets[0].clone(), value);
+                }
+            },
+            EMappingOperation::CAST => {
+                if let Some(value) = object.get(&fields[0]) {
+                    let casted = Self::cast(&fields[0], value, &mapping.value())?;
+                    object.insert(fields[0].clone(), casted);
+                }
+            },
+            EMappingOperation::SPLIT => {
+                let value = match object.get(&fields[0]) {
+                    Some(Value::String(value)) => value.clone(),
+                    Some(_) => {
+                        let message = format!("Field '{}' is not a string and cannot be split.", fields[0]);
+                        return Err(ConnectException::new(message));
+                    },
+                    None => return Ok(()),
+                };
+
+                let separator = mapping.value();
+                let mut parts = value.splitn(targets.len(), separator.as_str());
+                object.remove(&fields[0]);
+                for target in targets {
+                    let part = parts.next().unwrap_or_default();
+                    object.insert(target, Value::String(String::from(part)));
+                }
+            },
+            EMappingOperation::MERGE => {
+                let mut parts = Vec::new();
+                for field in &fields {
+                    match object.remove(field) {
+                        Some(Value::String(value)) => parts.push(value),
+                        Some(Value::Null) | None => {},
+                        Some(value) => parts.push(value.to_string()),
+                    }
+                }
+                object.insert(targets[0].clone(), Value::String(parts.join(&mapping.value())));
+            },
+            EMappingOperation::DEFAULT => {
+                if !object.contains_key(&targets[0]) {
+                    let value: Value = serde_json::from_str(&mapping.value()).unwrap_or(Value::Null);
+                    object.insert(targets[0].clone(), value);
+                }
+            },
+            EMappingOperation::DROP => {
+                for field in &fields {
+                    object.remove(field);
+                }
+            },
+            EMappingOperation::MASK => {
+                let value: Value = serde_json::from_str(&mapping.value()).unwrap_or(Value::Null);
+                for field in &fields {
+                    if object.contains_key(field) {
+                        object.insert(field.clone(), value.clone());
+                    }
+                }
+            },
+        }
+
+        Ok(())
+    }
+
+    fn cast(field: &str, value: &Value, jtype: &str) -> Result<Value, ConnectException> {
+        let text = match value {
+            Value::String(text) => text.clone(),
+            value => value.to_string(),
+        };
+
+        let casted = match EJSONType::from_string(jtype) {
+            Some(EJSONType::STRING) => Some(Value::String(text)),
+            Some(EJSONType::NUMERIC) => match text.trim().parse::<i64>() {
+                Ok(number) => Some(Value::from(number)),
+                Err(_) => text.trim().parse::<f64>().ok().map(Value::from),
+            },
+            Some(EJSONType::BOOLEAN) => text.trim().parse::<bool>().ok().map(Value::Bool),
+            None => None,
+        };
+
+        if casted.is_none() {
+            let message = format!("Field '{}' cannot be cast to {}.", field, jtype);
+            return Err(ConnectException::new(message));
+        }
+
+        Ok(casted.unwrap())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::{json, Value};
+
+    use crate::domain::mapping::field_mapping::FieldMapping;
+
+    use super::DocumentMapping;
+
+    fn apply(mappings: Vec<FieldMapping>, document: Value) -> Value {
+        DocumentMapping::new(mappings).apply_value(document).unwrap()
+    }
+
+    #[test]
+    fn rename_moves_the_value() {
+        let mapped = apply(vec![FieldMapping::rename(String::from("name"), String::from("full_name"))], json!({"name": "Ada"}));
+
+        assert_eq!(mapped, json!({"full_name": "Ada"}));
+    }
+
+    #[test]
+    fn cast_converts_strings_to_typed_values() {
+        let mapped = apply(vec![
+            FieldMapping::cast(String::from("age"), String::from("NUMERIC")),
+            FieldMapping::cast(String::from("score"), String::from("NUMERIC")),
+            FieldMapping::cast(String::from("active"), String::from("BOOLEAN")),
+            FieldMapping::cast(String::from("zip"), String::from("STRING")),
+        ], json!({"age": "42", "score": "4.5", "active": "true", "zip": 28001}));
+
+        assert_eq!(mapped, json!({"age": 42, "score": 4.5, "active": true, "zip": "28001"}));
+    }
+
+    #[test]
+    fn cast_rejects_unparseable_values() {
+        let mapping = DocumentMapping::new(vec![FieldMapping::cast(String::from("age"), String::from("NUMERIC"))]);
+
+        assert!(mapping.apply_value(json!({"age": "unknown"})).is_err());
+    }
+
+    #[test]
+    fn split_and_merge_are_symmetric() {
+        let split = apply(vec![FieldMapping::split(String::from("name"), vec![String::from("first"), String::from("last")], String::from(" "))], json!({"name": "Ada King Lovelace"}));
+        assert_eq!(split, json!({"first": "Ada", "last": "King Lovelace"}));
+
+        let merged = apply(vec![FieldMapping::merge(vec![String::from("first"), String::from("last")], String::from("name"), String::from(" "))], split);
+        assert_eq!(merged, json!({"name": "Ada King Lovelace"}));
+    }
+
+    #[test]
+    fn default_only_fills_missing_fields() {
+        let mapping = vec![FieldMapping::default_value(String::from("status"), String::from("\"new\""))];
+
+        assert_eq!(apply(mapping.clone(), json!({})), json!({"status": "new"}));
+        assert_eq!(apply(mapping, json!({"status": "done"})), json!({"status": "done"}));
+    }
+
+    #[test]
+    fn drop_removes_fields() {
+        let mapped = apply(vec![FieldMapping::drop_fields(vec![String::from("a"), String::from("missing")])], json!({"a": 1, "b": 2}));
+
+        assert_eq!(mapped, json!({"b": 2}));
+    }
+
+    #[test]
+    fn mask_replaces_only_present_fields() {
+        let mapped = apply(vec![FieldMapping::mask(vec![String::from("email"), String::from("phone")], String::from("\"***\""))], json!({"email": "ada@example.com", "name": "Ada"}));
+
+        assert_eq!(mapped, json!({"email": "***", "name": "Ada"}));
+    }
+
+    #[test]
+    fn invalid_definitions_are_rejected() {
+        assert!(DocumentMapping::from_string(r#"{"mappings": [{"operation": "RENAME", "fields": ["a"]}]}"#).is_err());
+        assert!(DocumentMapping::from_string(r#"{"mappings": [{"operation": "CAST", "fields": ["a"], "value": "DATE"}]}"#).is_err());
+        assert!(DocumentMapping::from_string(r#"{"mappings": [{"operation": "RENAME", "fields": ["a"], "targets": ["b"]}]}"#).is_ok());
+    }
+
+    #[test]
+    fn only_objects_can_be_mapped() {
+        let mapping = DocumentMapping::new(Vec::new());
+
+        assert!(mapping.apply("[1, 2]").is_err());
+        assert_eq!(mapping.preview(vec![String::from("[1]")])[0].mapped(), None);
+    }
+
+}
\ No newline at end of file
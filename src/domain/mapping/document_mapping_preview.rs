@@ -0,0 +1,32 @@
+#[derive(Debug, Clone)]
+pub struct DocumentMappingPreview {
+    original: String,
+    mapped: Option<String>,
+    error: Option<String>
+}
+
+impl DocumentMappingPreview {
+
+    pub fn new(original: String, mapped: Option<String>, error: Option<String>) -> Self {
+        Self {
+            original, mapped, error
+        }
+    }
+
+    pub fn original(&self) -> String {
+        self.original.clone()
+    }
+
+    pub fn mapped(&self) -> Option<String> {
+        self.mapped.clone()
+    }
+
+    pub fn error(&self) -> Option<String> {
+        self.error.clone()
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.error.is_none()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use serde::Deserialize;
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub enum EMappingOperation {
+    RENAME,
+    CAST,
+    SPLIT,
+    MERGE,
+    DEFAULT,
+    DROP,
+    MASK
+}
+
+impl EMappingOperation {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EMappingOperation::RENAME => String::from("RENAME"),
+            EMappingOperation::CAST => String::from("CAST"),
+            EMappingOperation::SPLIT => String::from("SPLIT"),
+            EMappingOperation::MERGE => String::from("MERGE"),
+            EMappingOperation::DEFAULT => String::from("DEFAULT"),
+            EMappingOperation::DROP => String::from("DROP"),
+            EMappingOperation::MASK => String::from("MASK"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EMappingOperation> {
+        match code {
+            "RENAME" => Some(EMappingOperation::RENAME),
+            "CAST" => Some(EMappingOperation::CAST),
+            "SPLIT" => Some(EMappingOperation::SPLIT),
+            "MERGE" => Some(EMappingOperation::MERGE),
+            "DEFAULT" => Some(EMappingOperation::DEFAULT),
+            "DROP" => Some(EMappingOperation::DROP),
+            "MASK" => Some(EMappingOperation::MASK),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
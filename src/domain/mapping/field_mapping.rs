@@ -0,0 +1,68 @@
+use serde::Deserialize;
+
+use super::e_mapping_operation::EMappingOperation;
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FieldMapping {
+    operation: EMappingOperation,
+    #[serde(default)]
+    fields: Vec<String>,
+    #[serde(default)]
+    targets: Vec<String>,
+    #[serde(default)]
+    value: String
+}
+
+impl FieldMapping {
+
+    pub fn new(operation: EMappingOperation, fields: Vec<String>, targets: Vec<String>, value: String) -> Self {
+        Self {
+            operation, fields, targets, value
+        }
+    }
+
+    pub fn rename(field: String, target: String) -> Self {
+        Self::new(EMappingOperation::RENAME, vec![field], vec![target], String::new())
+    }
+
+    pub fn cast(field: String, jtype: String) -> Self {
+        Self::new(EMappingOperation::CAST, vec![field], Vec::new(), jtype)
+    }
+
+    pub fn split(field: String, targets: Vec<String>, separator: String) -> Self {
+        Self::new(EMappingOperation::SPLIT, vec![field], targets, separator)
+    }
+
+    pub fn merge(fields: Vec<String>, target: String, separator: String) -> Self {
+        Self::new(EMappingOperation::MERGE, fields, vec![target], separator)
+    }
+
+    pub fn default_value(target: String, value: String) -> Self {
+        Self::new(EMappingOperation::DEFAULT, Vec::new(), vec![target], value)
+    }
+
+    pub fn drop_fields(fields: Vec<String>) -> Self {
+        Self::new(EMappingOperation::DROP, fields, Vec::new(), String::new())
+    }
+
+    pub fn mask(fields: Vec<String>, value: String) -> Self {
+        Self::new(EMappingOperation::MASK, fields, Vec::new(), value)
+    }
+
+    pub fn operation(&self) -> EMappingOperation {
+        self.operation.clone()
+    }
+
+    pub fn fields(&self) -> Vec<String> {
+        self.fields.clone()
+    }
+
+    pub fn targets(&self) -> Vec<String> {
+        self.targets.clone()
+    }
+
+    pub fn value(&self) -> String {
+        self.value.clone()
+    }
+
+}
\ No newline at end of file
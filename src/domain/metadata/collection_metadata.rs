@@ -0,0 +1,62 @@
+#[derive(Debug, Clone)]
+pub struct CollectionMetadata {
+    name: String,
+    documents: i64,
+    data_size: i64,
+    storage_size: i64,
+    avg_document_size: i64,
+    indexes: i64,
+    index_size: i64,
+    degraded: bool
+}
+
+impl CollectionMetadata {
+
+    pub fn new(name: String, documents: i64, data_size: i64, storage_size: i64, avg_document_size: i64, indexes: i64, index_size: i64) -> Self {
+        Self {
+            name, documents, data_size, storage_size, avg_document_size, indexes, index_size, degraded: false
+        }
+    }
+
+    pub fn set_degraded(&mut self, degraded: bool) -> &mut Self {
+        self.degraded = degraded;
+        self
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn documents(&self) -> i64 {
+        self.documents
+    }
+
+    pub fn data_size(&self) -> i64 {
+        self.data_size
+    }
+
+    pub fn storage_size(&self) -> i64 {
+        self.storage_size
+    }
+
+    pub fn avg_document_size(&self) -> i64 {
+        self.avg_document_size
+    }
+
+    pub fn indexes(&self) -> i64 {
+        self.indexes
+    }
+
+    pub fn index_size(&self) -> i64 {
+        self.index_size
+    }
+
+    pub fn total_size(&self) -> i64 {
+        self.storage_size + self.index_size
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.degraded
+    }
+
+}
\ No newline at end of file
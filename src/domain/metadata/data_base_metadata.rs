@@ -0,0 +1,49 @@
+use super::collection_metadata::CollectionMetadata;
+
+#[derive(Debug, Clone)]
+pub struct DataBaseMetadata {
+    name: String,
+    collections: Vec<CollectionMetadata>
+}
+
+impl DataBaseMetadata {
+
+    pub fn new(name: String, collections: Vec<CollectionMetadata>) -> Self {
+        Self {
+            name, collections
+        }
+    }
+
+    pub fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    pub fn collections(&self) -> Vec<CollectionMetadata> {
+        self.collections.clone()
+    }
+
+    pub fn documents(&self) -> i64 {
+        self.collections.iter().map(|c| c.documents()).sum()
+    }
+
+    pub fn data_size(&self) -> i64 {
+        self.collections.iter().map(|c| c.data_size()).sum()
+    }
+
+    pub fn storage_size(&self) -> i64 {
+        self.collections.iter().map(|c| c.storage_size()).sum()
+    }
+
+    pub fn indexes(&self) -> i64 {
+        self.collections.iter().map(|c| c.indexes()).sum()
+    }
+
+    pub fn index_size(&self) -> i64 {
+        self.collections.iter().map(|c| c.index_size()).sum()
+    }
+
+    pub fn total_size(&self) -> i64 {
+        self.collections.iter().map(|c| c.total_size()).sum()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,88 @@
+#[derive(Debug, Clone, Default)]
+pub struct ServerMetadata {
+    host: String,
+    version: String,
+    uptime_millis: i64,
+    connections_current: i64,
+    connections_available: i64,
+    connections_active: i64,
+    inserts: i64,
+    queries: i64,
+    updates: i64,
+    deletes: i64,
+    degraded: bool
+}
+
+impl ServerMetadata {
+
+    pub fn new(host: String, version: String, uptime_millis: i64) -> Self {
+        Self {
+            host, version, uptime_millis, ..Default::default()
+        }
+    }
+
+    pub fn set_connections(&mut self, current: i64, available: i64, active: i64) -> &mut Self {
+        self.connections_current = current;
+        self.connections_available = available;
+        self.connections_active = active;
+        self
+    }
+
+    pub fn set_operations(&mut self, inserts: i64, queries: i64, updates: i64, deletes: i64) -> &mut Self {
+        self.inserts = inserts;
+        self.queries = queries;
+        self.updates = updates;
+        self.deletes = deletes;
+        self
+    }
+
+    pub fn set_degraded(&mut self, degraded: bool) -> &mut Self {
+        self.degraded = degraded;
+        self
+    }
+
+    pub fn host(&self) -> String {
+        self.host.clone()
+    }
+
+    pub fn version(&self) -> String {
+        self.version.clone()
+    }
+
+    pub fn uptime_millis(&self) -> i64 {
+        self.uptime_millis
+    }
+
+    pub fn connections_current(&self) -> i64 {
+        self.connections_current
+    }
+
+    pub fn connections_available(&self) -> i64 {
+        self.connections_available
+    }
+
+    pub fn connections_active(&self) -> i64 {
+        self.connections_active
+    }
+
+    pub fn inserts(&self) -> i64 {
+        self.inserts
+    }
+
+    pub fn queries(&self) -> i64 {
+        self.queries
+    }
+
+    pub fn updates(&self) -> i64 {
+        self.updates
+    }
+
+    pub fn deletes(&self) -> i64 {
+        self.deletes
+    }
+
+    pub fn is_degraded(&self) -> bool {
+        self.degraded
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum EPreferenceLayer {
+    DEFAULT,
+    WORKSPACE,
+    USER,
+    SESSION
+}
+
+impl EPreferenceLayer {
+
+    pub fn precedence() -> Vec<EPreferenceLayer> {
+        vec![EPreferenceLayer::SESSION, EPreferenceLayer::USER, EPreferenceLayer::WORKSPACE, EPreferenceLayer::DEFAULT]
+    }
+
+    pub fn to_string(&self) -> String {
+        match self {
+            EPreferenceLayer::DEFAULT => String::from("DEFAULT"),
+            EPreferenceLayer::WORKSPACE => String::from("WORKSPACE"),
+            EPreferenceLayer::USER => String::from("USER"),
+            EPreferenceLayer::SESSION => String::from("SESSION"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<EPreferenceLayer> {
+        match code {
+            "DEFAULT" => Some(EPreferenceLayer::DEFAULT),
+            "WORKSPACE" => Some(EPreferenceLayer::WORKSPACE),
+            "USER" => Some(EPreferenceLayer::USER),
+            "SESSION" => Some(EPreferenceLayer::SESSION),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,183 @@
+use serde_json::{json, Map, Value};
+
+use crate::domain::table::e_table_export_format::ETableExportFormat;
+
+use super::{e_preference_layer::EPreferenceLayer, query_options::QueryOptions};
+
+pub const PAGE_SIZE: &str = "page_size";
+pub const IMPORT_BATCH_SIZE: &str = "import_batch_size";
+pub const EXPORT_FORMAT: &str = "export_format";
+pub const THEME: &str = "theme";
+pub const CONFIRM_DESTRUCTIVE: &str = "confirm_destructive";
+pub const QUERY_OPTIONS: &str = "query_options";
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+const DEFAULT_IMPORT_BATCH_SIZE: u64 = 500;
+const DEFAULT_THEME: &str = "default";
+
+#[derive(Debug, Clone)]
+pub struct Preferences {
+    defaults: Map<String, Value>,
+    workspace: Map<String, Value>,
+    user: Map<String, Value>,
+    session: Map<String, Value>
+}
+
+impl Preferences {
+
+    pub fn new() -> Preferences {
+        Preferences::default()
+    }
+
+    fn layer_ref(&self, layer: &EPreferenceLayer) -> &Map<String, Value> {
+        match layer {
+            EPreferenceLayer::DEFAULT => &self.defaults,
+            EPreferenceLayer::WORKSPACE => &self.workspace,
+            EPreferenceLayer::USER => &self.user,
+            EPreferenceLayer::SESSION => &self.session,
+        }
+    }
+
+    fn layer_mut(&mut self, layer: &EPreferenceLayer) -> &mut Map<String, Value> {
+        match layer {
+            EPreferenceLayer::DEFAULT => &mut self.defaults,
+            EPreferenceLayer::WORKSPACE => &mut self.workspace,
+            EPreferenceLayer::USER => &mut self.user,
+            EPreferenceLayer::SESSION => &mut self.session,
+        }
+    }
+
+    pub fn layer(&self, layer: &EPreferenceLayer) -> Map<String, Value> {
+        self.layer_ref(layer).clone()
+    }
+
+    pub fn set_layer(&mut self, layer: &EPreferenceLayer, values: Map<String, Value>) -> &mut Preferences {
+        *self.layer_mut(layer) = values;
+        self
+    }
+
+    pub fn set(&mut self, layer: &EPreferenceLayer, key: &str, value: Value) -> &mut Preferences {
+        self.layer_mut(layer).insert(String::from(key), value);
+        self
+    }
+
+    pub fn unset(&mut self, layer: &EPreferenceLayer, key: &str) -> Option<Value> {
+        self.layer_mut(layer).remove(key)
+    }
+
+    pub fn clear_session(&mut self) -> &mut Preferences {
+        self.session.clear();
+        self
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        self.source(key).and_then(|l| self.layer_ref(&l).get(key).cloned())
+    }
+
+    pub fn source(&self, key: &str) -> Option<EPreferenceLayer> {
+        EPreferenceLayer::precedence().into_iter()
+            .find(|l| self.layer_ref(l).contains_key(key))
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        let mut keys: Vec<String> = Vec::new();
+        for layer in EPreferenceLayer::precedence() {
+            for key in self.layer_ref(&layer).keys() {
+                if !keys.contains(key) {
+                    keys.push(key.clone());
+                }
+            }
+        }
+        keys.sort();
+        keys
+    }
+
+    pub fn resolved(&self) -> Map<String, Value> {
+        self.keys().into_iter()
+            .filter_map(|k| self.get(&k).map(|v| (k, v)))
+            .collect()
+    }
+
+    fn find<R>(&self, key: &str, extract: impl Fn(&Value) -> Option<R>) -> Option<R> {
+        EPreferenceLayer::precedence().into_iter()
+            .filter_map(|l| self.layer_ref(&l).get(key).and_then(&extract))
+            .next()
+    }
+
+    pub fn page_size(&self) -> usize {
+        self.find(PAGE_SIZE, |v| v.as_u64().filter(|s| *s > 0)).unwrap_or(DEFAULT_PAGE_SIZE) as usize
+    }
+
+    pub fn import_batch_size(&self) -> usize {
+        self.find(IMPORT_BATCH_SIZE, |v| v.as_u64().filter(|s| *s > 0)).unwrap_or(DEFAULT_IMPORT_BATCH_SIZE) as usize
+    }
+
+    pub fn export_format(&self) -> ETableExportFormat {
+        self.find(EXPORT_FORMAT, |v| v.as_str().and_then(ETableExportFormat::from_string))
+            .unwrap_or(ETableExportFormat::JSON)
+    }
+
+    pub fn theme(&self) -> String {
+        self.find(THEME, |v| v.as_str().map(String::from)).unwrap_or_else(|| String::from(DEFAULT_THEME))
+    }
+
+    pub fn confirm_destructive(&self) -> bool {
+        self.find(CONFIRM_DESTRUCTIVE, |v| v.as_bool()).unwrap_or(true)
+    }
+
+    pub fn query_options(&self, data_base: &str, collection: &str) -> Option<QueryOptions> {
+        let key = Preferences::collection_key(data_base, collection);
+        self.find(QUERY_OPTIONS, |v| v.get(&key).and_then(|o| QueryOptions::from_json(o).ok()))
+    }
+
+    pub fn set_query_options(&mut self, layer: &EPreferenceLayer, data_base: &str, collection: &str, options: &QueryOptions) -> &mut Preferences {
+        let key = Preferences::collection_key(data_base, collection);
+        let mut profiles = self.layer_query_options(layer);
+        profiles.insert(key, options.as_json());
+        self.set(layer, QUERY_OPTIONS, Value::Object(profiles))
+    }
+
+    pub fn unset_query_options(&mut self, layer: &EPreferenceLayer, data_base: &str, collection: &str) -> Option<QueryOptions> {
+        let key = Preferences::collection_key(data_base, collection);
+        let mut profiles = self.layer_query_options(layer);
+        let removed = profiles.remove(&key);
+        if profiles.is_empty() {
+            self.unset(layer, QUERY_OPTIONS);
+        } else {
+            self.set(layer, QUERY_OPTIONS, Value::Object(profiles));
+        }
+        removed.and_then(|o| QueryOptions::from_json(&o).ok())
+    }
+
+    fn layer_query_options(&self, layer: &EPreferenceLayer) -> Map<String, Value> {
+        self.layer_ref(layer).get(QUERY_OPTIONS)
+            .and_then(|v| v.as_object().cloned())
+            .unwrap_or_default()
+    }
+
+    fn collection_key(data_base: &str, collection: &str) -> String {
+        format!("{}.{}", data_base, collection)
+    }
+
+}
+
+impl Default for Preferences {
+
+    fn default() -> Self {
+        let defaults = json!({
+            PAGE_SIZE: DEFAULT_PAGE_SIZE,
+            IMPORT_BATCH_SIZE: DEFAULT_IMPORT_BATCH_SIZE,
+            EXPORT_FORMAT: ETableExportFormat::JSON.to_string(),
+            THEME: DEFAULT_THEME,
+            CONFIRM_DESTRUCTIVE: true
+        });
+
+        Preferences {
+            defaults: defaults.as_object().cloned().unwrap_or_default(),
+            workspace: Map::new(),
+            user: Map::new(),
+            session: Map::new()
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,208 @@
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::filter::{document_query::DocumentQuery, sort_field::SortField}};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct QueryOptions {
+    #[serde(default)]
+    page_size: Option<usize>,
+    #[serde(default)]
+    sort: Vec<SortField>,
+    #[serde(default)]
+    projection: Vec<String>,
+    #[serde(default)]
+    display_fields: Vec<String>,
+    #[serde(default)]
+    max_documents: Option<usize>
+}
+
+impl QueryOptions {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_json(value: &Value) -> Result<Self, ConnectException> {
+        let options: Result<QueryOptions, _> = serde_json::from_value(value.clone());
+        if let Err(error) = options {
+            let exception = ConnectException::new(format!("Invalid query options: {}", error));
+            return Err(exception);
+        }
+
+        let options = options.unwrap();
+        options.validate()?;
+
+        Ok(options)
+    }
+
+    pub fn page_size(&self) -> Option<usize> {
+        self.page_size
+    }
+
+    pub fn sort(&self) -> Vec<SortField> {
+        self.sort.clone()
+    }
+
+    pub fn projection(&self) -> Vec<String> {
+        self.projection.clone()
+    }
+
+    pub fn display_fields(&self) -> Vec<String> {
+        self.display_fields.clone()
+    }
+
+    pub fn max_documents(&self) -> Option<usize> {
+        self.max_documents
+    }
+
+    pub fn set_page_size(&mut self, page_size: usize) -> &mut Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    pub fn set_sort(&mut self, sort: Vec<SortField>) -> &mut Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn set_projection(&mut self, projection: Vec<String>) -> &mut Self {
+        self.projection = projection;
+        self
+    }
+
+    pub fn set_display_fields(&mut self, display_fields: Vec<String>) -> &mut Self {
+        self.display_fields = display_fields;
+        self
+    }
+
+    pub fn set_max_documents(&mut self, max_documents: usize) -> &mut Self {
+        self.max_documents = Some(max_documents);
+        self
+    }
+
+    pub fn validate(&self) -> Result<(), ConnectException> {
+        if self.page_size == Some(0) || self.max_documents == Some(0) {
+            return Err(ConnectException::new(String::from("Query option sizes must be greater than zero.")));
+        }
+
+        if self.sort.iter().any(|s| s.field().is_empty()) || self.projection.iter().any(|f| f.is_empty()) {
+            return Err(ConnectException::new(String::from("Query option fields cannot be empty.")));
+        }
+
+        Ok(())
+    }
+
+    pub fn apply(&self, query: &DocumentQuery) -> DocumentQuery {
+        let mut query = query.clone();
+
+        if query.sort().is_empty() {
+            query.set_sort(self.sort());
+        }
+
+        if query.projection().is_empty() {
+            query.set_projection(self.projection());
+        }
+
+        let limit = query.limit().or(self.page_size);
+        let limit = match (limit, self.max_documents) {
+            (Some(limit), Some(max)) => Some(limit.min(max)),
+            (limit, max) => limit.or(max),
+        };
+        query.set_limit(limit);
+
+        query
+    }
+
+    pub fn as_json(&self) -> Value {
+        json!({
+            "page_size": self.page_size,
+            "sort": self.sort.iter().map(|s| s.as_json()).collect::<Vec<Value>>(),
+            "projection": self.projection,
+            "display_fields": self.display_fields,
+            "max_documents": self.max_documents
+        })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use serde_json::json;
+
+    use crate::domain::{filter::{document_query::DocumentQuery, sort_field::SortField}, preference::{e_preference_layer::EPreferenceLayer, preferences::Preferences}};
+
+    use super::QueryOptions;
+
+    fn query() -> DocumentQuery {
+        DocumentQuery::from(String::from("shop"), String::from("orders"), None, None, None)
+    }
+
+    fn options() -> QueryOptions {
+        let mut options = QueryOptions::new();
+        options.set_page_size(20)
+            .set_sort(vec![SortField::descending(String::from("created"))])
+            .set_projection(vec![String::from("status")]);
+        options
+    }
+
+    #[test]
+    fn defaults_fill_an_open_query() {
+        let applied = options().apply(&query());
+
+        assert_eq!(applied.limit(), Some(20));
+        assert_eq!(applied.sort(), vec![SortField::descending(String::from("created"))]);
+        assert_eq!(applied.projection(), vec![String::from("status")]);
+    }
+
+    #[test]
+    fn explicit_query_settings_win() {
+        let mut explicit = query();
+        explicit.set_limit(Some(5))
+            .set_sort(vec![SortField::ascending(String::from("total"))])
+            .set_projection(vec![String::from("total")]);
+
+        let applied = options().apply(&explicit);
+
+        assert_eq!(applied.limit(), Some(5));
+        assert_eq!(applied.sort(), vec![SortField::ascending(String::from("total"))]);
+        assert_eq!(applied.projection(), vec![String::from("total")]);
+    }
+
+    #[test]
+    fn max_documents_caps_the_limit() {
+        let mut options = options();
+        options.set_max_documents(10);
+
+        assert_eq!(options.apply(&query()).limit(), Some(10));
+
+        let mut explicit = query();
+        explicit.set_limit(Some(500));
+        assert_eq!(options.apply(&explicit).limit(), Some(10));
+    }
+
+    #[test]
+    fn invalid_options_are_rejected() {
+        assert!(QueryOptions::from_json(&json!({"page_size": 0})).is_err());
+        assert!(QueryOptions::from_json(&json!({"sort": [{"field": ""}]})).is_err());
+        assert!(QueryOptions::from_json(&json!({"sort": [{"field": "name"}]})).is_ok());
+    }
+
+    #[test]
+    fn preferences_resolve_per_collection_by_layer() {
+        let mut user = options();
+        user.set_page_size(100);
+
+        let mut preferences = Preferences::new();
+        preferences.set_query_options(&EPreferenceLayer::WORKSPACE, "shop", "orders", &options());
+        preferences.set_query_options(&EPreferenceLayer::USER, "shop", "orders", &user);
+
+        assert_eq!(preferences.query_options("shop", "orders").and_then(|o| o.page_size()), Some(100));
+        assert!(preferences.query_options("shop", "customers").is_none());
+
+        preferences.unset_query_options(&EPreferenceLayer::USER, "shop", "orders");
+        assert_eq!(preferences.query_options("shop", "orders").and_then(|o| o.page_size()), Some(20));
+    }
+
+}
\ No newline at end of file
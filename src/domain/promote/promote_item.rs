@@ -0,0 +1,30 @@
+use crate::domain::filter::{collection_query::CollectionQuery, document_query::DocumentQuery};
+
+#[derive(Clone)]
+pub struct PromoteItem {
+    source: DocumentQuery,
+    destination: CollectionQuery
+}
+
+impl PromoteItem {
+
+    pub fn new(source: DocumentQuery, destination: CollectionQuery) -> Self {
+        Self {
+            source, destination
+        }
+    }
+
+    pub fn collection(source: CollectionQuery) -> Self {
+        let query = DocumentQuery::from(source.data_base(), source.collection(), None, None, None);
+        Self::new(query, source)
+    }
+
+    pub fn source(&self) -> DocumentQuery {
+        self.source.clone()
+    }
+
+    pub fn destination(&self) -> CollectionQuery {
+        self.destination.clone()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,36 @@
+use crate::domain::collection::copy_report::CopyReport;
+
+#[derive(Debug, Clone)]
+pub struct PromoteItemReport {
+    source: String,
+    destination: String,
+    copy: CopyReport
+}
+
+impl PromoteItemReport {
+
+    pub fn new(source: String, destination: String, copy: CopyReport) -> Self {
+        Self {
+            source, destination, copy
+        }
+    }
+
+    pub fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn destination(&self) -> String {
+        self.destination.clone()
+    }
+
+    pub fn copy(&self) -> CopyReport {
+        self.copy.clone()
+    }
+
+    pub fn payload(&self) -> String {
+        format!("{}>{}:{}/{}/{}/{}/{}",
+            self.source, self.destination,
+            self.copy.read(), self.copy.inserted(), self.copy.overwritten(), self.copy.skipped(), self.copy.failed())
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::domain::collection::copy_options::CopyOptions;
+
+#[derive(Debug, Clone)]
+pub struct PromoteOptions {
+    copy: CopyOptions,
+    confirmation: Option<String>,
+    force: bool
+}
+
+impl PromoteOptions {
+
+    pub fn new(copy: CopyOptions) -> PromoteOptions {
+        PromoteOptions {
+            copy,
+            confirmation: None,
+            force: false
+        }
+    }
+
+    pub fn copy(&self) -> CopyOptions {
+        return self.copy.clone();
+    }
+
+    pub fn confirmation(&self) -> Option<String> {
+        return self.confirmation.clone();
+    }
+
+    pub fn is_force(&self) -> bool {
+        return self.force;
+    }
+
+    pub fn set_confirmation(&mut self, confirmation: String) -> &mut PromoteOptions {
+        self.confirmation = Some(confirmation);
+        return self;
+    }
+
+    pub fn set_force(&mut self, force: bool) -> &mut PromoteOptions {
+        self.force = force;
+        return self;
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,62 @@
+use crate::commons::secret::signature;
+
+use super::promote_item_report::PromoteItemReport;
+
+#[derive(Debug, Clone)]
+pub struct PromoteReport {
+    source: String,
+    target: String,
+    timestamp: u128,
+    masked: bool,
+    items: Vec<PromoteItemReport>,
+    signature: String
+}
+
+impl PromoteReport {
+
+    pub fn new(source: String, target: String, timestamp: u128, masked: bool, items: Vec<PromoteItemReport>) -> Self {
+        Self {
+            source, target, timestamp, masked, items,
+            signature: String::new()
+        }
+    }
+
+    pub fn source(&self) -> String {
+        self.source.clone()
+    }
+
+    pub fn target(&self) -> String {
+        self.target.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+    pub fn is_masked(&self) -> bool {
+        self.masked
+    }
+
+    pub fn items(&self) -> Vec<PromoteItemReport> {
+        self.items.clone()
+    }
+
+    pub fn signature(&self) -> String {
+        self.signature.clone()
+    }
+
+    pub fn payload(&self) -> String {
+        let items: Vec<String> = self.items.iter().map(|i| i.payload()).collect();
+        format!("{}|{}|{}|{}|{}", self.source, self.target, self.timestamp, self.masked, items.join(";"))
+    }
+
+    pub fn sign(&mut self, key: &str) -> &mut Self {
+        self.signature = signature::sign(key, &self.payload());
+        self
+    }
+
+    pub fn verify(&self, key: &str) -> bool {
+        signature::verify(key, &self.payload(), &self.signature)
+    }
+
+}
\ No newline at end of file
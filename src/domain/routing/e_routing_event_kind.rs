@@ -0,0 +1,39 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum ERoutingEventKind {
+    PINNED,
+    UNPINNED,
+    PRIMARYLOST,
+    PRIMARYELECTED,
+    FAILOVER,
+    SERVERDOWN,
+    SERVERUP
+}
+
+impl ERoutingEventKind {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ERoutingEventKind::PINNED => String::from("PINNED"),
+            ERoutingEventKind::UNPINNED => String::from("UNPINNED"),
+            ERoutingEventKind::PRIMARYLOST => String::from("PRIMARYLOST"),
+            ERoutingEventKind::PRIMARYELECTED => String::from("PRIMARYELECTED"),
+            ERoutingEventKind::FAILOVER => String::from("FAILOVER"),
+            ERoutingEventKind::SERVERDOWN => String::from("SERVERDOWN"),
+            ERoutingEventKind::SERVERUP => String::from("SERVERUP"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<ERoutingEventKind> {
+        match code {
+            "PINNED" => Some(ERoutingEventKind::PINNED),
+            "UNPINNED" => Some(ERoutingEventKind::UNPINNED),
+            "PRIMARYLOST" => Some(ERoutingEventKind::PRIMARYLOST),
+            "PRIMARYELECTED" => Some(ERoutingEventKind::PRIMARYELECTED),
+            "FAILOVER" => Some(ERoutingEventKind::FAILOVER),
+            "SERVERDOWN" => Some(ERoutingEventKind::SERVERDOWN),
+            "SERVERUP" => Some(ERoutingEventKind::SERVERUP),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,41 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use super::e_routing_event_kind::ERoutingEventKind;
+
+#[derive(Debug, Clone)]
+pub struct RoutingEvent {
+    kind: ERoutingEventKind,
+    host: String,
+    detail: String,
+    timestamp: u128
+}
+
+impl RoutingEvent {
+
+    pub fn new(kind: ERoutingEventKind, host: String, detail: String) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis())
+            .unwrap_or_default();
+        Self {
+            kind, host, detail, timestamp
+        }
+    }
+
+    pub fn kind(&self) -> ERoutingEventKind {
+        self.kind.clone()
+    }
+
+    pub fn host(&self) -> String {
+        self.host.clone()
+    }
+
+    pub fn detail(&self) -> String {
+        self.detail.clone()
+    }
+
+    pub fn timestamp(&self) -> u128 {
+        self.timestamp
+    }
+
+}
\ No newline at end of file
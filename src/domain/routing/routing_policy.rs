@@ -0,0 +1,46 @@
+#[derive(Debug, Clone)]
+pub struct RoutingPolicy {
+    sticky: bool,
+    failover: bool
+}
+
+impl Default for RoutingPolicy {
+
+    fn default() -> Self {
+        RoutingPolicy {
+            sticky: false,
+            failover: true
+        }
+    }
+
+}
+
+impl RoutingPolicy {
+
+    pub fn new() -> RoutingPolicy {
+        RoutingPolicy::default()
+    }
+
+    pub fn is_sticky(&self) -> bool {
+        return self.sticky;
+    }
+
+    pub fn is_failover(&self) -> bool {
+        return self.failover;
+    }
+
+    pub fn set_sticky(&mut self, sticky: bool) -> &mut RoutingPolicy {
+        self.sticky = sticky;
+        return self;
+    }
+
+    pub fn set_failover(&mut self, failover: bool) -> &mut RoutingPolicy {
+        self.failover = failover;
+        return self;
+    }
+
+    pub fn key(&self) -> String {
+        return format!("{}|{}", self.sticky, self.failover);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,32 @@
+#[derive(Debug, Clone)]
+pub struct ServerTime {
+    server: i64,
+    client: i64,
+    latency: i64
+}
+
+impl ServerTime {
+
+    pub fn new(server: i64, client: i64, latency: i64) -> Self {
+        Self {
+            server, client, latency
+        }
+    }
+
+    pub fn server(&self) -> i64 {
+        self.server
+    }
+
+    pub fn client(&self) -> i64 {
+        self.client
+    }
+
+    pub fn latency(&self) -> i64 {
+        self.latency
+    }
+
+    pub fn skew(&self) -> i64 {
+        self.server - self.client
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,74 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[derive(Debug, Clone)]
+pub struct SessionState {
+    alias: String,
+    data_base: Option<String>,
+    collection: Option<String>,
+    opened_at: u128,
+    last_used: u128
+}
+
+impl SessionState {
+
+    pub fn new(alias: String) -> SessionState {
+        let timestamp = SessionState::now();
+        SessionState {
+            alias,
+            data_base: None,
+            collection: None,
+            opened_at: timestamp,
+            last_used: timestamp
+        }
+    }
+
+    fn now() -> u128 {
+        return SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
+    }
+
+    pub fn alias(&self) -> String {
+        return self.alias.clone();
+    }
+
+    pub fn data_base(&self) -> Option<String> {
+        return self.data_base.clone();
+    }
+
+    pub fn collection(&self) -> Option<String> {
+        return self.collection.clone();
+    }
+
+    pub fn opened_at(&self) -> u128 {
+        return self.opened_at;
+    }
+
+    pub fn last_used(&self) -> u128 {
+        return self.last_used;
+    }
+
+    pub fn set_data_base(&mut self, data_base: Option<String>) -> &mut SessionState {
+        if self.data_base != data_base {
+            self.collection = None;
+        }
+        self.data_base = data_base;
+        return self;
+    }
+
+    pub fn set_collection(&mut self, collection: Option<String>) -> &mut SessionState {
+        self.collection = collection;
+        return self;
+    }
+
+    pub fn idle_for(&self) -> u128 {
+        return SessionState::now().saturating_sub(self.last_used);
+    }
+
+    pub fn touch(&mut self) -> &mut SessionState {
+        self.last_used = SessionState::now();
+        return self;
+    }
+
+}
\ No newline at end of file
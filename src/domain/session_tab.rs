@@ -0,0 +1,44 @@
+use uuid::Uuid;
+
+use super::session_state::SessionState;
+
+#[derive(Debug, Clone)]
+pub struct SessionTab {
+    id: String,
+    state: SessionState
+}
+
+impl SessionTab {
+
+    pub fn new(alias: String) -> SessionTab {
+        SessionTab {
+            id: Uuid::new_v4().to_string(),
+            state: SessionState::new(alias)
+        }
+    }
+
+    pub fn id(&self) -> String {
+        return self.id.clone();
+    }
+
+    pub fn alias(&self) -> String {
+        return self.state.alias();
+    }
+
+    pub fn state(&self) -> SessionState {
+        return self.state.clone();
+    }
+
+    pub fn set_location(&mut self, data_base: Option<String>, collection: Option<String>) -> &mut SessionTab {
+        self.state.set_data_base(data_base);
+        self.state.set_collection(collection);
+        self.state.touch();
+        return self;
+    }
+
+    pub fn touch(&mut self) -> &mut SessionTab {
+        self.state.touch();
+        return self;
+    }
+
+}
\ No newline at end of file
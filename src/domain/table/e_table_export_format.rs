@@ -0,0 +1,24 @@
+#[derive(Debug, Clone, PartialEq)]
+pub enum ETableExportFormat {
+    JSON,
+    PROMETHEUS
+}
+
+impl ETableExportFormat {
+
+    pub fn to_string(&self) -> String {
+        match self {
+            ETableExportFormat::JSON => String::from("JSON"),
+            ETableExportFormat::PROMETHEUS => String::from("PROMETHEUS"),
+        }
+    }
+
+    pub fn from_string(code: &str) -> Option<ETableExportFormat> {
+        match code {
+            "JSON" => Some(ETableExportFormat::JSON),
+            "PROMETHEUS" => Some(ETableExportFormat::PROMETHEUS),
+            _ => None
+        }
+    }
+
+}
\ No newline at end of file
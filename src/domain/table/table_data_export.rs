@@ -0,0 +1,94 @@
+use serde_json::{json, Map, Value};
+
+use super::{e_table_export_format::ETableExportFormat, table_data_group::TableDataGroup};
+
+const METRIC_PREFIX: &str = "rust_db_manager";
+
+#[derive(Debug, Clone, Default)]
+pub struct TableDataExport {
+    entries: Vec<(Vec<(String, String)>, TableDataGroup)>
+}
+
+impl TableDataExport {
+
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new()
+        }
+    }
+
+    pub fn push(&mut self, labels: Vec<(String, String)>, groups: Vec<TableDataGroup>) -> &mut Self {
+        for group in groups {
+            self.entries.push((labels.clone(), group));
+        }
+        self
+    }
+
+    pub fn render(&self, format: ETableExportFormat) -> String {
+        match format {
+            ETableExportFormat::JSON => self.as_json(),
+            ETableExportFormat::PROMETHEUS => self.as_prometheus(),
+        }
+    }
+
+    fn as_json(&self) -> String {
+        let entries: Vec<Value> = self.entries.iter()
+            .map(|(labels, group)| {
+                let labels: Map<String, Value> = labels.iter()
+                    .map(|(k, v)| (k.clone(), Value::String(v.clone())))
+                    .collect();
+                let fields: Map<String, Value> = group.fields().iter()
+                    .map(|f| (f.name(), Value::String(f.value())))
+                    .collect();
+                json!({
+                    "labels": labels,
+                    "group": group.name(),
+                    "fields": fields
+                })
+            })
+            .collect();
+
+        Value::Array(entries).to_string()
+    }
+
+    fn as_prometheus(&self) -> String {
+        let mut lines = Vec::new();
+        for (labels, group) in &self.entries {
+            let labels = labels.iter()
+                .map(|(k, v)| format!("{}=\"{}\"", Self::metric_name(k), v.replace('\\', "\\\\").replace('"', "\\\"")))
+                .collect::<Vec<String>>()
+                .join(",");
+
+            for field in group.fields() {
+                let value = match Self::numeric(&field.value()) {
+                    Some(value) => value,
+                    None => continue,
+                };
+
+                let name = format!("{}_{}_{}", METRIC_PREFIX, Self::metric_name(&group.name()), Self::metric_name(&field.name()));
+                if labels.is_empty() {
+                    lines.push(format!("{} {}", name, value));
+                } else {
+                    lines.push(format!("{}{{{}}} {}", name, labels, value));
+                }
+            }
+        }
+
+        let mut result = lines.join("\n");
+        result.push('\n');
+        result
+    }
+
+    fn metric_name(name: &str) -> String {
+        name.trim().to_lowercase().chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    }
+
+    fn numeric(value: &str) -> Option<f64> {
+        let value = value.trim().trim_matches('"');
+        let value = value.strip_suffix(" Bytes").unwrap_or(value);
+        value.parse::<f64>().ok()
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,25 @@
+use crate::domain::document::document_data::DocumentData;
+
+#[derive(Debug, Clone)]
+pub struct UpdatePreview {
+    total: usize,
+    samples: Vec<DocumentData>
+}
+
+impl UpdatePreview {
+
+    pub fn new(total: usize, samples: Vec<DocumentData>) -> Self {
+        Self {
+            total, samples
+        }
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    pub fn samples(&self) -> Vec<DocumentData> {
+        self.samples.clone()
+    }
+
+}
\ No newline at end of file
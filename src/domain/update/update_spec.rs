@@ -0,0 +1,171 @@
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::document::document_update_field::DocumentUpdateField};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UpdateSpec {
+    #[serde(default)]
+    set: Map<String, Value>,
+    #[serde(default)]
+    unset: Vec<String>,
+    #[serde(default)]
+    rename: Map<String, Value>,
+    #[serde(default)]
+    inc: Map<String, Value>,
+    #[serde(default)]
+    mul: Map<String, Value>,
+    #[serde(default)]
+    push: Map<String, Value>,
+    #[serde(default)]
+    pull: Map<String, Value>,
+    #[serde(default)]
+    add_to_set: Map<String, Value>
+}
+
+impl UpdateSpec {
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn from_string(spec: &str) -> Result<Self, ConnectException> {
+        let result: Result<UpdateSpec, _> = serde_json::from_str(spec);
+        if let Err(error) = result {
+            let exception = ConnectException::new(format!("Invalid update specification: {}", error));
+            return Err(exception);
+        }
+
+        let spec = result.unwrap();
+        spec.validate()?;
+
+        Ok(spec)
+    }
+
+    pub fn from_fields(fields: &[DocumentUpdateField]) -> Result<Self, ConnectException> {
+        let mut spec = UpdateSpec::new();
+        for field in fields {
+            match field.value() {
+                Some(value) => {
+                    let json: Result<Value, _> = serde_json::from_str(&value);
+                    if let Err(error) = json {
+                        let message = format!("Invalid value for '{}': {}", field.field(), error);
+                        return Err(ConnectException::new(message));
+                    }
+                    spec.set(field.field(), json.unwrap());
+                },
+                None => {
+                    spec.unset(field.field());
+                },
+            }
+        }
+
+        spec.validate()?;
+
+        Ok(spec)
+    }
+
+    pub fn set(&mut self, field: String, value: Value) -> &mut Self {
+        self.set.insert(field, value);
+        self
+    }
+
+    pub fn unset(&mut self, field: String) -> &mut Self {
+        self.unset.push(field);
+        self
+    }
+
+    pub fn rename(&mut self, field: String, target: String) -> &mut Self {
+        self.rename.insert(field, Value::String(target));
+        self
+    }
+
+    pub fn inc(&mut self, field: String, amount: Value) -> &mut Self {
+        self.inc.insert(field, amount);
+        self
+    }
+
+    pub fn mul(&mut self, field: String, factor: Value) -> &mut Self {
+        self.mul.insert(field, factor);
+        self
+    }
+
+    pub fn push(&mut self, field: String, value: Value) -> &mut Self {
+        self.push.insert(field, value);
+        self
+    }
+
+    pub fn pull(&mut self, field: String, value: Value) -> &mut Self {
+        self.pull.insert(field, value);
+        self
+    }
+
+    pub fn add_to_set(&mut self, field: String, value: Value) -> &mut Self {
+        self.add_to_set.insert(field, value);
+        self
+    }
+
+    pub fn set_fields(&self) -> Map<String, Value> {
+        self.set.clone()
+    }
+
+    pub fn unset_fields(&self) -> Vec<String> {
+        self.unset.clone()
+    }
+
+    pub fn rename_fields(&self) -> Vec<(String, String)> {
+        self.rename.iter()
+            .filter_map(|(k, v)| v.as_str().map(|t| (k.clone(), String::from(t))))
+            .collect()
+    }
+
+    pub fn inc_fields(&self) -> Map<String, Value> {
+        self.inc.clone()
+    }
+
+    pub fn mul_fields(&self) -> Map<String, Value> {
+        self.mul.clone()
+    }
+
+    pub fn push_fields(&self) -> Map<String, Value> {
+        self.push.clone()
+    }
+
+    pub fn pull_fields(&self) -> Map<String, Value> {
+        self.pull.clone()
+    }
+
+    pub fn add_to_set_fields(&self) -> Map<String, Value> {
+        self.add_to_set.clone()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty() && self.unset.is_empty() && self.rename.is_empty()
+            && self.inc.is_empty() && self.mul.is_empty() && self.push.is_empty()
+            && self.pull.is_empty() && self.add_to_set.is_empty()
+    }
+
+    pub fn validate(&self) -> Result<(), ConnectException> {
+        if self.is_empty() {
+            return Err(ConnectException::new(String::from("Update specification is empty.")));
+        }
+
+        if let Some((field, _)) = self.rename.iter().find(|(_, v)| !v.is_string()) {
+            let message = format!("Rename target for '{}' must be a string.", field);
+            return Err(ConnectException::new(message));
+        }
+
+        if let Some((field, _)) = self.inc.iter().chain(self.mul.iter()).find(|(_, v)| !v.is_number()) {
+            let message = format!("Arithmetic operand for '{}' must be a number.", field);
+            return Err(ConnectException::new(message));
+        }
+
+        let operators = [&self.set, &self.rename, &self.inc, &self.mul, &self.push, &self.pull, &self.add_to_set];
+        if operators.iter().any(|o| o.contains_key("_id")) || self.unset.iter().any(|f| f == "_id") {
+            return Err(ConnectException::new(String::from("The '_id' field cannot be modified.")));
+        }
+
+        Ok(())
+    }
+
+}
\ No newline at end of file
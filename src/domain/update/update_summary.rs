@@ -0,0 +1,23 @@
+#[derive(Debug, Clone)]
+pub struct UpdateSummary {
+    matched: usize,
+    modified: usize
+}
+
+impl UpdateSummary {
+
+    pub fn new(matched: usize, modified: usize) -> Self {
+        Self {
+            matched, modified
+        }
+    }
+
+    pub fn matched(&self) -> usize {
+        self.matched
+    }
+
+    pub fn modified(&self) -> usize {
+        self.modified
+    }
+
+}
\ No newline at end of file
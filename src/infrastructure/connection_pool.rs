@@ -0,0 +1,100 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+const DEFAULT_MAX_CONNECTIONS: usize = 16;
+const DEFAULT_IDLE_TIMEOUT: Duration = Duration::from_secs(600);
+
+struct PoolEntry<C> {
+    client: C,
+    last_used: Instant
+}
+
+pub struct ConnectionPool<C: Clone> {
+    max_connections: usize,
+    idle_timeout: Duration,
+    entries: HashMap<String, PoolEntry<C>>
+}
+
+impl <C: Clone> Default for ConnectionPool<C> {
+
+    fn default() -> Self {
+        ConnectionPool {
+            max_connections: DEFAULT_MAX_CONNECTIONS,
+            idle_timeout: DEFAULT_IDLE_TIMEOUT,
+            entries: HashMap::new()
+        }
+    }
+
+}
+
+impl <C: Clone> ConnectionPool<C> {
+
+    pub fn new() -> ConnectionPool<C> {
+        ConnectionPool::default()
+    }
+
+    pub fn max_connections(&self) -> usize {
+        self.max_connections
+    }
+
+    pub fn idle_timeout(&self) -> Duration {
+        self.idle_timeout
+    }
+
+    pub fn set_max_connections(&mut self, max_connections: usize) -> &mut ConnectionPool<C> {
+        self.max_connections = max_connections.max(1);
+        self
+    }
+
+    pub fn set_idle_timeout(&mut self, idle_timeout: Duration) -> &mut ConnectionPool<C> {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn checkout(&mut self, key: &str) -> Option<(C, Duration)> {
+        self.evict_idle();
+
+        let entry = self.entries.get_mut(key)?;
+        let idle = entry.last_used.elapsed();
+        entry.last_used = Instant::now();
+
+        Some((entry.client.clone(), idle))
+    }
+
+    pub fn store(&mut self, key: String, client: C) {
+        self.evict_idle();
+
+        while !self.entries.contains_key(&key) && self.entries.len() >= self.max_connections {
+            let oldest = self.entries.iter()
+                .min_by_key(|(_, e)| e.last_used)
+                .map(|(k, _)| k.clone());
+            match oldest {
+                Some(oldest) => self.entries.remove(&oldest),
+                None => break,
+            };
+        }
+
+        self.entries.insert(key, PoolEntry { client, last_used: Instant::now() });
+    }
+
+    pub fn evict(&mut self, key: &str) -> Option<C> {
+        self.entries.remove(key).map(|e| e.client)
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn evict_idle(&mut self) {
+        let idle_timeout = self.idle_timeout;
+        self.entries.retain(|_, e| e.last_used.elapsed() < idle_timeout);
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,328 @@
+use std::{fs::{self, OpenOptions}, io::{self, Write}, path::{Path, PathBuf}, sync::Arc};
+
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+use serde_json::{json, Map, Value};
+
+use crate::{commons::{exception::connect_exception::ConnectException, secret::i_secret_store::ISecretStore}, domain::{connection_credential::{split_connection, ConnectionCredential}, connection_data::ConnectionData, connection_profile::ConnectionProfile, connection_tls::ConnectionTls, document::{e_id_strategy::EIdStrategy, lineage_policy::LineagePolicy, soft_delete_policy::{SoftDeletePolicy, DEFAULT_FIELD, DEFAULT_RETENTION}}, e_auth_mechanism::EAuthMechanism, e_read_preference::EReadPreference, routing::routing_policy::RoutingPolicy}};
+
+use super::repository::e_db_repository::EDBRepository;
+
+const PROFILES_KEY: &str = "profiles";
+
+#[derive(Clone)]
+pub struct ConnectionProfileRepository {
+    path: PathBuf,
+    store: Option<Arc<dyn ISecretStore>>
+}
+
+impl ConnectionProfileRepository {
+
+    pub fn new(path: PathBuf) -> ConnectionProfileRepository {
+        ConnectionProfileRepository {
+            path,
+            store: None
+        }
+    }
+
+    pub fn from_store(path: PathBuf, store: Arc<dyn ISecretStore>) -> ConnectionProfileRepository {
+        ConnectionProfileRepository {
+            path,
+            store: Some(store)
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        return self.path.clone();
+    }
+
+    pub fn find_all(&self) -> Result<Vec<ConnectionProfile>, ConnectException> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let json = json.unwrap();
+        let Some(items) = json.get(PROFILES_KEY).and_then(|p| p.as_array()) else {
+            let exception = ConnectException::new(format!("Profile file '{}' has no '{}' list.", self.path.display(), PROFILES_KEY));
+            return Err(exception);
+        };
+
+        let mut profiles = Vec::new();
+        for item in items {
+            profiles.push(self.profile_from_json(item)?);
+        }
+
+        return Ok(profiles);
+    }
+
+    pub fn find(&self, alias: &str) -> Result<Option<ConnectionProfile>, ConnectException> {
+        let profiles = self.find_all()?;
+        return Ok(profiles.into_iter().find(|p| p.alias() == alias));
+    }
+
+    pub fn save(&self, profile: &ConnectionProfile) -> Result<(), ConnectException> {
+        let mut profiles = self.find_all()?;
+        match profiles.iter().position(|p| p.is_same(profile)) {
+            Some(position) => profiles[position] = profile.clone(),
+            None => profiles.push(profile.clone()),
+        }
+        return self.write(&profiles);
+    }
+
+    pub fn delete(&self, alias: &str) -> Result<bool, ConnectException> {
+        let mut profiles = self.find_all()?;
+        let size = profiles.len();
+        profiles.retain(|p| p.alias() != alias);
+        if profiles.len() == size {
+            return Ok(false);
+        }
+        self.write(&profiles)?;
+        return Ok(true);
+    }
+
+    fn write(&self, profiles: &[ConnectionProfile]) -> Result<(), ConnectException> {
+        let mut items = Vec::new();
+        for profile in profiles {
+            items.push(self.profile_as_json(profile)?);
+        }
+
+        let content = serde_json::to_string_pretty(&json!({ PROFILES_KEY: items }));
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let temporal = self.path.with_extension("tmp");
+        if let Err(error) = self.write_private(&temporal, content.unwrap().as_bytes()) {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::rename(&temporal, &self.path) {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(());
+    }
+
+    fn write_private(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+        if path.exists() {
+            fs::remove_file(path)?;
+        }
+
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        options.mode(0o600);
+
+        let mut file = options.open(path)?;
+        file.write_all(content)?;
+        return file.sync_all();
+    }
+
+    fn profile_as_json(&self, profile: &ConnectionProfile) -> Result<Value, ConnectException> {
+        let data = profile.connection_data();
+
+        let (connection, embedded) = split_connection(&data.connection());
+
+        let credential = match data.credential().or(embedded) {
+            Some(credential) => Some(self.seal_credential(&profile.alias(), &credential)?),
+            None => None,
+        };
+
+        let tls = data.tls().map(|tls| json!({
+            "ca_file": tls.ca_file(),
+            "cert_key_file": tls.cert_key_file(),
+            "allow_invalid_certificates": tls.allow_invalid_certificates(),
+            "allow_invalid_hostnames": tls.allow_invalid_hostnames()
+        }));
+
+        let routing = data.routing().map(|routing| json!({
+            "sticky": routing.is_sticky(),
+            "failover": routing.is_failover()
+        }));
+
+        let lineage = data.lineage().map(|lineage| json!({
+            "modified_by": lineage.modified_by(),
+            "job_id": lineage.job_id()
+        }));
+
+        let credential = credential.map(|credential| json!({
+            "username": credential.username(),
+            "password": credential.password(),
+            "source": credential.source(),
+            "mechanism": credential.mechanism().map(|m| m.to_string())
+        }));
+
+        let id_strategies: Map<String, Value> = data.id_strategies().into_iter()
+            .map(|(k, v)| (k, Value::String(v.to_string())))
+            .collect();
+
+        let soft_deletes: Map<String, Value> = data.soft_deletes().into_iter()
+            .map(|(k, v)| (k, json!({
+                "field": v.field(),
+                "retention": v.retention()
+            })))
+            .collect();
+
+        return Ok(json!({
+            "alias": profile.alias(),
+            "category": data.category().to_string(),
+            "connection": connection,
+            "read_preference": data.read_preference().map(|r| r.to_string()),
+            "routing": routing,
+            "tls": tls,
+            "credential": credential,
+            "id_strategies": id_strategies,
+            "soft_deletes": soft_deletes,
+            "lineage": lineage,
+            "tags": profile.tags()
+        }));
+    }
+
+    fn profile_from_json(&self, json: &Value) -> Result<ConnectionProfile, ConnectException> {
+        let alias = self.string_field(json, "alias")?;
+
+        let code = self.string_field(json, "category")?;
+        let Some(category) = EDBRepository::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown repository category '{}' in profile '{}'.", code, alias)));
+        };
+
+        let mut data = ConnectionData::new(category, self.string_field(json, "connection")?);
+
+        if let Some(code) = json.get("read_preference").and_then(|r| r.as_str()) {
+            let Some(read_preference) = EReadPreference::from_string(code) else {
+                return Err(ConnectException::new(format!("Unknown read preference '{}' in profile '{}'.", code, alias)));
+            };
+            data.set_read_preference(read_preference);
+        }
+
+        if let Some(value) = json.get("routing").filter(|r| r.is_object()) {
+            let mut routing = RoutingPolicy::new();
+            routing.set_sticky(value.get("sticky").and_then(|v| v.as_bool()).unwrap_or(false));
+            routing.set_failover(value.get("failover").and_then(|v| v.as_bool()).unwrap_or(true));
+            data.set_routing(routing);
+        }
+
+        if let Some(value) = json.get("tls").filter(|t| t.is_object()) {
+            let mut tls = ConnectionTls::new();
+            if let Some(ca_file) = value.get("ca_file").and_then(|v| v.as_str()) {
+                tls.set_ca_file(String::from(ca_file));
+            }
+            if let Some(cert_key_file) = value.get("cert_key_file").and_then(|v| v.as_str()) {
+                tls.set_cert_key_file(String::from(cert_key_file));
+            }
+            tls.set_allow_invalid_certificates(value.get("allow_invalid_certificates").and_then(|v| v.as_bool()).unwrap_or(false));
+            tls.set_allow_invalid_hostnames(value.get("allow_invalid_hostnames").and_then(|v| v.as_bool()).unwrap_or(false));
+            data.set_tls(tls);
+        }
+
+        if let Some(value) = json.get("credential").filter(|c| c.is_object()) {
+            let username = self.string_field(value, "username")?;
+            let password = value.get("password").and_then(|v| v.as_str()).map(String::from);
+
+            let mut credential = ConnectionCredential::new(username, password);
+            if let Some(source) = value.get("source").and_then(|v| v.as_str()) {
+                credential.set_source(String::from(source));
+            }
+            if let Some(code) = value.get("mechanism").and_then(|v| v.as_str()) {
+                let Some(mechanism) = EAuthMechanism::from_string(code) else {
+                    return Err(ConnectException::new(format!("Unknown auth mechanism '{}' in profile '{}'.", code, alias)));
+                };
+                credential.set_mechanism(mechanism);
+            }
+
+            data.set_credential(self.unseal_credential(&alias, &credential)?);
+        }
+
+        if let Some(strategies) = json.get("id_strategies").and_then(|s| s.as_object()) {
+            for (key, value) in strategies {
+                let strategy = value.as_str().and_then(EIdStrategy::from_string);
+                let Some((data_base, collection)) = key.split_once('.') else {
+                    continue;
+                };
+                if let Some(strategy) = strategy {
+                    data.set_id_strategy(data_base, collection, strategy);
+                }
+            }
+        }
+
+        if let Some(policies) = json.get("soft_deletes").and_then(|s| s.as_object()) {
+            for (key, value) in policies {
+                let Some((data_base, collection)) = key.split_once('.') else {
+                    continue;
+                };
+                let field = value.get("field").and_then(|f| f.as_str()).unwrap_or(DEFAULT_FIELD);
+                let retention = value.get("retention").and_then(|r| r.as_u64()).unwrap_or(DEFAULT_RETENTION);
+                data.set_soft_delete(data_base, collection, SoftDeletePolicy::from(String::from(field), retention));
+            }
+        }
+
+        if let Some(value) = json.get("lineage").filter(|l| l.is_object()) {
+            let mut lineage = LineagePolicy::new(self.string_field(value, "modified_by")?);
+            if let Some(job_id) = value.get("job_id").and_then(|j| j.as_str()) {
+                lineage.set_job_id(String::from(job_id));
+            }
+            data.set_lineage(lineage);
+        }
+
+        let mut profile = ConnectionProfile::new(alias, data);
+
+        if let Some(tags) = json.get("tags").and_then(|t| t.as_array()) {
+            for tag in tags.iter().filter_map(|t| t.as_str()) {
+                profile.add_tag(String::from(tag));
+            }
+        }
+
+        return Ok(profile);
+    }
+
+    fn seal_credential(&self, alias: &str, credential: &ConnectionCredential) -> Result<ConnectionCredential, ConnectException> {
+        if credential.password().is_none() {
+            return Ok(credential.clone());
+        }
+
+        let Some(store) = &self.store else {
+            let exception = ConnectException::new(format!("Profile '{}' has a password, a secret store is required to persist it.", alias));
+            return Err(exception);
+        };
+
+        return credential.seal(store.as_ref());
+    }
+
+    fn unseal_credential(&self, alias: &str, credential: &ConnectionCredential) -> Result<ConnectionCredential, ConnectException> {
+        if credential.password().is_none() {
+            return Ok(credential.clone());
+        }
+
+        let Some(store) = &self.store else {
+            let exception = ConnectException::new(format!("Profile '{}' has a sealed password, a secret store is required to read it.", alias));
+            return Err(exception);
+        };
+
+        return credential.unseal(store.as_ref());
+    }
+
+    fn string_field(&self, json: &Value, key: &str) -> Result<String, ConnectException> {
+        let Some(value) = json.get(key).and_then(|v| v.as_str()) else {
+            return Err(ConnectException::new(format!("Profile field '{}' is missing or not a string.", key)));
+        };
+        return Ok(String::from(value));
+    }
+
+}
\ No newline at end of file
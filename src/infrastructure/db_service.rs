@@ -8,7 +8,7 @@ use argon2::{
 
 use std::time::{SystemTime, UNIX_EPOCH};
 
-use crate::{commons::exception::connect_exception::ConnectException, domain::connection_data::ConnectionData, infrastructure::repository::{db_dictionary, i_db_repository::IDBRepository}, service::service::Service};
+use crate::{commons::exception::connect_exception::ConnectException, domain::{connection_data::ConnectionData, document::document_origin::DocumentOrigin}, infrastructure::repository::{db_dictionary, i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository}, service::service::Service};
 
 use super::repository::e_db_repository::EDBRepository;
 
@@ -102,13 +102,17 @@ impl DBService {
         self.connection_data.clone()
     }
 
+    pub fn origin(&self) -> DocumentOrigin {
+        DocumentOrigin::new(self.name(), self.category())
+    }
+
     pub fn is_same(&self, other: DBService) -> bool {
         self.name == other.name
     }
 
-    pub async fn instance(&self) -> Result<Service<impl IDBRepository>, ConnectException> {
+    pub async fn instance(&self) -> Result<Service<impl IDBRepository + IDBMaintenance>, ConnectException> {
         let repository = db_dictionary::find(&self.connection_data).await?;
-        Ok(Service::from(repository))
+        Ok(Service::from_origin(repository, self.origin()))
     }
 
 }
\ No newline at end of file
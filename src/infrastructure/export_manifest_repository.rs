@@ -0,0 +1,195 @@
+use std::path::{Path, PathBuf};
+
+use ring::digest;
+use serde_json::{json, Value};
+use tokio::{fs::{self, File}, io::AsyncReadExt};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::{document::document_origin::DocumentOrigin, manifest::{e_export_kind::EExportKind, export_manifest::{ExportManifest, MANIFEST_VERSION}, manifest_check::ManifestCheck, manifest_file::ManifestFile}}};
+
+use super::repository::e_db_repository::EDBRepository;
+
+const CHUNK_SIZE: usize = 64 * 1024;
+
+pub fn manifest_path(data: &Path) -> PathBuf {
+    return data.with_extension("manifest.json");
+}
+
+pub async fn describe_file(path: &Path) -> Result<ManifestFile, ConnectException> {
+    let file = File::open(path).await;
+    if let Err(error) = file {
+        return Err(ConnectException::new(format!("Cannot read '{}': {}", path.display(), error)));
+    }
+
+    let mut file = file.unwrap();
+    let mut context = digest::Context::new(&digest::SHA256);
+    let mut buffer = vec![0u8; CHUNK_SIZE];
+    let mut size = 0;
+
+    loop {
+        let read = match file.read(&mut buffer).await {
+            Ok(read) => read,
+            Err(error) => return Err(ConnectException::new(format!("Cannot read '{}': {}", path.display(), error))),
+        };
+        if read == 0 {
+            break;
+        }
+        context.update(&buffer[..read]);
+        size += read as u64;
+    }
+
+    let checksum = context.finish().as_ref().iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    let name = path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+
+    return Ok(ManifestFile::new(name, size, checksum));
+}
+
+pub async fn write(path: &Path, manifest: &ExportManifest) -> Result<(), ConnectException> {
+    let content = serde_json::to_string_pretty(&manifest_as_json(manifest));
+    if let Err(error) = content {
+        return Err(ConnectException::from(error));
+    }
+
+    let temporal = path.with_extension("tmp");
+    if let Err(error) = fs::write(&temporal, content.unwrap()).await {
+        return Err(ConnectException::from(error));
+    }
+
+    if let Err(error) = fs::rename(&temporal, path).await {
+        return Err(ConnectException::from(error));
+    }
+
+    return Ok(());
+}
+
+pub async fn read(path: &Path) -> Result<ExportManifest, ConnectException> {
+    let content = fs::read_to_string(path).await;
+    if let Err(error) = content {
+        return Err(ConnectException::new(format!("Cannot read manifest '{}': {}", path.display(), error)));
+    }
+
+    let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+    if let Err(error) = json {
+        return Err(ConnectException::new(format!("Invalid manifest '{}': {}", path.display(), error)));
+    }
+
+    return manifest_from_json(&json.unwrap());
+}
+
+pub async fn verify(path: &Path) -> Result<ManifestCheck, ConnectException> {
+    let manifest = read(path).await?;
+    let directory = path.parent().map(|p| p.to_path_buf()).unwrap_or_default();
+
+    let mut issues = Vec::new();
+    if manifest.version() > MANIFEST_VERSION {
+        issues.push(format!("Manifest version {} is newer than the supported version {}.", manifest.version(), MANIFEST_VERSION));
+    }
+
+    if manifest.files().is_empty() {
+        issues.push(String::from("Manifest does not list any file."));
+    }
+
+    for expected in manifest.files() {
+        let file = directory.join(expected.name());
+        if !file.exists() {
+            issues.push(format!("File '{}' is missing.", expected.name()));
+            continue;
+        }
+
+        let actual = describe_file(&file).await?;
+        if actual.size() != expected.size() {
+            issues.push(format!("File '{}' has {} bytes, expected {}.", expected.name(), actual.size(), expected.size()));
+        }
+        if actual.checksum() != expected.checksum() {
+            issues.push(format!("File '{}' checksum does not match.", expected.name()));
+        }
+    }
+
+    return Ok(ManifestCheck::new(manifest, issues));
+}
+
+fn manifest_as_json(manifest: &ExportManifest) -> Value {
+    let origin = manifest.origin().map(|o| json!({
+        "connection": o.connection(),
+        "category": o.category().to_string()
+    }));
+
+    let files: Vec<Value> = manifest.files().iter()
+        .map(|f| json!({
+            "name": f.name(),
+            "size": f.size(),
+            "sha256": f.checksum()
+        }))
+        .collect();
+
+    return json!({
+        "version": manifest.version(),
+        "tool": manifest.tool(),
+        "tool_version": manifest.tool_version(),
+        "kind": manifest.kind().to_string(),
+        "source": {
+            "fingerprint": manifest.fingerprint(),
+            "origin": origin
+        },
+        "data_base": manifest.data_base(),
+        "collection": manifest.collection(),
+        "filter": manifest.filter(),
+        "counts": {
+            "documents": manifest.documents(),
+            "indexes": manifest.indexes()
+        },
+        "files": files,
+        "started_at": manifest.started_at() as u64,
+        "finished_at": manifest.finished_at() as u64
+    });
+}
+
+fn manifest_from_json(json: &Value) -> Result<ExportManifest, ConnectException> {
+    let code = string_field(json, "kind")?;
+    let Some(kind) = EExportKind::from_string(&code) else {
+        return Err(ConnectException::new(format!("Unknown export kind '{}' in manifest.", code)));
+    };
+
+    let source = json.get("source").cloned().unwrap_or_default();
+    let started_at = json.get("started_at").and_then(|v| v.as_u64()).unwrap_or_default() as u128;
+
+    let mut manifest = ExportManifest::new(kind, string_field(&source, "fingerprint")?, string_field(json, "data_base")?, string_field(json, "collection")?, started_at);
+    manifest.set_version(json.get("version").and_then(|v| v.as_u64()).unwrap_or_default());
+    manifest.set_tool(string_field(json, "tool")?, string_field(json, "tool_version")?);
+
+    if let Some(origin) = source.get("origin").filter(|o| o.is_object()) {
+        let code = string_field(origin, "category")?;
+        let Some(category) = EDBRepository::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown repository category '{}' in manifest.", code)));
+        };
+        manifest.set_origin(DocumentOrigin::new(string_field(origin, "connection")?, category));
+    }
+
+    if let Some(filter) = json.get("filter").and_then(|f| f.as_str()) {
+        manifest.set_filter(String::from(filter));
+    }
+
+    let counts = json.get("counts").cloned().unwrap_or_default();
+    manifest.set_counts(
+        counts.get("documents").and_then(|v| v.as_u64()).unwrap_or_default() as usize,
+        counts.get("indexes").and_then(|v| v.as_u64()).unwrap_or_default() as usize);
+
+    for file in json.get("files").and_then(|f| f.as_array()).cloned().unwrap_or_default() {
+        let size = file.get("size").and_then(|v| v.as_u64()).unwrap_or_default();
+        manifest.push_file(ManifestFile::new(string_field(&file, "name")?, size, string_field(&file, "sha256")?));
+    }
+
+    manifest.finish(json.get("finished_at").and_then(|v| v.as_u64()).unwrap_or_default() as u128);
+
+    return Ok(manifest);
+}
+
+fn string_field(json: &Value, key: &str) -> Result<String, ConnectException> {
+    let Some(value) = json.get(key).and_then(|v| v.as_str()) else {
+        return Err(ConnectException::new(format!("Manifest field '{}' is missing or not a string.", key)));
+    };
+    return Ok(String::from(value));
+}
\ No newline at end of file
@@ -0,0 +1,71 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::commons::exception::connect_exception::ConnectException;
+
+#[derive(Clone)]
+pub struct PreferencesRepository {
+    path: PathBuf
+}
+
+impl PreferencesRepository {
+
+    pub fn new(path: PathBuf) -> PreferencesRepository {
+        PreferencesRepository {
+            path
+        }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        return self.path.clone();
+    }
+
+    pub fn load(&self) -> Result<Map<String, Value>, ConnectException> {
+        if !self.path.exists() {
+            return Ok(Map::new());
+        }
+
+        let content = fs::read_to_string(&self.path);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let Value::Object(values) = json.unwrap() else {
+            let exception = ConnectException::new(format!("Preferences file '{}' must contain a JSON object.", self.path.display()));
+            return Err(exception);
+        };
+
+        return Ok(values);
+    }
+
+    pub fn save(&self, values: &Map<String, Value>) -> Result<(), ConnectException> {
+        let content = serde_json::to_string_pretty(values);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let temporal = self.path.with_extension("tmp");
+        if let Err(error) = fs::write(&temporal, content.unwrap()) {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::rename(&temporal, &self.path) {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(());
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,205 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::{filter::{document_query::DocumentQuery, e_filter_category::EFilterCategory, e_filter_operator::EFilterOperator, filter_element::FilterElement, filter_value::FilterValue, filter_value_attribute::FilterValueAttribute}, history::{e_history_action::EHistoryAction, history_entry::HistoryEntry, query_history::QueryHistory}}};
+
+const CAPACITY_KEY: &str = "capacity";
+const ENTRIES_KEY: &str = "entries";
+
+#[derive(Clone)]
+pub struct QueryHistoryRepository {
+    path: PathBuf
+}
+
+impl QueryHistoryRepository {
+
+    pub fn new(path: PathBuf) -> QueryHistoryRepository {
+        QueryHistoryRepository { path }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        return self.path.clone();
+    }
+
+    pub fn load(&self) -> Result<QueryHistory, ConnectException> {
+        if !self.path.exists() {
+            return Ok(QueryHistory::default());
+        }
+
+        let content = fs::read_to_string(&self.path);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let json = json.unwrap();
+        let Some(items) = json.get(ENTRIES_KEY).and_then(|e| e.as_array()) else {
+            let exception = ConnectException::new(format!("History file '{}' has no '{}' list.", self.path.display(), ENTRIES_KEY));
+            return Err(exception);
+        };
+
+        let mut entries = Vec::new();
+        for item in items {
+            entries.push(self.entry_from_json(item)?);
+        }
+
+        let capacity = json.get(CAPACITY_KEY)
+            .and_then(|c| c.as_u64())
+            .map(|c| c as usize)
+            .unwrap_or(QueryHistory::default().capacity());
+
+        return Ok(QueryHistory::from(capacity, entries));
+    }
+
+    pub fn save(&self, history: &QueryHistory) -> Result<(), ConnectException> {
+        let entries: Vec<Value> = history.entries().iter().map(|e| self.entry_as_json(e)).collect();
+
+        let content = serde_json::to_string_pretty(&json!({
+            CAPACITY_KEY: history.capacity(),
+            ENTRIES_KEY: entries
+        }));
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let temporal = self.path.with_extension("tmp");
+        if let Err(error) = fs::write(&temporal, content.unwrap()) {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::rename(&temporal, &self.path) {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(());
+    }
+
+    fn entry_as_json(&self, entry: &HistoryEntry) -> Value {
+        let query = entry.query();
+        return json!({
+            "id": entry.id(),
+            "action": entry.action().to_string(),
+            "data_base": query.data_base(),
+            "collection": query.collection(),
+            "skip": query.skip(),
+            "limit": query.limit(),
+            "filter": query.filter().map(|f| self.filter_as_json(&f)),
+            "page": entry.offset().zip(entry.limit()).map(|(o, l)| json!({ "offset": o, "limit": l })),
+            "pipeline": entry.pipeline(),
+            "timestamp": entry.timestamp() as u64,
+            "duration": entry.duration() as u64,
+            "count": entry.count(),
+            "error": entry.error()
+        });
+    }
+
+    fn entry_from_json(&self, json: &Value) -> Result<HistoryEntry, ConnectException> {
+        let code = self.string_field(json, "action")?;
+        let Some(action) = EHistoryAction::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown history action '{}'.", code)));
+        };
+
+        let filter = match json.get("filter").filter(|f| f.is_object()) {
+            Some(filter) => Some(self.filter_from_json(filter)?),
+            None => None,
+        };
+
+        let skip = json.get("skip").and_then(|s| s.as_u64()).map(|s| s as usize);
+        let limit = json.get("limit").and_then(|l| l.as_u64()).map(|l| l as usize);
+        let query = DocumentQuery::from(self.string_field(json, "data_base")?, self.string_field(json, "collection")?, skip, limit, filter);
+
+        let timestamp = json.get("timestamp").and_then(|t| t.as_u64()).unwrap_or_default() as u128;
+        let mut entry = HistoryEntry::new(action, query, timestamp);
+        entry.set_id(json.get("id").and_then(|i| i.as_u64()).unwrap_or_default());
+        entry.set_duration(json.get("duration").and_then(|d| d.as_u64()).unwrap_or_default() as u128);
+
+        if let Some(page) = json.get("page").filter(|p| p.is_object()) {
+            let offset = page.get("offset").and_then(|o| o.as_u64()).unwrap_or_default();
+            let limit = page.get("limit").and_then(|l| l.as_u64()).unwrap_or_default();
+            entry.set_page(offset, limit);
+        }
+        if let Some(pipeline) = json.get("pipeline").and_then(|p| p.as_str()) {
+            entry.set_pipeline(String::from(pipeline));
+        }
+        if let Some(count) = json.get("count").and_then(|c| c.as_u64()) {
+            entry.set_count(count as usize);
+        }
+        if let Some(error) = json.get("error").and_then(|e| e.as_str()) {
+            entry.set_error(String::from(error));
+        }
+
+        return Ok(entry);
+    }
+
+    fn filter_as_json(&self, filter: &FilterElement) -> Value {
+        let value = filter.value();
+        let attributes: Vec<Value> = value.attributes().iter()
+            .map(|a| json!({ "key": a.key(), "value": a.value() }))
+            .collect();
+        let children: Vec<Value> = value.children().iter()
+            .map(|c| self.filter_as_json(c))
+            .collect();
+
+        return json!({
+            "key": filter.field(),
+            "operator": filter.operator().to_string(),
+            "or": filter.is_or(),
+            "negation": filter.is_negate(),
+            "category": value.category().to_string(),
+            "value": value.value(),
+            "attributes": attributes,
+            "children": children
+        });
+    }
+
+    fn filter_from_json(&self, json: &Value) -> Result<FilterElement, ConnectException> {
+        let code = self.string_field(json, "category")?;
+        let Some(category) = EFilterCategory::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown filter category '{}' in history.", code)));
+        };
+
+        let code = self.string_field(json, "operator")?;
+        let Some(operator) = EFilterOperator::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown filter operator '{}' in history.", code)));
+        };
+
+        let attributes = json.get("attributes").and_then(|a| a.as_array()).cloned().unwrap_or_default().iter()
+            .filter_map(|a| Some(FilterValueAttribute::new(String::from(a.get("key")?.as_str()?), String::from(a.get("value")?.as_str()?))))
+            .collect();
+
+        let mut children = Vec::new();
+        for child in json.get("children").and_then(|c| c.as_array()).cloned().unwrap_or_default() {
+            children.push(self.filter_from_json(&child)?);
+        }
+
+        let value = FilterValue::from(category, self.string_field(json, "value")?, attributes, children);
+        let mut filter = FilterElement::compare(self.string_field(json, "key")?, operator, value);
+        if json.get("or").and_then(|o| o.as_bool()).unwrap_or(false) {
+            filter.as_or();
+        }
+        if json.get("negation").and_then(|n| n.as_bool()).unwrap_or(false) {
+            filter.negate();
+        }
+
+        return Ok(filter);
+    }
+
+    fn string_field(&self, json: &Value, key: &str) -> Result<String, ConnectException> {
+        let Some(value) = json.get(key).and_then(|v| v.as_str()) else {
+            return Err(ConnectException::new(format!("History field '{}' is missing or not a string.", key)));
+        };
+        return Ok(String::from(value));
+    }
+
+}
\ No newline at end of file
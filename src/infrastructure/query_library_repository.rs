@@ -0,0 +1,147 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::library::{e_saved_query_kind::ESavedQueryKind, saved_query::SavedQuery}};
+
+const QUERIES_KEY: &str = "queries";
+
+#[derive(Clone)]
+pub struct QueryLibraryRepository {
+    path: PathBuf
+}
+
+impl QueryLibraryRepository {
+
+    pub fn new(path: PathBuf) -> QueryLibraryRepository {
+        QueryLibraryRepository { path }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        return self.path.clone();
+    }
+
+    pub fn find_all(&self) -> Result<Vec<SavedQuery>, ConnectException> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&self.path);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let json = json.unwrap();
+        let Some(items) = json.get(QUERIES_KEY).and_then(|q| q.as_array()) else {
+            let exception = ConnectException::new(format!("Query library file '{}' has no '{}' list.", self.path.display(), QUERIES_KEY));
+            return Err(exception);
+        };
+
+        let mut queries = Vec::new();
+        for item in items {
+            queries.push(self.query_from_json(item)?);
+        }
+
+        return Ok(queries);
+    }
+
+    pub fn find(&self, connection: &str, data_base: &str, collection: &str, name: &str) -> Result<Option<SavedQuery>, ConnectException> {
+        let queries = self.find_all()?;
+        return Ok(queries.into_iter().find(|q| q.name() == name && q.belongs_to(connection, data_base, collection)));
+    }
+
+    pub fn save(&self, query: &SavedQuery) -> Result<(), ConnectException> {
+        let mut queries = self.find_all()?;
+        match queries.iter().position(|q| q.is_same(query)) {
+            Some(position) => queries[position] = query.clone(),
+            None => queries.push(query.clone()),
+        }
+        return self.write(&queries);
+    }
+
+    pub fn delete(&self, query: &SavedQuery) -> Result<bool, ConnectException> {
+        let mut queries = self.find_all()?;
+        let size = queries.len();
+        queries.retain(|q| !q.is_same(query));
+        if queries.len() == size {
+            return Ok(false);
+        }
+        self.write(&queries)?;
+        return Ok(true);
+    }
+
+    fn write(&self, queries: &[SavedQuery]) -> Result<(), ConnectException> {
+        let items: Vec<Value> = queries.iter().map(|q| self.query_as_json(q)).collect();
+
+        let content = serde_json::to_string_pretty(&json!({ QUERIES_KEY: items }));
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let temporal = self.path.with_extension("tmp");
+        if let Err(error) = fs::write(&temporal, content.unwrap()) {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::rename(&temporal, &self.path) {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(());
+    }
+
+    fn query_as_json(&self, query: &SavedQuery) -> Value {
+        return json!({
+            "name": query.name(),
+            "connection": query.connection(),
+            "data_base": query.data_base(),
+            "collection": query.collection(),
+            "kind": query.kind().to_string(),
+            "body": query.body(),
+            "description": query.description()
+        });
+    }
+
+    fn query_from_json(&self, json: &Value) -> Result<SavedQuery, ConnectException> {
+        let name = self.string_field(json, "name")?;
+
+        let code = self.string_field(json, "kind")?;
+        let Some(kind) = ESavedQueryKind::from_string(&code) else {
+            return Err(ConnectException::new(format!("Unknown query kind '{}' in saved query '{}'.", code, name)));
+        };
+
+        let mut query = SavedQuery::new(
+            name,
+            self.string_field(json, "connection")?,
+            self.string_field(json, "data_base")?,
+            self.string_field(json, "collection")?,
+            kind,
+            self.string_field(json, "body")?
+        );
+
+        if let Some(description) = json.get("description").and_then(|d| d.as_str()) {
+            query.set_description(String::from(description));
+        }
+
+        return Ok(query);
+    }
+
+    fn string_field(&self, json: &Value, key: &str) -> Result<String, ConnectException> {
+        let Some(value) = json.get(key).and_then(|v| v.as_str()) else {
+            return Err(ConnectException::new(format!("Saved query field '{}' is missing or not a string.", key)));
+        };
+        return Ok(String::from(value));
+    }
+
+}
\ No newline at end of file
@@ -1,9 +1,10 @@
 use crate::{commons::exception::connect_exception::ConnectException, domain::connection_data::ConnectionData};
 
-use super::{e_db_repository::EDBRepository, i_db_repository::IDBRepository, mongo_db::mongo_db_repository::MongoDbRepository};
+use super::{db_repository::DBRepository, e_db_repository::EDBRepository, i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository, mongo_db::mongo_db_repository::MongoDbRepository, unsupported_repository::UnsupportedRepository};
 
-pub async fn find(connection: &ConnectionData) -> Result<impl IDBRepository, ConnectException>  {
+pub async fn find(connection: &ConnectionData) -> Result<impl IDBRepository + IDBMaintenance, ConnectException>  {
     match connection.category() {
-        EDBRepository::MongoDB => Ok(MongoDbRepository::new(connection).await?)
+        EDBRepository::MongoDB => Ok(DBRepository::MongoDB(MongoDbRepository::new(connection).await?)),
+        EDBRepository::MySQL | EDBRepository::Redis => Ok(DBRepository::Unsupported(UnsupportedRepository::new(connection)))
     }
 }
\ No newline at end of file
@@ -0,0 +1,446 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        collection::{
+            collection_data::CollectionData, collection_definition::CollectionDefinition, collection_index::CollectionIndex, collection_validator::CollectionValidator, dump_report::DumpReport, generate_collection_query::GenerateCollectionQuery, import_integrity::ImportIntegrity, import_preview::ImportPreview, maintenance_report::MaintenanceReport
+        },
+        data_base::generate_database_query::GenerateDatabaseQuery,
+        document::{document_data::DocumentData, document_delete_preview::DocumentDeletePreview, document_insert_result::DocumentInsertResult, document_schema::DocumentSchema, document_size::DocumentSize, soft_delete_policy::SoftDeletePolicy, document_update_field::DocumentUpdateField, lineage_policy::LineagePolicy},
+        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery},
+        host_latency::HostLatency,
+        metadata::{collection_metadata::CollectionMetadata, data_base_metadata::DataBaseMetadata, server_metadata::ServerMetadata},
+        routing::routing_event::RoutingEvent,
+        server_time::ServerTime,
+        table::table_data_group::TableDataGroup,
+        update::{update_preview::UpdatePreview, update_spec::UpdateSpec, update_summary::UpdateSummary},
+    },
+};
+
+use super::{i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository, mongo_db::mongo_db_repository::MongoDbRepository, unsupported_repository::UnsupportedRepository};
+
+#[derive(Clone)]
+pub enum DBRepository {
+    MongoDB(MongoDbRepository),
+    Unsupported(UnsupportedRepository)
+}
+
+#[async_trait]
+impl IDBRepository for DBRepository {
+
+    fn fingerprint(&self) -> String {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.fingerprint(),
+            DBRepository::Unsupported(repository) => repository.fingerprint(),
+        };
+    }
+
+    fn soft_delete(&self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.soft_delete(data_base, collection),
+            DBRepository::Unsupported(repository) => repository.soft_delete(data_base, collection),
+        };
+    }
+
+    fn lineage(&self) -> Option<LineagePolicy> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.lineage(),
+            DBRepository::Unsupported(repository) => repository.lineage(),
+        };
+    }
+
+    fn routing_events(&self) -> Vec<RoutingEvent> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.routing_events(),
+            DBRepository::Unsupported(repository) => repository.routing_events(),
+        };
+    }
+
+    async fn status(&self) -> Result<(), ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.status().await,
+            DBRepository::Unsupported(repository) => repository.status().await,
+        };
+    }
+
+    async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.metadata().await,
+            DBRepository::Unsupported(repository) => repository.metadata().await,
+        };
+    }
+
+    async fn server_stats(&self) -> Result<ServerMetadata, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.server_stats().await,
+            DBRepository::Unsupported(repository) => repository.server_stats().await,
+        };
+    }
+
+    async fn server_time(&self) -> Result<ServerTime, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.server_time().await,
+            DBRepository::Unsupported(repository) => repository.server_time().await,
+        };
+    }
+
+    async fn latency_probe(&self) -> Result<Vec<HostLatency>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.latency_probe().await,
+            DBRepository::Unsupported(repository) => repository.latency_probe().await,
+        };
+    }
+
+    async fn data_base_metadata(&self, query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_metadata(query).await,
+            DBRepository::Unsupported(repository) => repository.data_base_metadata(query).await,
+        };
+    }
+
+    async fn data_base_stats(&self, query: &DataBaseQuery) -> Result<DataBaseMetadata, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_stats(query).await,
+            DBRepository::Unsupported(repository) => repository.data_base_stats(query).await,
+        };
+    }
+
+    async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_find_all().await,
+            DBRepository::Unsupported(repository) => repository.data_base_find_all().await,
+        };
+    }
+
+    async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_exists(query).await,
+            DBRepository::Unsupported(repository) => repository.data_base_exists(query).await,
+        };
+    }
+
+    async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_create(query).await,
+            DBRepository::Unsupported(repository) => repository.data_base_create(query).await,
+        };
+    }
+
+    async fn data_base_drop(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.data_base_drop(query).await,
+            DBRepository::Unsupported(repository) => repository.data_base_drop(query).await,
+        };
+    }
+
+    async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_accept_schema().await,
+            DBRepository::Unsupported(repository) => repository.collection_accept_schema().await,
+        };
+    }
+
+    async fn collection_metadata(&self, query: &CollectionQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_metadata(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_metadata(query).await,
+        };
+    }
+
+    async fn collection_stats(&self, query: &CollectionQuery) -> Result<CollectionMetadata, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_stats(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_stats(query).await,
+        };
+    }
+
+    async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_find_all(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_find_all(query).await,
+        };
+    }
+
+    async fn collection_indexes(&self, query: &CollectionQuery) -> Result<Vec<CollectionIndex>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_indexes(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_indexes(query).await,
+        };
+    }
+
+    async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_exists(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_exists(query).await,
+        };
+    }
+
+    async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_create(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_create(query).await,
+        };
+    }
+
+    async fn collection_drop(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_drop(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_drop(query).await,
+        };
+    }
+
+    async fn collection_validator(&self, query: &CollectionQuery) -> Result<Option<CollectionValidator>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_validator(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_validator(query).await,
+        };
+    }
+
+    async fn collection_set_validator(&self, query: &CollectionQuery, validator: Option<&CollectionValidator>) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_set_validator(query, validator).await,
+            DBRepository::Unsupported(repository) => repository.collection_set_validator(query, validator).await,
+        };
+    }
+
+    async fn collection_rename(&self, query: &CollectionQuery, name: &str) -> Result<String, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_rename(query, name).await,
+            DBRepository::Unsupported(repository) => repository.collection_rename(query, name).await,
+        };
+    }
+
+    async fn collection_export(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_export(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_export(query).await,
+        };
+    }
+
+    async fn collection_export_partitioned(&self, query: &CollectionQuery, partitions: usize) -> Result<Vec<BoxStream<'static, Result<DocumentData, ConnectException>>>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_export_partitioned(query, partitions).await,
+            DBRepository::Unsupported(repository) => repository.collection_export_partitioned(query, partitions).await,
+        };
+    }
+
+    async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportIntegrity, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_import(query, documents).await,
+            DBRepository::Unsupported(repository) => repository.collection_import(query, documents).await,
+        };
+    }
+
+    async fn collection_import_preview(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportPreview, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_import_preview(query, documents).await,
+            DBRepository::Unsupported(repository) => repository.collection_import_preview(query, documents).await,
+        };
+    }
+
+    async fn collection_dump(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_dump(query, path).await,
+            DBRepository::Unsupported(repository) => repository.collection_dump(query, path).await,
+        };
+    }
+
+    async fn collection_restore(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_restore(query, path).await,
+            DBRepository::Unsupported(repository) => repository.collection_restore(query, path).await,
+        };
+    }
+
+    async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.find_all(query).await,
+            DBRepository::Unsupported(repository) => repository.find_all(query).await,
+        };
+    }
+
+    async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.find_query(query).await,
+            DBRepository::Unsupported(repository) => repository.find_query(query).await,
+        };
+    }
+
+    async fn find_page(&self, query: &DocumentQuery, offset: u64, limit: u64) -> Result<CollectionData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.find_page(query, offset, limit).await,
+            DBRepository::Unsupported(repository) => repository.find_page(query, offset, limit).await,
+        };
+    }
+
+    async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.find(query).await,
+            DBRepository::Unsupported(repository) => repository.find(query).await,
+        };
+    }
+
+    async fn count(&self, query: &DocumentQuery) -> Result<u64, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.count(query).await,
+            DBRepository::Unsupported(repository) => repository.count(query).await,
+        };
+    }
+
+    async fn document_exists(&self, query: &DocumentQuery) -> Result<bool, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.document_exists(query).await,
+            DBRepository::Unsupported(repository) => repository.document_exists(query).await,
+        };
+    }
+
+    async fn document_size(&self, query: &DocumentQuery) -> Result<DocumentSize, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.document_size(query).await,
+            DBRepository::Unsupported(repository) => repository.document_size(query).await,
+        };
+    }
+
+    async fn find_stream(&self, query: &DocumentQuery) -> Result<BoxStream<'static, Result<DocumentData, ConnectException>>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.find_stream(query).await,
+            DBRepository::Unsupported(repository) => repository.find_stream(query).await,
+        };
+    }
+
+    async fn schema(&self, query: &CollectionQuery) -> Result<DocumentSchema, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.schema(query).await,
+            DBRepository::Unsupported(repository) => repository.schema(query).await,
+        };
+    }
+
+    async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.insert(query, value).await,
+            DBRepository::Unsupported(repository) => repository.insert(query, value).await,
+        };
+    }
+
+    async fn insert_many(&self, query: &CollectionQuery, values: Vec<String>, batch_size: usize) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.insert_many(query, values, batch_size).await,
+            DBRepository::Unsupported(repository) => repository.insert_many(query, values, batch_size).await,
+        };
+    }
+
+    async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.update(query, value).await,
+            DBRepository::Unsupported(repository) => repository.update(query, value).await,
+        };
+    }
+
+    async fn update_transaction(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.update_transaction(query, value).await,
+            DBRepository::Unsupported(repository) => repository.update_transaction(query, value).await,
+        };
+    }
+
+    async fn patch(&self, query: &DocumentQuery, fields: Vec<DocumentUpdateField>) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.patch(query, fields).await,
+            DBRepository::Unsupported(repository) => repository.patch(query, fields).await,
+        };
+    }
+
+    async fn bulk_set(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdateSummary, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.bulk_set(query, spec).await,
+            DBRepository::Unsupported(repository) => repository.bulk_set(query, spec).await,
+        };
+    }
+
+    async fn bulk_set_preview(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdatePreview, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.bulk_set_preview(query, spec).await,
+            DBRepository::Unsupported(repository) => repository.bulk_set_preview(query, spec).await,
+        };
+    }
+
+    async fn upsert(&self, query: &DocumentQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.upsert(query, value).await,
+            DBRepository::Unsupported(repository) => repository.upsert(query, value).await,
+        };
+    }
+
+    async fn update_revision(&self, query: &DocumentQuery, value: &str, revision: i64) -> Result<DocumentData, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.update_revision(query, value, revision).await,
+            DBRepository::Unsupported(repository) => repository.update_revision(query, value, revision).await,
+        };
+    }
+
+    async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.delete(query).await,
+            DBRepository::Unsupported(repository) => repository.delete(query).await,
+        };
+    }
+
+    async fn delete_transaction(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.delete_transaction(query).await,
+            DBRepository::Unsupported(repository) => repository.delete_transaction(query).await,
+        };
+    }
+
+    async fn delete_preview(&self, query: &DocumentQuery) -> Result<DocumentDeletePreview, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.delete_preview(query).await,
+            DBRepository::Unsupported(repository) => repository.delete_preview(query).await,
+        };
+    }
+
+    async fn aggregate_raw(&self, query: &CollectionQuery, pipeline_json: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.aggregate_raw(query, pipeline_json).await,
+            DBRepository::Unsupported(repository) => repository.aggregate_raw(query, pipeline_json).await,
+        };
+    }
+
+    async fn move_documents(&self, query: &DocumentQuery, target_collection: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.move_documents(query, target_collection).await,
+            DBRepository::Unsupported(repository) => repository.move_documents(query, target_collection).await,
+        };
+    }
+
+}
+
+#[async_trait]
+impl IDBMaintenance for DBRepository {
+
+    async fn collection_compact(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_compact(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_compact(query).await,
+        };
+    }
+
+    async fn collection_reindex(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_reindex(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_reindex(query).await,
+        };
+    }
+
+    async fn collection_validate(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return match self {
+            DBRepository::MongoDB(repository) => repository.collection_validate(query).await,
+            DBRepository::Unsupported(repository) => repository.collection_validate(query).await,
+        };
+    }
+
+}
\ No newline at end of file
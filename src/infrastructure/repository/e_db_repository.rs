@@ -2,7 +2,9 @@ use strum::{EnumIter, IntoEnumIterator};
 
 #[derive(Debug, Clone, EnumIter)]
 pub enum EDBRepository {
-    MongoDB
+    MongoDB,
+    MySQL,
+    Redis
 }
 
 impl EDBRepository {
@@ -13,13 +15,17 @@ impl EDBRepository {
 
     pub fn to_string(&self) -> String {
         match self {
-            EDBRepository::MongoDB => String::from("MongoDB")
+            EDBRepository::MongoDB => String::from("MongoDB"),
+            EDBRepository::MySQL => String::from("MySQL"),
+            EDBRepository::Redis => String::from("Redis"),
         }
     }
 
     pub fn from_string(category: &str) -> Option<EDBRepository> {
         match category {
             "MongoDB" => Some(EDBRepository::MongoDB),
+            "MySQL" => Some(EDBRepository::MySQL),
+            "Redis" => Some(EDBRepository::Redis),
             _ => None,
         }
     }
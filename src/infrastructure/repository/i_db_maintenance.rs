@@ -0,0 +1,21 @@
+use async_trait::async_trait;
+
+use crate::{
+    commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind},
+    domain::{collection::maintenance_report::MaintenanceReport, filter::collection_query::CollectionQuery},
+};
+
+#[async_trait]
+pub trait IDBMaintenance: Clone + Send + Sync {
+    async fn collection_compact(&self, _query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, String::from("Compact is not supported by this repository.")))
+    }
+
+    async fn collection_reindex(&self, _query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, String::from("Reindex is not supported by this repository.")))
+    }
+
+    async fn collection_validate(&self, _query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, String::from("Validate is not supported by this repository.")))
+    }
+}
\ No newline at end of file
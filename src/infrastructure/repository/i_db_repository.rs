@@ -1,24 +1,41 @@
+use std::path::Path;
+
 use async_trait::async_trait;
+use futures_util::stream::BoxStream;
 
 use crate::{
     commons::exception::connect_exception::ConnectException,
     domain::{
         collection::{
-            collection_data::CollectionData, collection_definition::CollectionDefinition, generate_collection_query::GenerateCollectionQuery
+            collection_data::CollectionData, collection_definition::CollectionDefinition, collection_index::CollectionIndex, collection_validator::CollectionValidator, dump_report::DumpReport, generate_collection_query::GenerateCollectionQuery, import_integrity::ImportIntegrity, import_preview::ImportPreview
         },
         data_base::generate_database_query::GenerateDatabaseQuery,
-        document::{document_data::DocumentData, document_schema::DocumentSchema},
+        document::{document_data::DocumentData, document_delete_preview::DocumentDeletePreview, document_insert_result::DocumentInsertResult, document_schema::DocumentSchema, document_size::DocumentSize, soft_delete_policy::SoftDeletePolicy, document_update_field::DocumentUpdateField, lineage_policy::LineagePolicy},
         filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery},
+        host_latency::HostLatency,
+        metadata::{collection_metadata::CollectionMetadata, data_base_metadata::DataBaseMetadata, server_metadata::ServerMetadata},
+        routing::routing_event::RoutingEvent,
+        server_time::ServerTime,
         table::table_data_group::TableDataGroup,
+        update::{update_preview::UpdatePreview, update_spec::UpdateSpec, update_summary::UpdateSummary},
     },
 };
 
 #[async_trait]
 pub trait IDBRepository: Clone + Send + Sync {
+    fn fingerprint(&self) -> String;
+    fn soft_delete(&self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy>;
+    fn lineage(&self) -> Option<LineagePolicy>;
+    fn routing_events(&self) -> Vec<RoutingEvent>;
+
     async fn status(&self) -> Result<(), ConnectException>;
     async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException>;
+    async fn server_stats(&self) -> Result<ServerMetadata, ConnectException>;
+    async fn server_time(&self) -> Result<ServerTime, ConnectException>;
+    async fn latency_probe(&self) -> Result<Vec<HostLatency>, ConnectException>;
 
     async fn data_base_metadata(&self, query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException>;
+    async fn data_base_stats(&self, query: &DataBaseQuery) -> Result<DataBaseMetadata, ConnectException>;
     async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException>;
     async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException>;
     async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException>;
@@ -26,19 +43,43 @@ pub trait IDBRepository: Clone + Send + Sync {
 
     async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException>;
     async fn collection_metadata(&self, query: &CollectionQuery) -> Result<Vec<TableDataGroup>, ConnectException>;
+    async fn collection_stats(&self, query: &CollectionQuery) -> Result<CollectionMetadata, ConnectException>;
     async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException>;
+    async fn collection_indexes(&self, query: &CollectionQuery) -> Result<Vec<CollectionIndex>, ConnectException>;
     async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException>;
     async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException>;
     async fn collection_drop(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException>;
+    async fn collection_validator(&self, query: &CollectionQuery) -> Result<Option<CollectionValidator>, ConnectException>;
+    async fn collection_set_validator(&self, query: &CollectionQuery, validator: Option<&CollectionValidator>) -> Result<String, ConnectException>;
     async fn collection_rename(&self, query: &CollectionQuery, name: &str) -> Result<String, ConnectException>;
     async fn collection_export(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException>;
-    async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException>;
+    async fn collection_export_partitioned(&self, query: &CollectionQuery, partitions: usize) -> Result<Vec<BoxStream<'static, Result<DocumentData, ConnectException>>>, ConnectException>;
+    async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportIntegrity, ConnectException>;
+    async fn collection_import_preview(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportPreview, ConnectException>;
+    async fn collection_dump(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException>;
+    async fn collection_restore(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException>;
     
     async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException>;
     async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException>;
+    async fn find_page(&self, query: &DocumentQuery, offset: u64, limit: u64) -> Result<CollectionData, ConnectException>;
     async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException>;
+    async fn count(&self, query: &DocumentQuery) -> Result<u64, ConnectException>;
+    async fn document_exists(&self, query: &DocumentQuery) -> Result<bool, ConnectException>;
+    async fn document_size(&self, query: &DocumentQuery) -> Result<DocumentSize, ConnectException>;
+    async fn find_stream(&self, query: &DocumentQuery) -> Result<BoxStream<'static, Result<DocumentData, ConnectException>>, ConnectException>;
     async fn schema(&self, query: &CollectionQuery) -> Result<DocumentSchema, ConnectException>;
     async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException>;
+    async fn insert_many(&self, query: &CollectionQuery, values: Vec<String>, batch_size: usize) -> Result<Vec<DocumentInsertResult>, ConnectException>;
     async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn update_transaction(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn patch(&self, query: &DocumentQuery, fields: Vec<DocumentUpdateField>) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn bulk_set(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdateSummary, ConnectException>;
+    async fn bulk_set_preview(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdatePreview, ConnectException>;
+    async fn upsert(&self, query: &DocumentQuery, value: &str) -> Result<DocumentData, ConnectException>;
+    async fn update_revision(&self, query: &DocumentQuery, value: &str, revision: i64) -> Result<DocumentData, ConnectException>;
     async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn delete_transaction(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn delete_preview(&self, query: &DocumentQuery) -> Result<DocumentDeletePreview, ConnectException>;
+    async fn aggregate_raw(&self, query: &CollectionQuery, pipeline_json: &str) -> Result<Vec<DocumentData>, ConnectException>;
+    async fn move_documents(&self, query: &DocumentQuery, target_collection: &str) -> Result<Vec<DocumentData>, ConnectException>;
 }
\ No newline at end of file
@@ -0,0 +1,67 @@
+use serde_json::Value;
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{
+        metadata::{collection_metadata::CollectionMetadata, server_metadata::ServerMetadata},
+        table::table_data_group::TableDataGroup,
+    },
+};
+
+const BYTE_UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+pub trait IMetadataExtractor {
+    type Source;
+
+    fn server_groups(source: &Self::Source) -> Result<Vec<TableDataGroup>, ConnectException>;
+    fn collection_groups(source: Self::Source) -> Result<Vec<TableDataGroup>, ConnectException>;
+    fn collections_groups(sources: Vec<Self::Source>) -> Result<Vec<TableDataGroup>, ConnectException>;
+    fn server_metadata(source: &Self::Source) -> ServerMetadata;
+    fn collection_metadata(name: String, source: &Self::Source) -> CollectionMetadata;
+
+    fn format_bytes(bytes: i64) -> String {
+        if bytes.abs() < 1024 {
+            return format!("{} Bytes", bytes);
+        }
+
+        let mut value = bytes as f64 / 1024.0;
+        let mut unit = 0;
+        while value.abs() >= 1024.0 && unit < BYTE_UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        format!("{:.2} {}", value, BYTE_UNITS[unit])
+    }
+
+    fn flatten_group(order: usize, name: String, value: &Value) -> TableDataGroup {
+        let mut rows = Vec::new();
+        flatten_value(String::new(), value, &mut rows);
+
+        let mut group = TableDataGroup::new(order, name);
+        for (key, value) in rows {
+            group.push(key, value);
+        }
+
+        group
+    }
+
+}
+
+fn flatten_value(prefix: String, value: &Value, rows: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                let path = if prefix.is_empty() { key.clone() } else { format!("{}.{}", prefix, key) };
+                flatten_value(path, field, rows);
+            }
+        },
+        Value::Array(items) => {
+            for (index, item) in items.iter().enumerate() {
+                flatten_value(format!("{}[{}]", prefix, index), item, rows);
+            }
+        },
+        Value::String(text) => rows.push((prefix, text.clone())),
+        other => rows.push((prefix, other.to_string())),
+    }
+}
\ No newline at end of file
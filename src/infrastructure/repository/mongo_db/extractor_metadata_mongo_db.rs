@@ -3,14 +3,18 @@ use std::time::Duration;
 use chrono::Local;
 use mongodb::bson::{Bson, Document};
 
-use crate::{commons::exception::connect_exception::ConnectException, domain::table::table_data_group::TableDataGroup};
+use crate::{commons::exception::connect_exception::ConnectException, domain::{metadata::{collection_metadata::CollectionMetadata, server_metadata::ServerMetadata}, table::table_data_group::TableDataGroup}, infrastructure::repository::i_metadata_extractor::IMetadataExtractor};
+
+pub(crate) const DEGRADED_KEY: &str = "degraded";
 
 pub(crate) struct ExtractorMetadataMongoDb {
 }
 
-impl ExtractorMetadataMongoDb {
-    
-    pub(crate) fn from_db(server_info: &Document) -> Result<Vec<TableDataGroup>, ConnectException> {
+
+impl IMetadataExtractor for ExtractorMetadataMongoDb {
+    type Source = Document;
+
+    fn server_groups(server_info: &Document) -> Result<Vec<TableDataGroup>, ConnectException> {
         let mut metadata: Vec<TableDataGroup> = Vec::new();
         metadata.push(Self::metadata_general(server_info)?);
         metadata.push(Self::metadata_connection(server_info)?);
@@ -20,6 +24,82 @@ impl ExtractorMetadataMongoDb {
         Ok(metadata)
     }
 
+    fn server_metadata(server_info: &Document) -> ServerMetadata {
+        let mut metadata = ServerMetadata::new(
+            server_info.get_str("host").unwrap_or_default().to_string(),
+            server_info.get_str("version").unwrap_or_default().to_string(),
+            Self::number(server_info, "uptimeMillis")
+        );
+        metadata.set_degraded(Self::is_degraded(server_info));
+
+        if let Ok(connections) = server_info.get_document("connections") {
+            metadata.set_connections(
+                Self::number(connections, "current"),
+                Self::number(connections, "available"),
+                Self::number(connections, "active")
+            );
+        }
+
+        if let Ok(operations) = server_info.get_document("opcounters") {
+            metadata.set_operations(
+                Self::number(operations, "insert"),
+                Self::number(operations, "query"),
+                Self::number(operations, "update"),
+                Self::number(operations, "delete")
+            );
+        }
+
+        metadata
+    }
+
+    fn collection_metadata(name: String, collection_info: &Document) -> CollectionMetadata {
+        let mut metadata = CollectionMetadata::new(
+            name,
+            Self::number(collection_info, "count"),
+            Self::number(collection_info, "size"),
+            Self::number(collection_info, "storageSize"),
+            Self::number(collection_info, "avgObjSize"),
+            Self::number(collection_info, "nindexes"),
+            Self::number(collection_info, "totalIndexSize")
+        );
+        metadata.set_degraded(Self::is_degraded(collection_info));
+        metadata
+    }
+
+    fn collection_groups(collection_info: Document) -> Result<Vec<TableDataGroup>, ConnectException> {
+        let mut metadata: Vec<TableDataGroup> = Vec::new();
+        if let Ok(index_sizes) = collection_info.get_document("indexSizes") {
+            let json = Bson::Document(index_sizes.clone()).into_relaxed_extjson();
+            metadata.push(Self::flatten_group(1, String::from("index_sizes"), &json));
+        }
+        metadata.insert(0, Self::_from_collections(vec![collection_info])?);
+        Ok(metadata)
+    }
+
+    fn collections_groups(collections_info: Vec<Document>) -> Result<Vec<TableDataGroup>, ConnectException> {
+        let collections = collections_info.len();
+        let mut group = Self::_from_collections(collections_info)?;
+        group.push(String::from("Collections"), collections.to_string());
+        Ok(vec![group])
+    }
+
+}
+
+impl ExtractorMetadataMongoDb {
+
+    fn is_degraded(document: &Document) -> bool {
+        document.get_bool(DEGRADED_KEY).unwrap_or(false)
+    }
+
+    fn number(document: &Document, key: &str) -> i64 {
+        match document.get(key) {
+            Some(Bson::Int32(value)) => i64::from(*value),
+            Some(Bson::Int64(value)) => *value,
+            Some(Bson::Double(value)) => *value as i64,
+            _ => 0,
+        }
+    }
+
     fn metadata_general(server_info: &Document) -> Result<TableDataGroup, ConnectException> {
         let mut group = TableDataGroup::new(0, String::from("general"));
 
@@ -62,23 +142,30 @@ impl ExtractorMetadataMongoDb {
            uptime
         );
 
+        if let Ok(local_time) = server_info.get_datetime("localTime") {
+            let skew = local_time.timestamp_millis() - now.timestamp_millis();
+            group.push(
+                String::from("Clock Skew"),
+                format!("{} ms", skew)
+            );
+        }
+
+        if Self::is_degraded(server_info) {
+            group.push(
+                String::from("Metadata"),
+                String::from("Degraded, serverStatus is not permitted")
+            );
+        }
+
         Ok(group)
     }
 
     fn metadata_connection(server_info: &Document) -> Result<TableDataGroup, ConnectException> {
         let mut group = TableDataGroup::new(1, String::from("connection"));
 
-        let o_connections = server_info.get("connections");
-        if o_connections.is_none() {
+        let Ok(connections) = server_info.get_document("connections") else {
             return Ok(group);
-        }
-
-        let r_connections = o_connections.unwrap().as_document();
-        if r_connections.is_none() {
-            return Ok(group);
-        }
-
-        let connections = r_connections.unwrap();
+        };
 
         group.push(
             String::from("Current"),
@@ -103,41 +190,17 @@ impl ExtractorMetadataMongoDb {
     fn metadata_lock(server_info: &Document) -> Result<TableDataGroup, ConnectException> {
         let mut group = TableDataGroup::new(2, String::from("global_lock"));
 
-        let o_lock = server_info.get("globalLock");
-        if o_lock.is_none() {
-            return Ok(group);
-        }
-
-        let r_lock = o_lock.unwrap().as_document();
-        if r_lock.is_none() {
+        let Ok(lock) = server_info.get_document("globalLock") else {
             return Ok(group);
-        }
+        };
 
-        let lock = r_lock.unwrap();
-
-        let o_queue = lock.get("currentQueue");
-        if o_queue.is_none() {
+        let Ok(queue) = lock.get_document("currentQueue") else {
             return Ok(group);
-        }
+        };
 
-        let r_queue = o_queue.unwrap().as_document();
-        if r_queue.is_none() {
+        let Ok(active) = lock.get_document("activeClients") else {
             return Ok(group);
-        }
-
-        let queue = r_queue.unwrap();
-
-        let o_active = lock.get("currentQueue");
-        if o_active.is_none() {
-            return Ok(group);
-        }
-
-        let r_active = o_active.unwrap().as_document();
-        if r_active.is_none() {
-            return Ok(group);
-        }
-
-        let active = r_active.unwrap();
+        };
 
         group.push(
             String::from("Active Clients"),
@@ -170,17 +233,9 @@ impl ExtractorMetadataMongoDb {
     fn metadata_operation(server_info: &Document) -> Result<TableDataGroup, ConnectException> {
         let mut group = TableDataGroup::new(3, String::from("operation"));
 
-        let o_connections = server_info.get("opcounters");
-        if o_connections.is_none() {
+        let Ok(connections) = server_info.get_document("opcounters") else {
             return Ok(group);
-        }
-
-        let r_connections = o_connections.unwrap().as_document();
-        if r_connections.is_none() {
-            return Ok(group);
-        }
-
-        let connections = r_connections.unwrap();
+        };
 
         group.push(
             String::from("Total Inserts"),
@@ -202,21 +257,6 @@ impl ExtractorMetadataMongoDb {
         Ok(group)
     }
 
-    pub(crate) fn from_collection(collection_info: Document) -> Result<Vec<TableDataGroup>, ConnectException> {
-        let mut metadata: Vec<TableDataGroup> = Vec::new();
-        metadata.push(Self::_from_collections(vec![collection_info])?);
-        Ok(metadata)
-    }
-
-    pub(crate) fn from_collections(collections_info: Vec<Document>) -> Result<Vec<TableDataGroup>, ConnectException> {
-        let collections = collections_info.len();
-        let mut group = Self::_from_collections(collections_info)?;
-        group.push(String::from("Collections"), collections.to_string());
-        let mut metadata: Vec<TableDataGroup> = Vec::new();
-        metadata.push(group);
-        Ok(metadata)
-    }
-
     fn _from_collections(collections_info: Vec<Document>) -> Result<TableDataGroup, ConnectException> {
         let mut group = TableDataGroup::new(0, String::from("collection"));
 
@@ -228,26 +268,36 @@ impl ExtractorMetadataMongoDb {
         let mut total_index_size = 0;
         let mut total_size = 0;
         let mut index_sizes = 0;
+        let mut degraded = 0;
 
         for collection_info in collections_info {
-            count = count + collection_info.get("count").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            size =  size + collection_info.get("size").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            storage_size  = storage_size + collection_info.get("storageSize").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            avg_obj_size = avg_obj_size + collection_info.get("avgObjSize").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            nindexes = nindexes + collection_info.get("nindexes").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            total_index_size = total_index_size + collection_info.get("totalIndexSize").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            total_size = total_size + collection_info.get("totalSize").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
-            index_sizes = index_sizes + collection_info.get("indexSizes").unwrap_or(&Bson::String(String::new())).to_string().parse::<i64>().unwrap_or_default();
+            if Self::is_degraded(&collection_info) {
+                degraded += 1;
+            }
+            count += Self::number(&collection_info, "count");
+            size += Self::number(&collection_info, "size");
+            storage_size += Self::number(&collection_info, "storageSize");
+            avg_obj_size += Self::number(&collection_info, "avgObjSize");
+            nindexes += Self::number(&collection_info, "nindexes");
+            total_index_size += Self::number(&collection_info, "totalIndexSize");
+            total_size += Self::number(&collection_info, "totalSize");
+            if let Ok(sizes) = collection_info.get_document("indexSizes") {
+                index_sizes += sizes.keys().map(|k| Self::number(sizes, k)).sum::<i64>();
+            }
         }
 
         group.push(String::from("Documents"), count.to_string());
-        group.push(String::from("Data size"), format!("{:?} Bytes", size));
-        group.push(String::from("Storage size"), format!("{:?} Bytes", storage_size));
-        group.push(String::from("Average Object size"), format!("{:?} Bytes", avg_obj_size));
+        group.push(String::from("Data size"), Self::format_bytes(size));
+        group.push(String::from("Storage size"), Self::format_bytes(storage_size));
+        group.push(String::from("Average Object size"), Self::format_bytes(avg_obj_size));
         group.push(String::from("Indexes Count"), nindexes.to_string());
-        group.push(String::from("Index size"),format!("{:?} Bytes", total_index_size));
-        group.push(String::from("Total Size"), total_size.to_string());
-        group.push(String::from("Indexes"), index_sizes.to_string());
+        group.push(String::from("Index size"), Self::format_bytes(total_index_size));
+        group.push(String::from("Total Size"), Self::format_bytes(total_size));
+        group.push(String::from("Indexes"), Self::format_bytes(index_sizes));
+
+        if degraded > 0 {
+            group.push(String::from("Metadata"), format!("Degraded, collStats is not permitted on {} collections", degraded));
+        }
 
         Ok(group)
     }
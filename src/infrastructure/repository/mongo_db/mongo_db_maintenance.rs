@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use mongodb::bson::{doc, Bson, Document};
+
+use crate::{
+    commons::exception::connect_exception::ConnectException,
+    domain::{collection::maintenance_report::MaintenanceReport, filter::collection_query::CollectionQuery, table::table_data_group::TableDataGroup},
+    infrastructure::repository::i_db_maintenance::IDBMaintenance,
+};
+
+use super::mongo_db_repository::MongoDbRepository;
+
+impl MongoDbRepository {
+
+    async fn maintenance_command(&self, data_base: &str, command: Document) -> Result<Document, ConnectException> {
+        let result = self.client().database(data_base)
+            .run_command(command, None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        Ok(result.unwrap())
+    }
+
+    fn maintenance_report(operation: &str, result: &Document, fields: &[(&str, &str)]) -> MaintenanceReport {
+        let mut details = TableDataGroup::new(0, String::from(operation));
+        for (key, name) in fields {
+            if let Some(value) = result.get(*key) {
+                details.push(String::from(*name), value.to_string());
+            }
+        }
+
+        let ok = match result.get("ok") {
+            Some(Bson::Double(ok)) => *ok == 1.0,
+            Some(Bson::Int32(ok)) => *ok == 1,
+            Some(Bson::Int64(ok)) => *ok == 1,
+            _ => false,
+        };
+
+        let valid = result.get_bool("valid").unwrap_or(true);
+
+        MaintenanceReport::new(
+            String::from(operation),
+            ok && valid,
+            details,
+            MongoDbRepository::string_array(result, "errors"),
+            MongoDbRepository::string_array(result, "warnings")
+        )
+    }
+
+    fn string_array(result: &Document, key: &str) -> Vec<String> {
+        result.get_array(key)
+            .map(|a| a.iter()
+                .map(|v| v.as_str().map(String::from).unwrap_or(v.to_string()))
+                .collect())
+            .unwrap_or_default()
+    }
+
+}
+
+#[async_trait]
+impl IDBMaintenance for MongoDbRepository {
+
+    async fn collection_compact(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        let command = doc! { "compact": query.collection() };
+        let result = self.maintenance_command(&query.data_base(), command).await?;
+
+        Ok(MongoDbRepository::maintenance_report("compact", &result, &[
+            ("bytesFreed", "Bytes Freed"),
+        ]))
+    }
+
+    async fn collection_reindex(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        let command = doc! { "reIndex": query.collection() };
+        let result = self.maintenance_command(&query.data_base(), command).await?;
+
+        Ok(MongoDbRepository::maintenance_report("reindex", &result, &[
+            ("nIndexesWas", "Indexes Before"),
+            ("nIndexes", "Indexes After"),
+        ]))
+    }
+
+    async fn collection_validate(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        let command = doc! { "validate": query.collection() };
+        let result = self.maintenance_command(&query.data_base(), command).await?;
+
+        Ok(MongoDbRepository::maintenance_report("validate", &result, &[
+            ("valid", "Valid"),
+            ("nrecords", "Records"),
+            ("nIndexes", "Indexes"),
+            ("nInvalidDocuments", "Invalid Documents"),
+            ("nNonCompliantDocuments", "Non Compliant Documents"),
+        ]))
+    }
+
+}
\ No newline at end of file
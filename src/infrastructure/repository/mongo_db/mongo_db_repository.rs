@@ -1,66 +1,180 @@
+use std::{collections::HashMap, io::ErrorKind as IoErrorKind, path::{Path, PathBuf}, sync::Mutex, time::Duration};
+
 use async_trait::async_trait;
+use lazy_static::lazy_static;
 
 use mongodb::{
-    bson::{doc, to_document, Bson, Document},
-    options::{AggregateOptions, ClientOptions},
-    Client, Collection, Cursor, Database,
+    bson::{doc, oid::ObjectId, Bson, Document},
+    error::{BulkWriteFailure, ErrorKind},
+    options::{AggregateOptions, ClientOptions, FindOneAndUpdateOptions, FindOneOptions, FindOptions, InsertManyOptions, ReplaceOptions, ReturnDocument, ServerAddress},
+    Client, ClientSession, Collection, Cursor, Database,
 };
 
-use futures_util::stream::StreamExt;
+use chrono::Local;
+use futures_util::{future::try_join_all, stream::BoxStream, StreamExt, TryStreamExt};
 use serde_json::{from_str, Value};
+use tokio::{fs::{self, File}, io::{AsyncReadExt, AsyncWriteExt, BufReader, BufWriter}};
 use uuid::Uuid;
 
 use crate::{
     commons::{
         configuration::definition::mongo_db::mongo_db,
-        exception::connect_exception::ConnectException,
+        exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind},
     },
     domain::{
         collection::{
-            collection_data::CollectionData, collection_definition::CollectionDefinition, generate_collection_query::GenerateCollectionQuery
+            collection_data::CollectionData, collection_definition::CollectionDefinition, collection_index::CollectionIndex, collection_validator::CollectionValidator, dump_report::DumpReport, generate_collection_query::GenerateCollectionQuery, import_failure::ImportFailure, import_integrity::ImportIntegrity, import_preview::ImportPreview, import_preview_entry::ImportPreviewEntry
         },
         connection_data::ConnectionData,
         data_base::generate_database_query::GenerateDatabaseQuery,
+        host_latency::HostLatency,
+        metadata::{collection_metadata::CollectionMetadata, data_base_metadata::DataBaseMetadata, server_metadata::ServerMetadata},
+        server_time::ServerTime,
         document::{
-            document_data::DocumentData, document_key::DocumentKey,
-            document_key_attribute::DocumentKeyAttribute, document_schema::DocumentSchema,
+            document_data::DocumentData, document_delete_preview::DocumentDeletePreview, document_field_size::DocumentFieldSize, document_insert_result::DocumentInsertResult, document_key::DocumentKey,
+            document_key_attribute::DocumentKeyAttribute, document_schema::DocumentSchema, document_size::DocumentSize, schema_field::SchemaField, lineage_policy::LineagePolicy, soft_delete_policy::SoftDeletePolicy, document_update_field::DocumentUpdateField, e_id_strategy::EIdStrategy,
         },
         e_json_type::EJSONType,
+        e_read_preference::EReadPreference,
+        routing::routing_event::RoutingEvent,
         field::generate::field_data::FieldData,
         filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery, filter_element::FilterElement},
         table::table_data_group::TableDataGroup,
+        update::{update_preview::UpdatePreview, update_spec::UpdateSpec, update_summary::UpdateSummary},
     },
-    infrastructure::repository::i_db_repository::IDBRepository,
+    infrastructure::{connection_pool::ConnectionPool, repository::{i_db_repository::IDBRepository, i_metadata_extractor::IMetadataExtractor}},
 };
 
-use super::{e_action::EAction, extractor_metadata_mongo_db::ExtractorMetadataMongoDb};
+use super::{e_action::EAction, extractor_metadata_mongo_db::{ExtractorMetadataMongoDb, DEGRADED_KEY}, mongo_db_router::MongoDbRouter};
+
+const PREVIEW_SAMPLES: i32 = 5;
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+const ID_BATCH_SIZE: usize = 1000;
+const REVISION_FIELD: &str = "_rev";
+const SEQUENCE_COLLECTION: &str = "_sequences";
+const HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const DUMP_BATCH_SIZE: usize = 1000;
+const NAMESPACE_EXISTS: i32 = 48;
+const DOCUMENT_SIZE_LIMIT: usize = 16 * 1024 * 1024;
+const HOT_SPOT_SHARE: f64 = 0.1;
+const SCHEMA_SAMPLES: i32 = 100;
+const SCHEMA_EXAMPLES: usize = 3;
+const SCHEMA_EXAMPLE_LENGTH: usize = 80;
+
+lazy_static! {
+    static ref POOL: Mutex<ConnectionPool<(Client, Option<MongoDbRouter>)>> = Mutex::new(ConnectionPool::new());
+}
 
 #[derive(Clone)]
 pub struct MongoDbRepository {
-    client: Client
+    connection: ConnectionData,
+    client: Client,
+    router: Option<MongoDbRouter>
 }
 
 impl MongoDbRepository {
     
-    pub async fn new(connection: &ConnectionData) -> Result<impl IDBRepository, ConnectException> {
-        let client = MongoDbRepository::connect(connection.connection()).await;
-        if client.is_err() {
-            let exception = ConnectException::new(client.err().unwrap().to_string());
-            return Err(exception);
-        }
+    pub async fn new(connection: &ConnectionData) -> Result<MongoDbRepository, ConnectException> {
+        let (client, router) = MongoDbRepository::pooled_client(connection).await?;
         
         let instance = MongoDbRepository {
-            client: client.ok().unwrap()
+            connection: connection.clone(),
+            client,
+            router
         };
 
         Ok(instance)
     }
 
-    async fn connect(connection: String) -> Result<Client, mongodb::error::Error> {
-        let client_options = ClientOptions::parse(connection).await?;
+    pub fn pool() -> &'static Mutex<ConnectionPool<(Client, Option<MongoDbRouter>)>> {
+        &POOL
+    }
+
+    async fn pooled_client(connection: &ConnectionData) -> Result<(Client, Option<MongoDbRouter>), ConnectException> {
+        let key = connection.client_key();
+
+        let cached = POOL.lock().expect("Could not lock mutex").checkout(&key);
+        if let Some(((client, router), idle)) = cached {
+            if idle < HEALTH_CHECK_INTERVAL || MongoDbRepository::is_healthy(&client).await {
+                return Ok((client, router));
+            }
+            POOL.lock().expect("Could not lock mutex").evict(&key);
+        }
+
+        let pooled = MongoDbRepository::connect(connection).await?;
+        POOL.lock().expect("Could not lock mutex").store(key, pooled.clone());
+
+        Ok(pooled)
+    }
+
+    async fn is_healthy(client: &Client) -> bool {
+        let admin = client.database("admin");
+        let ping = admin.run_command(doc! {"ping": 1}, None);
+        matches!(tokio::time::timeout(PROBE_TIMEOUT, ping).await, Ok(Ok(_)))
+    }
+
+    async fn connect(connection: &ConnectionData) -> Result<(Client, Option<MongoDbRouter>), ConnectException> {
+        let mut client_options = MongoDbRepository::client_options(connection).await?;
+
+        let router = connection.routing().map(|policy| {
+            let read_preference = connection.read_preference().unwrap_or(EReadPreference::PRIMARY);
+            MongoDbRouter::new(read_preference, policy)
+        });
+        if let Some(router) = &router {
+            router.apply(&mut client_options);
+        }
+
         let client = Client::with_options(client_options)?;
 
-        Ok(client)
+        Ok((client, router))
+    }
+
+    async fn client_options(connection: &ConnectionData) -> Result<ClientOptions, ConnectException> {
+        let mut client_options = ClientOptions::parse(connection.connection()).await?;
+        if let Some(read_preference) = connection.read_preference() {
+            client_options.selection_criteria = Some(read_preference.as_mongo_selection());
+        }
+
+        if let Some(tls) = connection.tls() {
+            client_options.tls = Some(tls.as_mongo_tls()?);
+        }
+
+        if let Some(credential) = connection.credential() {
+            client_options.credential = Some(credential.as_mongo_credential());
+        }
+
+        Ok(client_options)
+    }
+
+    async fn probe_host(&self, options: &ClientOptions, host: &ServerAddress) -> HostLatency {
+        let mut host_options = options.clone();
+        host_options.hosts = vec![host.clone()];
+        host_options.direct_connection = Some(true);
+        host_options.server_selection_timeout = Some(PROBE_TIMEOUT);
+
+        let client = match Client::with_options(host_options) {
+            Ok(client) => client,
+            Err(error) => return HostLatency::unreachable(host.to_string(), error.to_string()),
+        };
+
+        let start = Local::now().timestamp_millis();
+        let result = client.database("admin")
+            .run_command(doc! {"hello": 1}, None).await;
+        let latency = Local::now().timestamp_millis() - start;
+
+        match result {
+            Ok(hello) => {
+                let region = hello.get_document("tags").ok()
+                    .and_then(|t| t.get_str("region").ok())
+                    .map(String::from);
+                HostLatency::reachable(host.to_string(), latency, region)
+            },
+            Err(error) => HostLatency::unreachable(host.to_string(), error.to_string()),
+        }
+    }
+
+    pub(crate) fn client(&self) -> &Client {
+        &self.client
     }
 
     fn collection_from_resource(&self, query: &GenerateCollectionQuery) -> Collection<Document> {
@@ -78,9 +192,76 @@ impl MongoDbRepository {
         self.data_base(data_base).collection(&collection)
     }
 
+    async fn generate_id(&self, query: &CollectionQuery) -> Result<Bson, ConnectException> {
+        let strategy = self.connection.id_strategy(&query.data_base(), &query.collection());
+        if let Some(key) = strategy.generate_key() {
+            return Ok(Bson::String(key));
+        }
+
+        if strategy == EIdStrategy::OBJECTID {
+            return Ok(Bson::ObjectId(ObjectId::new()));
+        }
+
+        let sequences = self.collection(&query.data_base(), &String::from(SEQUENCE_COLLECTION));
+        let options = FindOneAndUpdateOptions::builder()
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .build();
+
+        let result = sequences.find_one_and_update(
+            doc! {"_id": query.collection()},
+            doc! {"$inc": {"seq": 1i64}},
+            options).await;
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
+        }
+
+        let Some(sequence) = result.unwrap().and_then(|d| d.get_i64("seq").ok()) else {
+            let message = format!("Could not generate a sequential key for '{}'.", query.collection());
+            return Err(ConnectException::new(message));
+        };
+
+        Ok(Bson::Int64(sequence))
+    }
+
     async fn find_cursor(&self, query: &DocumentQuery) -> Result<Cursor<Document>, ConnectException>  {
+        let pipeline = self.find_pipeline(query)?;
+        self.aggregate_cursor(query, pipeline).await
+    }
+
+    async fn aggregate_cursor(&self, query: &DocumentQuery, pipeline: Vec<Document>) -> Result<Cursor<Document>, ConnectException>  {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let r_cursor = collection.aggregate(pipeline, AggregateOptions::default()).await;
+        if let Err(error) = &r_cursor {
+            let exception = ConnectException::from(error.clone());
+            return Err(exception);
+        }
+
+        Ok(r_cursor.unwrap())
+    }
+
+    async fn find_session_documents(&self, query: &DocumentQuery, session: &mut ClientSession) -> Result<Vec<Result<Document, mongodb::error::Error>>, ConnectException>  {
+        let pipeline = self.find_pipeline(query)?;
         let collection = self.collection(&query.data_base(), &query.collection());
 
+        let r_cursor = collection.aggregate_with_session(pipeline, AggregateOptions::default(), session).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let mut cursor = r_cursor.unwrap();
+
+        let mut documents = Vec::new();
+        while let Some(r_document) = cursor.next(session).await {
+            documents.push(r_document);
+        }
+
+        Ok(documents)
+    }
+
+    fn find_pipeline(&self, query: &DocumentQuery) -> Result<Vec<Document>, ConnectException>  {
         let mut filter = FilterElement::new();
 
         let o_filter = query.filter();
@@ -90,6 +271,13 @@ impl MongoDbRepository {
 
         let mut pipeline: Vec<Document> = filter.as_mongo_agregate()?;
 
+        let sort: Document = query.sort().iter()
+            .map(|s| (s.field(), Bson::Int32(if s.is_descending() { -1 } else { 1 })))
+            .collect();
+        if !sort.is_empty() {
+            pipeline.push(doc! {"$sort": sort});
+        }
+
         if let Some(skip) = query.skip() {
             pipeline.push(doc! {"$skip":  Bson::Int64(skip as i64)});
         }
@@ -98,19 +286,157 @@ impl MongoDbRepository {
             pipeline.push(doc! {"$limit":  Bson::Int64(limit as i64)});
         }
 
-        let r_cursor = collection.aggregate(pipeline, AggregateOptions::default()).await;
-        if r_cursor.is_err() {
-            let exception = ConnectException::new(r_cursor.unwrap_err().to_string());
-            return Err(exception);
+        let projection: Document = query.projection().into_iter()
+            .map(|f| (f, Bson::Int32(1)))
+            .collect();
+        if !projection.is_empty() {
+            pipeline.push(doc! {"$project": projection});
         }
 
-        Ok(r_cursor.unwrap())
+        Ok(pipeline)
+    }
+
+    fn match_filter(&self, query: &DocumentQuery) -> Result<Document, ConnectException> {
+        let filter = query.filter().unwrap_or_else(FilterElement::new);
+
+        let mut conditions = Vec::new();
+        for stage in filter.as_mongo_agregate()? {
+            match stage.get_document("$match") {
+                Ok(condition) if stage.len() == 1 => conditions.push(condition.clone()),
+                _ => return Err(ConnectException::new(String::from("Only field filters can be used to match documents directly."))),
+            }
+        }
+
+        match conditions.len() {
+            0 => Ok(doc! {}),
+            1 => Ok(conditions.remove(0)),
+            _ => Ok(doc! {"$and": conditions}),
+        }
+    }
+
+    async fn preview_documents(&self, query: &DocumentQuery, transform: Vec<Document>) -> Result<(usize, Vec<DocumentData>), ConnectException> {
+        let mut sample = vec![doc! { "$sample": { "size": PREVIEW_SAMPLES } }];
+        sample.extend(transform);
+
+        let mut pipeline = self.find_pipeline(query)?;
+        pipeline.push(doc! {
+            "$facet": {
+                "total": [{ "$count": "total" }],
+                "samples": sample
+            }
+        });
+
+        let mut cursor = self.aggregate_cursor(query, pipeline).await?;
+
+        let facet = match cursor.next().await {
+            Some(Ok(facet)) => facet,
+            Some(Err(error)) => return Err(ConnectException::from(error)),
+            None => return Ok((0, Vec::new())),
+        };
+
+        let total = MongoDbRepository::facet_total(&facet);
+
+        let mut samples = Vec::new();
+        if let Ok(documents) = facet.get_array("samples") {
+            for document in documents.iter().filter_map(|d| d.as_document()) {
+                samples.push(self.make_document_data(query.data_base(), query.collection(), document)?);
+            }
+        }
+
+        Ok((total, samples))
+    }
+
+    fn facet_total(facet: &Document) -> usize {
+        let total = facet.get_array("total").ok()
+            .and_then(|t| t.first())
+            .and_then(|t| t.as_document())
+            .and_then(|t| t.get("total"))
+            .and_then(|t| t.as_i64().or(t.as_i32().map(i64::from)))
+            .unwrap_or_default();
+        total as usize
+    }
+
+    async fn partition_filters(&self, query: &DocumentQuery, partitions: usize) -> Result<Vec<Document>, ConnectException> {
+        let pipeline = vec![
+            doc! {"$bucketAuto": {"groupBy": "$_id", "buckets": partitions.max(1) as i32}}
+        ];
+
+        let buckets: Vec<Document> = self.aggregate_cursor(query, pipeline).await?
+            .try_collect().await
+            .map_err(ConnectException::from)?;
+
+        let mut filters = Vec::new();
+        for (index, bucket) in buckets.iter().enumerate() {
+            let bounds = bucket.get_document("_id");
+            if let Err(error) = bounds {
+                return Err(ConnectException::from(error));
+            }
+
+            let bounds = bounds.unwrap();
+            let min = bounds.get("min").cloned().unwrap_or(Bson::MinKey);
+            let max = bounds.get("max").cloned().unwrap_or(Bson::MaxKey);
+
+            if index == buckets.len() - 1 {
+                filters.push(doc! {"_id": {"$gte": min, "$lte": max}});
+            } else {
+                filters.push(doc! {"_id": {"$gte": min, "$lt": max}});
+            }
+        }
+
+        Ok(filters)
     }
 
     async fn collections_metadata_document(&self, data_base: String, collection: String) -> Result<Document, ConnectException> {
-        let data_base = self.data_base(&data_base);
-        Ok(data_base
-            .run_command(doc! {"collStats": collection}, None).await.unwrap())
+        let result = self.data_base(&data_base)
+            .run_command(doc! {"collStats": &collection}, None).await
+            .map_err(ConnectException::from);
+
+        match result {
+            Err(error) if error.is(EConnectErrorKind::AUTH) => self.degraded_collection_document(data_base, collection).await,
+            result => result,
+        }
+    }
+
+    async fn degraded_collection_document(&self, data_base: String, collection: String) -> Result<Document, ConnectException> {
+        let count = self.collection(&data_base, &collection)
+            .estimated_document_count(None).await
+            .map_err(ConnectException::from)?;
+        let indexes = self.first_batch(&data_base, doc! {"listIndexes": &collection}).await?;
+
+        Ok(doc! {
+            "ns": format!("{}.{}", data_base, collection),
+            "count": count as i64,
+            "nindexes": indexes.len() as i32,
+            DEGRADED_KEY: true
+        })
+    }
+
+    async fn server_status_document(&self) -> Result<Document, ConnectException> {
+        let admin = self.client.database("admin");
+        let result = admin.run_command(doc! {"serverStatus": 1}, None).await
+            .map_err(ConnectException::from);
+
+        let error = match result {
+            Err(error) if error.is(EConnectErrorKind::AUTH) => error,
+            result => return result,
+        };
+
+        let hello = admin.run_command(doc! {"hello": 1}, None).await;
+        let build = admin.run_command(doc! {"buildInfo": 1}, None).await;
+        let (Ok(hello), Ok(build)) = (hello, build) else {
+            return Err(error);
+        };
+
+        let mut document = doc! {
+            "host": hello.get_str("me").unwrap_or_default(),
+            "version": build.get_str("version").unwrap_or_default(),
+            DEGRADED_KEY: true
+        };
+        if let Ok(local_time) = hello.get_datetime("localTime") {
+            document.insert("localTime", *local_time);
+        }
+
+        Ok(document)
     }
 
     fn document_keys(&self, document: &Document) -> Result<Vec<DocumentKey>, ConnectException> {
@@ -133,17 +459,26 @@ impl MongoDbRepository {
                     DocumentKeyAttribute::new(String::from("$oid"), String::from("true"))
                 ]
             )),
-            Err(_) => {
-                let id = o_id.unwrap().as_str();
-                if let None = id {
+            Err(_) => match o_id.unwrap() {
+                Bson::String(id) => DocumentKey::new(
+                    String::from(key), 
+                    id.clone(),
+                    EJSONType::STRING,
+                    Vec::new()),
+                Bson::Int32(id) => DocumentKey::new(
+                    String::from(key), 
+                    id.to_string(),
+                    EJSONType::NUMERIC,
+                    Vec::new()),
+                Bson::Int64(id) => DocumentKey::new(
+                    String::from(key), 
+                    id.to_string(),
+                    EJSONType::NUMERIC,
+                    Vec::new()),
+                _ => {
                     let exception = ConnectException::new(String::from("Identifier not found."));
                     return Err(exception);
-                }
-                DocumentKey::new(
-                String::from(key), 
-                String::from(id.unwrap()),
-                EJSONType::STRING,
-                Vec::new())
+                },
             },
         };
         
@@ -153,17 +488,60 @@ impl MongoDbRepository {
     }
 
     async fn query_action(&self, query: &DocumentQuery, action: EAction, value: Option<&str>) -> Result<CollectionData, ConnectException> {
+        self.query_action_session(query, action, value, None).await
+    }
+
+    async fn query_action_transaction(&self, query: &DocumentQuery, action: EAction, value: Option<&str>) -> Result<CollectionData, ConnectException> {
+        let r_session = self.client.start_session(None).await;
+        if let Err(error) = r_session {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut session = r_session.unwrap();
+
+        let result = session.start_transaction(None).await;
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
+        }
+
+        let result = self.query_action_session(query, action, value, Some(&mut session)).await;
+        if result.is_err() {
+            let _ = session.abort_transaction().await;
+            return result;
+        }
+
+        let commit = session.commit_transaction().await;
+        if let Err(error) = commit {
+            let _ = session.abort_transaction().await;
+            return Err(ConnectException::from(error));
+        }
+
+        result
+    }
+
+    async fn query_action_session(&self, query: &DocumentQuery, action: EAction, value: Option<&str>, mut session: Option<&mut ClientSession>) -> Result<CollectionData, ConnectException> {
         let mut documents = Vec::<DocumentData>::new();
         
         let collection = self.collection(&query.data_base(), &query.collection());
 
-        let mut cursor = self.find_cursor(query).await?;
+        let r_documents = match session.as_deref_mut() {
+            Some(session) => self.find_session_documents(query, session).await?,
+            None => self.find_cursor(query).await?.collect::<Vec<_>>().await,
+        };
 
         let mut ids_to_action = vec![];
+        let mut warnings = Vec::new();
         
-        while let Some(r_document) = cursor.next().await {
+        let mut position = 0;
+        for r_document in r_documents {
+            position += 1;
+
             if let Err(error) = r_document {
-                let exception = ConnectException::new(error.to_string());
+                if action == EAction::FIND && matches!(*error.kind, ErrorKind::BsonDeserialization(_)) {
+                    warnings.push(format!("Document at position {} skipped: {}", position, error));
+                    continue;
+                }
+                let exception = ConnectException::from(error);
                 return Err(exception);
             }
 
@@ -173,21 +551,29 @@ impl MongoDbRepository {
                 ids_to_action.push(id.clone());
             }
     
-            let data = self.make_document_data(query.data_base(), query.collection(), &document)?;
-            documents.push(data);
+            match self.make_document_data(query.data_base(), query.collection(), &document) {
+                Ok(data) => documents.push(data),
+                Err(error) if action == EAction::FIND => {
+                    warnings.push(MongoDbRepository::document_warning(&document, position, &error));
+                },
+                Err(error) => return Err(error),
+            }
 
             if action == EAction::UPDATE {
-                self.update_document(&collection, &document, value).await?;
+                self.update_document(&collection, &document, value, session.as_deref_mut()).await?;
             }
         }
 
         if action == EAction::DELETE {
-            self.delete_document(&collection, ids_to_action).await?;
+            self.delete_document(&collection, ids_to_action, session.as_deref_mut()).await?;
         }
         
-        let r_total = collection.estimated_document_count(None).await;
+        let r_total = match session {
+            Some(session) => collection.count_documents_with_session(doc! {}, None, session).await,
+            None => collection.estimated_document_count(None).await,
+        };
         if let Err(error) = r_total {
-            let exception = ConnectException::new(error.to_string());
+            let exception = ConnectException::from(error);
             return Err(exception);
         }
 
@@ -197,20 +583,72 @@ impl MongoDbRepository {
             return Err(exception);
         }
 
-        let data = CollectionData::new(
+        let mut data = CollectionData::new(
             total.unwrap(),
             query.limit(),
             query.skip(), 
             documents
         );
+        data.set_warnings(warnings);
      
         Ok(data)
     }
 
+    async fn move_documents_session(&self, query: &DocumentQuery, target_collection: &str, session: &mut ClientSession) -> Result<Vec<DocumentData>, ConnectException> {
+        let documents: Result<Vec<Document>, mongodb::error::Error> = self.find_session_documents(query, session).await?
+            .into_iter()
+            .collect();
+        if let Err(error) = documents {
+            return Err(ConnectException::from(error));
+        }
+
+        let documents = documents.unwrap();
+        if documents.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let ids: Vec<Bson> = documents.iter()
+            .filter_map(|d| d.get("_id").cloned())
+            .collect();
+
+        let source = self.collection(&query.data_base(), &query.collection());
+        let target = self.collection(&query.data_base(), &String::from(target_collection));
+
+        for chunk in ids.chunks(ID_BATCH_SIZE) {
+            let existing = target.count_documents_with_session(doc! {"_id": {"$in": chunk.to_vec()}}, None, session).await;
+            if let Err(error) = existing {
+                return Err(ConnectException::from(error));
+            }
+            if existing.unwrap() > 0 {
+                let message = format!("Target collection '{}' already contains documents with the same _id, nothing was moved.", target_collection);
+                return Err(ConnectException::from_kind(EConnectErrorKind::CONFLICT, message));
+            }
+        }
+
+        if let Err(error) = target.insert_many_with_session(documents.clone(), None, session).await {
+            return Err(ConnectException::from(error));
+        }
+
+        for chunk in ids.chunks(ID_BATCH_SIZE) {
+            self.delete_document(&source, chunk.to_vec(), Some(session)).await?;
+        }
+
+        documents.iter()
+            .map(|d| self.make_document_data(query.data_base(), String::from(target_collection), d))
+            .collect()
+    }
+
+    fn document_warning(document: &Document, position: usize, error: &ConnectException) -> String {
+        match document.get("_id") {
+            Some(id) => format!("Document {} skipped: {}", id, error.message()),
+            None => format!("Document at position {} skipped: {}", position, error.message()),
+        }
+    }
+
     fn make_document_data(&self, data_base: String, collection: String, document: &Document) -> Result<DocumentData, ConnectException> {
         let json = serde_json::to_string(&document);
         if let Err(error) = json {
-            let exception = ConnectException::new(error.to_string());
+            let exception = ConnectException::from(error);
             return Err(exception);
         }
 
@@ -227,19 +665,60 @@ impl MongoDbRepository {
         ))
     }
 
-    async fn delete_document(&self, collection: &Collection<Document>, id_documents: Vec<Bson>) -> Result<(), ConnectException> {
+    fn make_result_data(&self, data_base: String, collection: String, document: &Document) -> Result<DocumentData, ConnectException> {
+        let json = serde_json::to_string(document);
+        if let Err(error) = json {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let keys = self.document_keys(document)?;
+        let base_key = keys.iter().find(|k| k.name() == "_id").cloned();
+
+        Ok(DocumentData::new(data_base, collection, base_key, keys, json.unwrap()))
+    }
+
+    fn pipeline_from_string(&self, value: &str) -> Result<Vec<Document>, ConnectException> {
+        let json: Result<Value, _> = from_str(value);
+        if let Err(error) = json {
+            let exception = ConnectException::new(format!("Invalid JSON format: {}", error));
+            return Err(exception);
+        }
+
+        let stages = match json.unwrap() {
+            Value::Array(stages) => stages,
+            Value::Object(stage) => vec![Value::Object(stage)],
+            _ => return Err(ConnectException::new(String::from("Pipeline must be a JSON array of stages."))),
+        };
+
+        let mut pipeline = Vec::new();
+        for (index, stage) in stages.into_iter().enumerate() {
+            match Bson::try_from(stage) {
+                Ok(Bson::Document(stage)) => pipeline.push(stage),
+                Ok(_) => return Err(ConnectException::new(format!("Pipeline stage {} must be a JSON object.", index))),
+                Err(error) => return Err(ConnectException::new(format!("Failed to convert pipeline stage {} to BSON: {}", index, error))),
+            }
+        }
+
+        Ok(pipeline)
+    }
+
+    async fn delete_document(&self, collection: &Collection<Document>, id_documents: Vec<Bson>, session: Option<&mut ClientSession>) -> Result<(), ConnectException> {
         let delete_filter = doc! { "_id": { "$in": id_documents } };
         
-        let result = collection.delete_many(delete_filter, None).await;
-        if result.is_err() {
-            let exception = ConnectException::new(result.unwrap_err().to_string());
+        let result = match session {
+            Some(session) => collection.delete_many_with_session(delete_filter, None, session).await,
+            None => collection.delete_many(delete_filter, None).await,
+        };
+        if let Err(error) = &result {
+            let exception = ConnectException::from(error.clone());
             return Err(exception);
         }
         
         Ok(())
     }
 
-    async fn update_document(&self, collection: &Collection<Document>, document: &Document, value: Option<&str>) -> Result<(), ConnectException> {
+    async fn update_document(&self, collection: &Collection<Document>, document: &Document, value: Option<&str>, session: Option<&mut ClientSession>) -> Result<(), ConnectException> {
         if let None = value {
             let exception = ConnectException::new(String::from("Cannot update None document."));
             return Err(exception);
@@ -247,106 +726,479 @@ impl MongoDbRepository {
 
         let new_document = self.document_from_string(&value.unwrap())?;
 
-        let result = collection.replace_one(document.clone(), new_document, None).await;
-        if result.is_err() {
-            let exception = ConnectException::new(result.unwrap_err().to_string());
+        let result = match session {
+            Some(session) => collection.replace_one_with_session(document.clone(), new_document, None, session).await,
+            None => collection.replace_one(document.clone(), new_document, None).await,
+        };
+        if let Err(error) = &result {
+            let exception = ConnectException::from(error.clone());
             return Err(exception);
         }
         Ok(())
     }
 
-    fn document_from_string(&self, value: &str) -> Result<Document, ConnectException> {
-        let json: Result<Value, _> = from_str(value);
-        if json.is_err() {
-            let error_message = format!("Invalid JSON format: {}", json.err().unwrap());
-            let exception = ConnectException::new(error_message);
-            return Err(exception);
-        }
-
-        let document = to_document(&json.unwrap());
-        if document.is_err() {
-            let err = format!("Failed to convert JSON to BSON: {}", document.unwrap_err());
-            return Err(ConnectException::new(err));
+    fn element_size(key: &str, value: &Bson) -> Result<usize, ConnectException> {
+        let mut buffer = Vec::new();
+        if let Err(error) = doc! {key: value.clone()}.to_writer(&mut buffer) {
+            return Err(ConnectException::from(error));
         }
-
-        Ok(document.unwrap())
+        Ok(buffer.len() - 5)
     }
 
-}
-
-#[async_trait]
-impl IDBRepository for MongoDbRepository {
-
-    async fn status(&self) -> Result<(), ConnectException> {
-        let _ = self.data_base_find_all().await?;
-        return Ok(());
+    fn field_count(value: &Bson) -> usize {
+        1 + match value {
+            Bson::Document(document) => document.values().map(MongoDbRepository::field_count).sum(),
+            Bson::Array(values) => values.iter().map(MongoDbRepository::field_count).sum(),
+            _ => 0,
+        }
     }
 
-    async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException> {
-        let server_info = &self.client.database("admin")
-            .run_command(doc! {"serverStatus": 1}, None).await.unwrap();
+    fn collect_hot_spots(path: &str, value: &Bson, total: usize, hot_spots: &mut Vec<DocumentFieldSize>) -> Result<(), ConnectException> {
+        let children: Vec<(String, &Bson)> = match value {
+            Bson::Document(document) => document.iter().map(|(k, v)| (k.clone(), v)).collect(),
+            Bson::Array(values) => values.iter().enumerate().map(|(i, v)| (i.to_string(), v)).collect(),
+            _ => return Ok(()),
+        };
 
-        ExtractorMetadataMongoDb::from_db(server_info)
-    }
+        for (key, child) in children {
+            let bytes = MongoDbRepository::element_size(&key, child)?;
+            let share = bytes as f64 / total as f64;
+            if share < HOT_SPOT_SHARE {
+                continue;
+            }
 
-    async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
-        let result = self.client.list_database_names(None, None).await;
-        if result.is_err() {
-            let exception = ConnectException::new(result.err().unwrap().to_string());
-            return Err(exception);
+            let child_path = format!("{}.{}", path, key);
+            hot_spots.push(DocumentFieldSize::new(child_path.clone(), bytes, MongoDbRepository::field_count(child), share));
+            MongoDbRepository::collect_hot_spots(&child_path, child, total, hot_spots)?;
         }
-        
-        Ok(result.ok().unwrap())
-    }
-
-    async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException> {
-        let databases = self.data_base_find_all().await?;
 
-        Ok(databases.iter().any(|name| name == &query.data_base()))
+        Ok(())
     }
 
-    async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
-        let data_base = query.data_base();
-        let temp_col = format!("TEMP_{}", Uuid::new_v4().to_string());
-        let fix = CollectionQuery::from(data_base.clone(), temp_col.clone());
-        if self.collection_exists(&fix).await? {
-            return self.data_base_create(query).await;
-        }
+    async fn collection_options(&self, query: &CollectionQuery) -> Result<Document, ConnectException> {
+        let specifications = self.first_batch(&query.data_base(), doc! {
+            "listCollections": 1,
+            "filter": { "name": query.collection() }
+        }).await?;
 
-        let query = GenerateCollectionQuery::from_collection(data_base.clone(), temp_col);
-        let _ = self.collection_create(&query).await?;
+        let Some(specification) = specifications.first() else {
+            let message = format!("Collection '{}.{}' does not exist.", query.data_base(), query.collection());
+            return Err(ConnectException::new(message));
+        };
 
-        Ok(data_base)
+        Ok(specification.get_document("options").cloned().unwrap_or_default())
     }
 
-    async fn data_base_drop(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
-        let data_base = query.data_base();
-        let database = self.data_base(&data_base);
-        let result = database.drop(None).await;
-        if result.is_err() {
-            let exception = ConnectException::new(result.err().unwrap().to_string());
-            return Err(exception);
+    fn infer_fields(documents: &[Document]) -> Vec<SchemaField> {
+        let mut paths: Vec<String> = Vec::new();
+        let mut samples: HashMap<String, (Vec<String>, usize, Vec<String>)> = HashMap::new();
+
+        for document in documents {
+            let mut seen = Vec::new();
+            MongoDbRepository::collect_schema_fields("", document, &mut seen);
+
+            let mut counted: Vec<String> = Vec::new();
+            for (path, type_name, example) in seen {
+                let entry = samples.entry(path.clone()).or_insert_with(|| {
+                    paths.push(path.clone());
+                    (Vec::new(), 0, Vec::new())
+                });
+                if !entry.0.contains(&type_name) {
+                    entry.0.push(type_name);
+                }
+                if !counted.contains(&path) {
+                    entry.1 += 1;
+                    counted.push(path);
+                }
+                if let Some(example) = example {
+                    if entry.2.len() < SCHEMA_EXAMPLES && !entry.2.contains(&example) {
+                        entry.2.push(example);
+                    }
+                }
+            }
         }
 
-        Ok(data_base)
+        paths.into_iter()
+            .filter_map(|path| samples.remove(&path).map(|(types, occurrences, examples)| {
+                SchemaField::new(path, types, occurrences, occurrences < documents.len(), examples)
+            }))
+            .collect()
     }
 
-    async fn data_base_metadata(&self, query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
-        let mut documents = Vec::new();
-        
-        let collections = self.collection_find_all(query).await?;
-        for collection in collections {
-            let document = self.collections_metadata_document(query.data_base(), collection).await?;
-            documents.push(document);
+    fn collect_schema_fields(path: &str, document: &Document, seen: &mut Vec<(String, String, Option<String>)>) {
+        for (key, value) in document {
+            let child = if path.is_empty() { key.clone() } else { format!("{}.{}", path, key) };
+
+            let example = match value {
+                Bson::Document(_) | Bson::Array(_) | Bson::Null => None,
+                value => {
+                    let example = value.clone().into_relaxed_extjson().to_string();
+                    Some(example.chars().take(SCHEMA_EXAMPLE_LENGTH).collect())
+                },
+            };
+            seen.push((child.clone(), MongoDbRepository::bson_type_name(value), example));
+
+            match value {
+                Bson::Document(nested) => MongoDbRepository::collect_schema_fields(&child, nested, seen),
+                Bson::Array(items) => {
+                    for item in items.iter().filter_map(|i| i.as_document()) {
+                        MongoDbRepository::collect_schema_fields(&child, item, seen);
+                    }
+                },
+                _ => {},
+            }
         }
+    }
 
-        ExtractorMetadataMongoDb::from_collections(documents)
+    fn bson_type_name(value: &Bson) -> String {
+        let name = match value {
+            Bson::Double(_) => "double",
+            Bson::String(_) => "string",
+            Bson::Array(_) => "array",
+            Bson::Document(_) => "object",
+            Bson::Boolean(_) => "bool",
+            Bson::Null => "null",
+            Bson::RegularExpression(_) => "regex",
+            Bson::JavaScriptCode(_) | Bson::JavaScriptCodeWithScope(_) => "javascript",
+            Bson::Int32(_) => "int",
+            Bson::Int64(_) => "long",
+            Bson::Timestamp(_) => "timestamp",
+            Bson::Binary(_) => "binData",
+            Bson::ObjectId(_) => "objectId",
+            Bson::DateTime(_) => "date",
+            Bson::Symbol(_) => "symbol",
+            Bson::Decimal128(_) => "decimal",
+            Bson::Undefined => "undefined",
+            Bson::MaxKey => "maxKey",
+            Bson::MinKey => "minKey",
+            Bson::DbPointer(_) => "dbPointer",
+        };
+        String::from(name)
     }
 
-    async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {        
-        let json = mongo_db();
+    async fn import_validation(&self, query: &CollectionQuery, documents: &[(usize, Document)]) -> Result<(bool, Vec<usize>, Vec<String>), ConnectException> {
+        let specifications = self.first_batch(&query.data_base(), doc! {
+            "listCollections": 1,
+            "filter": { "name": query.collection() }
+        }).await?;
 
-        let definition: CollectionDefinition = serde_json::from_str(&json).expect("Failed to parse JSON");
+        let options = specifications.first()
+            .and_then(|s| s.get_document("options").ok().cloned())
+            .unwrap_or_default();
+
+        let Ok(validator) = options.get_document("validator") else {
+            return Ok((true, Vec::new(), Vec::new()));
+        };
+
+        let data_base = self.data_base(&query.data_base());
+        let mut invalid = Vec::new();
+        for chunk in documents.chunks(ID_BATCH_SIZE) {
+            let values: Vec<Document> = chunk.iter().map(|(_, d)| d.clone()).collect();
+            let pipeline = vec![
+                doc! { "$documents": values },
+                doc! { "$match": { "$nor": [validator.clone()] } }
+            ];
+
+            let rejected: Result<Vec<Document>, mongodb::error::Error> = match data_base.aggregate(pipeline, None).await {
+                Ok(cursor) => cursor.try_collect().await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = rejected {
+                return Ok((false, Vec::new(), vec![format!("Schema validation skipped: {}", error)]));
+            }
+
+            let rejected = rejected.unwrap();
+            for (index, document) in chunk {
+                if rejected.contains(document) {
+                    invalid.push(*index);
+                }
+            }
+        }
+
+        if options.get_str("validationAction") == Ok("warn") {
+            let warnings = invalid.iter()
+                .map(|i| format!("Document {} does not match the collection validator (validation action is warn).", i))
+                .collect();
+            return Ok((true, Vec::new(), warnings));
+        }
+
+        Ok((true, invalid, Vec::new()))
+    }
+
+    fn dump_paths(query: &CollectionQuery, path: &Path) -> (PathBuf, PathBuf) {
+        let directory = path.join(query.data_base());
+        let bson = directory.join(format!("{}.bson", query.collection()));
+        let metadata = directory.join(format!("{}.metadata.json", query.collection()));
+        (bson, metadata)
+    }
+
+    async fn first_batch(&self, data_base: &String, command: Document) -> Result<Vec<Document>, ConnectException> {
+        let result = self.data_base(data_base).run_command(command, None).await;
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
+        }
+
+        let result = result.unwrap();
+        let batch = result.get_document("cursor")
+            .and_then(|c| c.get_array("firstBatch"));
+        if let Err(error) = batch {
+            return Err(ConnectException::from(error));
+        }
+
+        Ok(batch.unwrap().iter()
+            .filter_map(|d| d.as_document().cloned())
+            .collect())
+    }
+
+    async fn dump_metadata(&self, query: &CollectionQuery) -> Result<Document, ConnectException> {
+        let indexes = self.first_batch(&query.data_base(), doc! {"listIndexes": query.collection()}).await?;
+
+        let specifications = self.first_batch(&query.data_base(), doc! {
+            "listCollections": 1,
+            "filter": { "name": query.collection() }
+        }).await?;
+
+        let specification = specifications.first();
+        let options = specification
+            .and_then(|s| s.get_document("options").ok().cloned())
+            .unwrap_or_default();
+        let uuid = specification
+            .and_then(|s| s.get_document("info").ok())
+            .and_then(|i| i.get("uuid"))
+            .and_then(|u| match u {
+                Bson::Binary(binary) => Some(binary.bytes.iter().map(|b| format!("{:02x}", b)).collect::<String>()),
+                _ => None,
+            });
+
+        let mut metadata = doc! {
+            "indexes": indexes,
+            "collectionName": query.collection(),
+            "type": "collection",
+            "options": options
+        };
+        if let Some(uuid) = uuid {
+            metadata.insert("uuid", uuid);
+        }
+
+        Ok(metadata)
+    }
+
+    async fn restore_metadata(&self, query: &CollectionQuery, path: &Path) -> Result<usize, ConnectException> {
+        let content = fs::read_to_string(path).await;
+        if let Err(error) = content {
+            if error.kind() == IoErrorKind::NotFound {
+                return Ok(0);
+            }
+            return Err(ConnectException::from(error));
+        }
+
+        let metadata = self.document_from_string(&content.unwrap())?;
+
+        if let Ok(options) = metadata.get_document("options") {
+            if !options.is_empty() {
+                let mut command = doc! {"create": query.collection()};
+                command.extend(options.clone());
+                let result = self.data_base(&query.data_base()).run_command(command, None).await;
+                if let Err(error) = result {
+                    let exists = matches!(*error.kind, ErrorKind::Command(ref command) if command.code == NAMESPACE_EXISTS);
+                    if !exists {
+                        return Err(ConnectException::from(error));
+                    }
+                }
+            }
+        }
+
+        let indexes: Vec<Document> = metadata.get_array("indexes")
+            .map(|i| i.iter().filter_map(|d| d.as_document().cloned()).collect::<Vec<Document>>())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|i| i.get_str("name").map(|n| n != "_id_").unwrap_or(true))
+            .map(|mut i| {
+                i.remove("ns");
+                i
+            })
+            .collect();
+
+        if indexes.is_empty() {
+            return Ok(0);
+        }
+
+        let size = indexes.len();
+        let command = doc! {
+            "createIndexes": query.collection(),
+            "indexes": indexes
+        };
+
+        let result = self.data_base(&query.data_base()).run_command(command, None).await;
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
+        }
+
+        Ok(size)
+    }
+
+    fn document_from_string(&self, value: &str) -> Result<Document, ConnectException> {
+        let json: Result<Value, _> = from_str(value);
+        if json.is_err() {
+            let error_message = format!("Invalid JSON format: {}", json.err().unwrap());
+            let exception = ConnectException::new(error_message);
+            return Err(exception);
+        }
+
+        match Bson::try_from(json.unwrap()) {
+            Ok(Bson::Document(document)) => Ok(document),
+            Ok(_) => Err(ConnectException::new(String::from("Document must be a JSON object."))),
+            Err(error) => {
+                let err = format!("Failed to convert JSON to BSON: {}", error);
+                Err(ConnectException::new(err))
+            },
+        }
+    }
+
+}
+
+#[async_trait]
+impl IDBRepository for MongoDbRepository {
+
+    fn fingerprint(&self) -> String {
+        return self.connection.fingerprint();
+    }
+
+    fn soft_delete(&self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy> {
+        return self.connection.soft_delete(data_base, collection);
+    }
+
+    fn lineage(&self) -> Option<LineagePolicy> {
+        return self.connection.lineage();
+    }
+
+    fn routing_events(&self) -> Vec<RoutingEvent> {
+        return self.router.as_ref()
+            .map(|r| r.monitor().events())
+            .unwrap_or_default();
+    }
+
+    async fn status(&self) -> Result<(), ConnectException> {
+        let _ = self.data_base_find_all().await?;
+        return Ok(());
+    }
+
+    async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException> {
+        let server_info = self.server_status_document().await?;
+        ExtractorMetadataMongoDb::server_groups(&server_info)
+    }
+
+    async fn server_stats(&self) -> Result<ServerMetadata, ConnectException> {
+        let server_info = self.server_status_document().await?;
+        Ok(ExtractorMetadataMongoDb::server_metadata(&server_info))
+    }
+
+    async fn latency_probe(&self) -> Result<Vec<HostLatency>, ConnectException> {
+        let options = MongoDbRepository::client_options(&self.connection).await?;
+
+        let mut latencies = Vec::new();
+        for host in &options.hosts {
+            latencies.push(self.probe_host(&options, host).await);
+        }
+
+        Ok(latencies)
+    }
+
+    async fn server_time(&self) -> Result<ServerTime, ConnectException> {
+        let start = Local::now().timestamp_millis();
+
+        let result = self.client.database("admin")
+            .run_command(doc! {"hello": 1}, None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let end = Local::now().timestamp_millis();
+
+        let hello = result.unwrap();
+        let local_time = hello.get_datetime("localTime");
+        if let Err(error) = local_time {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let server = local_time.unwrap().timestamp_millis();
+        let latency = end - start;
+        let client = start + latency / 2;
+
+        Ok(ServerTime::new(server, client, latency))
+    }
+
+    async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
+        let result = self.client.list_database_names(None, None).await;
+        if let Err(error) = &result {
+            let exception = ConnectException::from(error.clone());
+            return Err(exception);
+        }
+        
+        Ok(result.ok().unwrap())
+    }
+
+    async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException> {
+        let databases = self.data_base_find_all().await?;
+
+        Ok(databases.iter().any(|name| name == &query.data_base()))
+    }
+
+    async fn data_base_create(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        let data_base = query.data_base();
+        let temp_col = format!("TEMP_{}", Uuid::new_v4().to_string());
+        let fix = CollectionQuery::from(data_base.clone(), temp_col.clone());
+        if self.collection_exists(&fix).await? {
+            return self.data_base_create(query).await;
+        }
+
+        let query = GenerateCollectionQuery::from_collection(data_base.clone(), temp_col);
+        let _ = self.collection_create(&query).await?;
+
+        Ok(data_base)
+    }
+
+    async fn data_base_drop(&self, query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        let data_base = query.data_base();
+        let database = self.data_base(&data_base);
+        let result = database.drop(None).await;
+        if let Err(error) = &result {
+            let exception = ConnectException::from(error.clone());
+            return Err(exception);
+        }
+
+        Ok(data_base)
+    }
+
+    async fn data_base_metadata(&self, query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
+        let mut documents = Vec::new();
+        
+        let collections = self.collection_find_all(query).await?;
+        for collection in collections {
+            let document = self.collections_metadata_document(query.data_base(), collection).await?;
+            documents.push(document);
+        }
+
+        ExtractorMetadataMongoDb::collections_groups(documents)
+    }
+
+    async fn data_base_stats(&self, query: &DataBaseQuery) -> Result<DataBaseMetadata, ConnectException> {
+        let mut collections = Vec::new();
+
+        for collection in self.collection_find_all(query).await? {
+            let fix = CollectionQuery::from(query.data_base(), collection);
+            collections.push(self.collection_stats(&fix).await?);
+        }
+
+        Ok(DataBaseMetadata::new(query.data_base(), collections))
+    }
+
+    async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {        
+        let json = mongo_db();
+
+        let definition: CollectionDefinition = serde_json::from_str(&json).expect("Failed to parse JSON");
 
         Ok(definition)
     }
@@ -354,19 +1206,53 @@ impl IDBRepository for MongoDbRepository {
     async fn collection_metadata(&self, query: &CollectionQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
         let document = self.collections_metadata_document(query.data_base(), query.collection()).await?;
 
-        ExtractorMetadataMongoDb::from_collection(document)
+        ExtractorMetadataMongoDb::collection_groups(document)
+    }
+
+    async fn collection_stats(&self, query: &CollectionQuery) -> Result<CollectionMetadata, ConnectException> {
+        let document = self.collections_metadata_document(query.data_base(), query.collection()).await?;
+        Ok(ExtractorMetadataMongoDb::collection_metadata(query.collection(), &document))
     }
 
     async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException> {
         let result = self.data_base(&query.data_base()).list_collection_names(None).await;
-        if result.is_err() {
-            let exception = ConnectException::new(result.unwrap_err().to_string());
+        if let Err(error) = &result {
+            let exception = ConnectException::from(error.clone());
             return Err(exception);
         }
 
         Ok(result.ok().unwrap())
     }
 
+    async fn collection_indexes(&self, query: &CollectionQuery) -> Result<Vec<CollectionIndex>, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let r_cursor = collection.list_indexes(None).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let mut cursor = r_cursor.unwrap();
+
+        let mut indexes = Vec::new();
+        while let Some(r_index) = cursor.next().await {
+            let index = match r_index {
+                Ok(index) => index,
+                Err(error) => return Err(ConnectException::from(error)),
+            };
+
+            let options = index.options.unwrap_or_default();
+            let name = options.name.unwrap_or_default();
+            let unique = options.unique.unwrap_or_default();
+            let fields = index.keys.keys().cloned().collect();
+
+            indexes.push(CollectionIndex::new(name, fields, unique));
+        }
+
+        Ok(indexes)
+    }
+
     async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException> {
         let fix = DocumentQuery::from(query.data_base(), query.collection(), Some(0), Some(1), None);
         let collections = self.find(&fix).await?;
@@ -377,9 +1263,10 @@ impl IDBRepository for MongoDbRepository {
     async fn collection_create(&self, query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
         let name = query.collection();
         let db = self.data_base(&query.data_base());
-        let result = db.create_collection(&name, None).await;
+        let options = query.as_mongo_create()?;
+        let result = db.create_collection(&name, options).await;
         if let Err(result) = result {
-            let exception = ConnectException::new(result.to_string());
+            let exception = ConnectException::from(result);
             return Err(exception);
         }
 
@@ -389,7 +1276,7 @@ impl IDBRepository for MongoDbRepository {
             let indexes = FieldData::collection_as_mongo_create(query.fields())?;
             if let Err(result) = collection.create_indexes(indexes, None).await {
                 let _ = self.collection_drop(query).await?;
-                let exception = ConnectException::new(result.to_string());
+                let exception = ConnectException::from(result);
                 return Err(exception);
             }
         }
@@ -401,7 +1288,31 @@ impl IDBRepository for MongoDbRepository {
         let collection = self.collection_from_resource(&query);
         let result = collection.drop(None).await;
         if let Err(error) = result {
-            let exception = ConnectException::new(error.to_string());
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        Ok(query.collection())
+    }
+
+    async fn collection_validator(&self, query: &CollectionQuery) -> Result<Option<CollectionValidator>, ConnectException> {
+        let options = self.collection_options(query).await?;
+        CollectionValidator::from_mongo_options(&options)
+    }
+
+    async fn collection_set_validator(&self, query: &CollectionQuery, validator: Option<&CollectionValidator>) -> Result<String, ConnectException> {
+        let command = match validator {
+            Some(validator) => validator.as_mongo_modify(&query.collection())?,
+            None => doc! {
+                "collMod": query.collection(),
+                "validator": {},
+                "validationLevel": "off"
+            },
+        };
+
+        let db = self.data_base(&query.data_base());
+        if let Err(error) = db.run_command(command, None).await {
+            let exception = ConnectException::from(error);
             return Err(exception);
         }
 
@@ -416,7 +1327,7 @@ impl IDBRepository for MongoDbRepository {
         };
 
         if let Err(error) = admin_db.run_command(command, None).await {
-            let exception = ConnectException::new(error.to_string());
+            let exception = ConnectException::from(error);
             return Err(exception);
         }
 
@@ -425,29 +1336,314 @@ impl IDBRepository for MongoDbRepository {
 
     async fn collection_export(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException> {
         let fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
-        Ok(self.find_all(&fix).await?.documents())
+        self.find_stream(&fix).await?.try_collect().await
+    }
+
+    async fn collection_export_partitioned(&self, query: &CollectionQuery, partitions: usize) -> Result<Vec<BoxStream<'static, Result<DocumentData, ConnectException>>>, ConnectException> {
+        let fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        let filters = self.partition_filters(&fix, partitions).await?;
+
+        let collection = self.collection(&query.data_base(), &query.collection());
+        let reads = filters.into_iter()
+            .map(|filter| collection.find(filter, None));
+
+        let r_cursors = try_join_all(reads).await;
+        if let Err(error) = r_cursors {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut parts = Vec::new();
+        for cursor in r_cursors.unwrap() {
+            let repository = self.clone();
+            let data_base = query.data_base();
+            let collection = query.collection();
+
+            let stream = cursor.map(move |r_document| match r_document {
+                Ok(document) => repository.make_document_data(data_base.clone(), collection.clone(), &document),
+                Err(error) => Err(ConnectException::from(error)),
+            });
+            parts.push(stream.boxed());
+        }
+
+        Ok(parts)
     }
 
-    async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException> {
+    async fn collection_import_preview(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportPreview, ConnectException> {
         let collection = self.collection(&query.data_base(), &query.collection());
 
+        let mut entries = Vec::new();
         let mut parsed = Vec::new();
-        for document in documents {
-            parsed.push(self.document_from_string(&document)?);
+        for (index, document) in documents.iter().enumerate() {
+            match self.document_from_string(document) {
+                Ok(document) => parsed.push((index, document)),
+                Err(error) => entries.push(ImportPreviewEntry::failure(index, None, error.message())),
+            }
         }
 
-        if let Err(error) = collection.insert_many(parsed, None).await {
-            let exception = ConnectException::new(error.to_string());
-            return Err(exception);
+        let ids: Vec<Bson> = parsed.iter()
+            .filter_map(|(_, d)| d.get("_id").cloned())
+            .collect();
+
+        let mut existing = Vec::new();
+        for chunk in ids.chunks(ID_BATCH_SIZE) {
+            let options = FindOptions::builder().projection(doc! {"_id": 1}).build();
+            let found: Result<Vec<Document>, mongodb::error::Error> = match collection.find(doc! {"_id": {"$in": chunk.to_vec()}}, options).await {
+                Ok(cursor) => cursor.try_collect().await,
+                Err(error) => Err(error),
+            };
+            if let Err(error) = found {
+                return Err(ConnectException::from(error));
+            }
+            existing.extend(found.unwrap().into_iter().filter_map(|d| d.get("_id").cloned()));
+        }
+
+        let (validated, invalid, warnings) = self.import_validation(query, &parsed).await?;
+
+        for (index, document) in &parsed {
+            let id = document.get("_id");
+            let key = id.and_then(|i| serde_json::to_string(i).ok());
+
+            if invalid.contains(index) {
+                entries.push(ImportPreviewEntry::failure(*index, key, String::from("Document does not match the collection validator.")));
+                continue;
+            }
+
+            match (id, key) {
+                (Some(id), Some(key)) if existing.contains(id) => entries.push(ImportPreviewEntry::replace(*index, key)),
+                (Some(id), Some(key)) => {
+                    existing.push(id.clone());
+                    entries.push(ImportPreviewEntry::insert(*index, Some(key)));
+                },
+                (_, key) => entries.push(ImportPreviewEntry::insert(*index, key)),
+            }
         }
 
-        Ok(String::new())
+        entries.sort_by_key(|e| e.index());
+
+        Ok(ImportPreview::new(entries, validated, warnings))
+    }
+
+    async fn collection_dump(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        let (bson_path, metadata_path) = MongoDbRepository::dump_paths(query, path);
+        if let Some(directory) = bson_path.parent() {
+            if let Err(error) = fs::create_dir_all(directory).await {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let collection = self.collection(&query.data_base(), &query.collection());
+        let cursor = collection.find(None, None).await;
+        if let Err(error) = cursor {
+            return Err(ConnectException::from(error));
+        }
+
+        let file = File::create(&bson_path).await;
+        if let Err(error) = file {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut cursor = cursor.unwrap();
+        let mut writer = BufWriter::new(file.unwrap());
+        let mut documents = 0;
+
+        while let Some(r_document) = cursor.next().await {
+            if let Err(error) = r_document {
+                return Err(ConnectException::from(error));
+            }
+
+            let mut buffer = Vec::new();
+            if let Err(error) = r_document.unwrap().to_writer(&mut buffer) {
+                return Err(ConnectException::from(error));
+            }
+
+            if let Err(error) = writer.write_all(&buffer).await {
+                return Err(ConnectException::from(error));
+            }
+
+            documents += 1;
+        }
+
+        if let Err(error) = writer.flush().await {
+            return Err(ConnectException::from(error));
+        }
+
+        let metadata = self.dump_metadata(query).await?;
+        let indexes = metadata.get_array("indexes").map(|i| i.len()).unwrap_or_default();
+
+        let json = serde_json::to_string(&Bson::Document(metadata).into_canonical_extjson());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::write(&metadata_path, json.unwrap()).await {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut report = DumpReport::new(query.data_base(), query.collection(), path.display().to_string(), documents, indexes);
+        report.set_files(vec![bson_path.display().to_string(), metadata_path.display().to_string()]);
+
+        Ok(report)
+    }
+
+    async fn collection_restore(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        let (bson_path, metadata_path) = MongoDbRepository::dump_paths(query, path);
+
+        let file = File::open(&bson_path).await;
+        if let Err(error) = file {
+            return Err(ConnectException::from(error));
+        }
+
+        let collection = self.collection(&query.data_base(), &query.collection());
+        let indexes = self.restore_metadata(query, &metadata_path).await?;
+
+        let mut reader = BufReader::new(file.unwrap());
+        let mut batch = Vec::new();
+        let mut documents = 0;
+
+        loop {
+            let size = match reader.read_i32_le().await {
+                Ok(size) => size,
+                Err(error) if error.kind() == IoErrorKind::UnexpectedEof => break,
+                Err(error) => return Err(ConnectException::from(error)),
+            };
+
+            if size < 5 {
+                let message = format!("Corrupted dump, invalid document size {} after {} documents.", size, documents);
+                return Err(ConnectException::new(message));
+            }
+
+            let mut buffer = vec![0u8; size as usize];
+            buffer[..4].copy_from_slice(&size.to_le_bytes());
+            if let Err(error) = reader.read_exact(&mut buffer[4..]).await {
+                return Err(ConnectException::from(error));
+            }
+
+            let document = Document::from_reader(buffer.as_slice());
+            if let Err(error) = document {
+                return Err(ConnectException::from(error));
+            }
+
+            batch.push(document.unwrap());
+            documents += 1;
+
+            if batch.len() >= DUMP_BATCH_SIZE {
+                if let Err(error) = collection.insert_many(std::mem::take(&mut batch), None).await {
+                    return Err(ConnectException::from(error));
+                }
+            }
+        }
+
+        if !batch.is_empty() {
+            if let Err(error) = collection.insert_many(batch, None).await {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        Ok(DumpReport::new(query.data_base(), query.collection(), path.display().to_string(), documents, indexes))
+    }
+
+    async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportIntegrity, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let mut failures = Vec::new();
+        let mut parsed = Vec::new();
+        for (index, document) in documents.iter().enumerate() {
+            match self.document_from_string(document) {
+                Ok(document) => parsed.push((index, document)),
+                Err(error) => failures.push(ImportFailure::new(index, None, error.message())),
+            }
+        }
+
+        let before = collection.count_documents(None, None).await;
+        if let Err(error) = before {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut rejected = 0;
+        if !parsed.is_empty() {
+            let batch: Vec<Document> = parsed.iter().map(|(_, d)| d.clone()).collect();
+            let options = InsertManyOptions::builder().ordered(false).build();
+            if let Err(error) = collection.insert_many(batch, options).await {
+                let ErrorKind::BulkWrite(BulkWriteFailure { write_errors: Some(errors), .. }) = error.kind.as_ref() else {
+                    return Err(ConnectException::from(error));
+                };
+                for write_error in errors {
+                    let Some((index, document)) = parsed.get(write_error.index) else {
+                        continue;
+                    };
+                    let key = document.get("_id").map(|id| id.clone().into_relaxed_extjson().to_string());
+                    failures.push(ImportFailure::new(*index, key, write_error.message.clone()));
+                    rejected += 1;
+                }
+            }
+        }
+
+        let after = collection.count_documents(None, None).await;
+        if let Err(error) = after {
+            return Err(ConnectException::from(error));
+        }
+
+        failures.sort_by_key(|f| f.index());
+
+        let inserted = parsed.len() - rejected;
+        let mut integrity = ImportIntegrity::new(query.data_base(), query.collection(), documents.len(), parsed.len(), inserted, failures);
+        integrity.set_counts(before.unwrap(), after.unwrap());
+
+        Ok(integrity)
     }
 
     async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
         Ok(self.query_action(query, EAction::FIND, None).await?)
     }
 
+    async fn find_page(&self, query: &DocumentQuery, offset: u64, limit: u64) -> Result<CollectionData, ConnectException> {
+        let mut fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, query.filter());
+        fix.set_sort(query.sort());
+        fix.set_projection(query.projection());
+
+        let mut pipeline = self.find_pipeline(&fix)?;
+        pipeline.push(doc! {
+            "$facet": {
+                "total": [{ "$count": "total" }],
+                "documents": [
+                    { "$skip": Bson::Int64(offset as i64) },
+                    { "$limit": Bson::Int64(limit as i64) }
+                ]
+            }
+        });
+
+        let mut cursor = self.aggregate_cursor(&fix, pipeline).await?;
+
+        let facet = match cursor.next().await {
+            Some(Ok(facet)) => facet,
+            Some(Err(error)) => return Err(ConnectException::from(error)),
+            None => doc! {},
+        };
+
+        let total = MongoDbRepository::facet_total(&facet);
+
+        let mut documents = Vec::new();
+        let mut warnings = Vec::new();
+        if let Ok(page) = facet.get_array("documents") {
+            for (position, document) in page.iter().filter_map(|d| d.as_document()).enumerate() {
+                match self.make_document_data(query.data_base(), query.collection(), document) {
+                    Ok(data) => documents.push(data),
+                    Err(error) => warnings.push(MongoDbRepository::document_warning(document, offset as usize + position + 1, &error)),
+                }
+            }
+        }
+
+        let mut data = CollectionData::new(
+            total,
+            Some(limit as usize),
+            Some(offset as usize),
+            documents
+        );
+        data.set_warnings(warnings);
+
+        Ok(data)
+    }
+
     async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
         let fix = DocumentQuery::from(query.data_base(), query.collection(), query.skip(), query.limit(), None);
         return self.find_query(&fix).await;
@@ -459,23 +1655,122 @@ impl IDBRepository for MongoDbRepository {
         Ok(documents.first().cloned())
     }
 
+    async fn count(&self, query: &DocumentQuery) -> Result<u64, ConnectException> {
+        let mut pipeline = self.find_pipeline(query)?;
+        pipeline.push(doc! { "$count": "total" });
+
+        let mut cursor = self.aggregate_cursor(query, pipeline).await?;
+        let total = match cursor.next().await {
+            Some(Ok(document)) => document.get("total")
+                .and_then(|t| t.as_i64().or(t.as_i32().map(i64::from)))
+                .unwrap_or_default(),
+            Some(Err(error)) => return Err(ConnectException::from(error)),
+            None => 0,
+        };
+
+        Ok(total as u64)
+    }
+
+    async fn document_exists(&self, query: &DocumentQuery) -> Result<bool, ConnectException> {
+        let mut pipeline = self.find_pipeline(query)?;
+        pipeline.push(doc! { "$limit": 1 });
+        pipeline.push(doc! { "$project": { "_id": 1 } });
+
+        let mut cursor = self.aggregate_cursor(query, pipeline).await?;
+        match cursor.next().await {
+            Some(Ok(_)) => Ok(true),
+            Some(Err(error)) => Err(ConnectException::from(error)),
+            None => Ok(false),
+        }
+    }
+
+    async fn document_size(&self, query: &DocumentQuery) -> Result<DocumentSize, ConnectException> {
+        let mut pipeline = self.find_pipeline(query)?;
+        pipeline.push(doc! { "$limit": 1 });
+
+        let mut cursor = self.aggregate_cursor(query, pipeline).await?;
+        let document = match cursor.next().await {
+            Some(Ok(document)) => document,
+            Some(Err(error)) => return Err(ConnectException::from(error)),
+            None => return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, String::from("Document not found."))),
+        };
+
+        let mut buffer = Vec::new();
+        if let Err(error) = document.to_writer(&mut buffer) {
+            return Err(ConnectException::from(error));
+        }
+        let total = buffer.len();
+
+        let mut fields = Vec::new();
+        let mut hot_spots = Vec::new();
+        for (key, value) in document.iter() {
+            let bytes = MongoDbRepository::element_size(key, value)?;
+            let share = bytes as f64 / total as f64;
+            fields.push(DocumentFieldSize::new(key.clone(), bytes, MongoDbRepository::field_count(value), share));
+            if share >= HOT_SPOT_SHARE {
+                MongoDbRepository::collect_hot_spots(key, value, total, &mut hot_spots)?;
+            }
+        }
+
+        fields.sort_by_key(|f| std::cmp::Reverse(f.bytes()));
+        hot_spots.sort_by_key(|f| std::cmp::Reverse(f.bytes()));
+
+        let base_key = self.document_keys(&document)?.into_iter().find(|k| k.name() == "_id");
+
+        Ok(DocumentSize::new(base_key, total, DOCUMENT_SIZE_LIMIT, fields, hot_spots))
+    }
+
+    async fn find_stream(&self, query: &DocumentQuery) -> Result<BoxStream<'static, Result<DocumentData, ConnectException>>, ConnectException> {
+        let cursor = self.find_cursor(query).await?;
+
+        let repository = self.clone();
+        let data_base = query.data_base();
+        let collection = query.collection();
+
+        let stream = cursor.map(move |r_document| match r_document {
+            Ok(document) => repository.make_document_data(data_base.clone(), collection.clone(), &document),
+            Err(error) => Err(ConnectException::from(error)),
+        });
+
+        Ok(stream.boxed())
+    }
+
     async fn schema(&self, query: &CollectionQuery) -> Result<DocumentSchema, ConnectException> {
+        let fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        let pipeline = vec![doc! { "$sample": { "size": SCHEMA_SAMPLES } }];
+
+        let cursor = self.aggregate_cursor(&fix, pipeline).await?;
+        let documents: Result<Vec<Document>, mongodb::error::Error> = cursor.try_collect().await;
+        if let Err(error) = documents {
+            return Err(ConnectException::from(error));
+        }
+
+        let documents = documents.unwrap();
+        let inferred = MongoDbRepository::infer_fields(&documents);
+
         let fields = Vec::new();
         let comments = Vec::from(vec![
-            String::from("If '_id' field is not defined it will be created with an ObjectId default value.")
+            String::from("If '_id' field is not defined it will be created with an ObjectId default value."),
+            format!("Fields inferred from a sample of {} documents.", documents.len())
         ]);
-        Ok(DocumentSchema::new(comments, false, fields))
+
+        let mut schema = DocumentSchema::new(comments, false, fields);
+        schema.set_inferred(inferred, documents.len());
+
+        Ok(schema)
     }
 
     async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException> {
         let collection = self.collection(&query.data_base(), &query.collection());
 
         let mut document = self.document_from_string(&value)?;
+        if !document.contains_key("_id") {
+            document.insert("_id", self.generate_id(query).await?);
+        }
 
         let result = collection.insert_one(document.clone(), None).await;
-        if result.is_err() {
-            let err = format!("Could not insert into database: {}", result.err().unwrap());
-            return Err(ConnectException::new(err));
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
         }
 
         document.insert("_id", result.unwrap().inserted_id);
@@ -483,12 +1778,272 @@ impl IDBRepository for MongoDbRepository {
         Ok(self.make_document_data(query.data_base(), query.collection(), &document)?)
     }
 
+    async fn insert_many(&self, query: &CollectionQuery, values: Vec<String>, batch_size: usize) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let mut results = Vec::new();
+
+        let mut pending = Vec::new();
+        for (index, value) in values.iter().enumerate() {
+            match self.document_from_string(value) {
+                Ok(mut document) => {
+                    if !document.contains_key("_id") {
+                        match self.generate_id(query).await {
+                            Ok(id) => document.insert("_id", id),
+                            Err(error) => {
+                                results.push(DocumentInsertResult::failure(index, error.message()));
+                                continue;
+                            },
+                        };
+                    }
+                    pending.push((index, document));
+                },
+                Err(error) => results.push(DocumentInsertResult::failure(index, error.message())),
+            }
+        }
+
+        for chunk in pending.chunks(batch_size.max(1)) {
+            let documents: Vec<Document> = chunk.iter().map(|(_, d)| d.clone()).collect();
+            let options = InsertManyOptions::builder().ordered(false).build();
+
+            let mut failed = HashMap::new();
+            if let Err(error) = collection.insert_many(documents, options).await {
+                match error.kind.as_ref() {
+                    ErrorKind::BulkWrite(BulkWriteFailure { write_errors: Some(errors), .. }) => {
+                        for write_error in errors {
+                            failed.insert(write_error.index, write_error.message.clone());
+                        }
+                    },
+                    _ => {
+                        for position in 0..chunk.len() {
+                            failed.insert(position, error.to_string());
+                        }
+                    },
+                }
+            }
+
+            for (position, (index, document)) in chunk.iter().enumerate() {
+                match failed.remove(&position) {
+                    Some(error) => results.push(DocumentInsertResult::failure(*index, error)),
+                    None => {
+                        let data = self.make_document_data(query.data_base(), query.collection(), document)?;
+                        results.push(DocumentInsertResult::success(*index, data));
+                    },
+                }
+            }
+        }
+
+        results.sort_by_key(|r| r.index());
+
+        Ok(results)
+    }
+
     async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
         Ok(self.query_action(query, EAction::UPDATE, Some(value)).await?.documents())
     }
 
+    async fn update_transaction(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        Ok(self.query_action_transaction(query, EAction::UPDATE, Some(value)).await?.documents())
+    }
+
+    async fn bulk_set(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdateSummary, ConnectException> {
+        spec.validate()?;
+
+        let collection = self.collection(&query.data_base(), &query.collection());
+        let update = spec.as_mongo_update()?;
+        let filter = self.match_filter(query)?;
+
+        let result = collection.update_many(filter, update, None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let result = result.unwrap();
+        Ok(UpdateSummary::new(result.matched_count as usize, result.modified_count as usize))
+    }
+
+    async fn patch(&self, query: &DocumentQuery, fields: Vec<DocumentUpdateField>) -> Result<Vec<DocumentData>, ConnectException> {
+        let spec = UpdateSpec::from_fields(&fields)?;
+        let update = spec.as_mongo_update()?;
+
+        let collection = self.collection(&query.data_base(), &query.collection());
+        let filter = self.match_filter(query)?;
+
+        let result = collection.update_many(filter.clone(), update, None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let r_cursor = collection.find(filter, None).await;
+        if let Err(error) = r_cursor {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        let mut documents = Vec::new();
+        let mut cursor = r_cursor.unwrap();
+        while let Some(r_document) = cursor.next().await {
+            let document = match r_document {
+                Ok(document) => document,
+                Err(error) => return Err(ConnectException::from(error)),
+            };
+            documents.push(self.make_document_data(query.data_base(), query.collection(), &document)?);
+        }
+
+        Ok(documents)
+    }
+
+    async fn bulk_set_preview(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdatePreview, ConnectException> {
+        spec.validate()?;
+
+        let transform = spec.as_mongo_agregate()?;
+        let (total, samples) = self.preview_documents(query, transform).await?;
+        Ok(UpdatePreview::new(total, samples))
+    }
+
+    async fn upsert(&self, query: &DocumentQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let filter = self.match_filter(query)?;
+        if filter.is_empty() {
+            let exception = ConnectException::new(String::from("Upsert requires a filter."));
+            return Err(exception);
+        }
+
+        let mut new_document = self.document_from_string(value)?;
+
+        let options = FindOneOptions::builder().projection(doc! {"_id": 1}).build();
+        let existing = collection.find_one(filter.clone(), options).await;
+        if let Err(error) = existing {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        if let Some(id) = existing.unwrap().and_then(|d| d.get("_id").cloned()) {
+            new_document.insert("_id", id);
+        }
+
+        let options = ReplaceOptions::builder().upsert(true).build();
+        let result = collection.replace_one(filter, new_document.clone(), options).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        if let Some(id) = result.unwrap().upserted_id {
+            new_document.insert("_id", id);
+        }
+
+        self.make_document_data(query.data_base(), query.collection(), &new_document)
+    }
+
+    async fn update_revision(&self, query: &DocumentQuery, value: &str, revision: i64) -> Result<DocumentData, ConnectException> {
+        let collection = self.collection(&query.data_base(), &query.collection());
+
+        let mut cursor = self.find_cursor(query).await?;
+        let document = match cursor.next().await {
+            Some(Ok(document)) => document,
+            Some(Err(error)) => return Err(ConnectException::from(error)),
+            None => return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, String::from("Document not found."))),
+        };
+
+        let o_id = document.get("_id");
+        if o_id.is_none() {
+            let exception = ConnectException::new(String::from("Identifier not found."));
+            return Err(exception);
+        }
+
+        let id = o_id.unwrap().clone();
+
+        let filter = if revision == 0 {
+            doc! {
+                "_id": id.clone(),
+                "$or": [
+                    { REVISION_FIELD: { "$exists": false } },
+                    { REVISION_FIELD: 0 }
+                ]
+            }
+        } else {
+            doc! { "_id": id.clone(), REVISION_FIELD: revision }
+        };
+
+        let mut new_document = self.document_from_string(value)?;
+        new_document.insert("_id", id);
+        new_document.insert(REVISION_FIELD, Bson::Int64(revision + 1));
+
+        let result = collection.replace_one(filter, new_document.clone(), None).await;
+        if let Err(error) = result {
+            let exception = ConnectException::from(error);
+            return Err(exception);
+        }
+
+        if result.unwrap().matched_count == 0 {
+            let message = format!("Revision conflict: expected revision {} but the document was modified.", revision);
+            return Err(ConnectException::from_kind(EConnectErrorKind::CONFLICT, message));
+        }
+
+        self.make_document_data(query.data_base(), query.collection(), &new_document)
+    }
+
     async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
         Ok(self.query_action(query, EAction::DELETE, None).await?.documents())
     }
+
+    async fn delete_transaction(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        Ok(self.query_action_transaction(query, EAction::DELETE, None).await?.documents())
+    }
+
+    async fn delete_preview(&self, query: &DocumentQuery) -> Result<DocumentDeletePreview, ConnectException> {
+        let (total, samples) = self.preview_documents(query, Vec::new()).await?;
+        Ok(DocumentDeletePreview::new(total, samples))
+    }
+
+    async fn aggregate_raw(&self, query: &CollectionQuery, pipeline_json: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        let pipeline = self.pipeline_from_string(pipeline_json)?;
+
+        let fix = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        let documents: Result<Vec<Document>, mongodb::error::Error> = self.aggregate_cursor(&fix, pipeline).await?.try_collect().await;
+        if let Err(error) = documents {
+            return Err(ConnectException::from(error));
+        }
+
+        documents.unwrap().iter()
+            .map(|d| self.make_result_data(query.data_base(), query.collection(), d))
+            .collect()
+    }
+
+    async fn move_documents(&self, query: &DocumentQuery, target_collection: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        if query.collection() == target_collection {
+            return Err(ConnectException::new(String::from("Source and target collections must be different.")));
+        }
+
+        let r_session = self.client.start_session(None).await;
+        if let Err(error) = r_session {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut session = r_session.unwrap();
+
+        let result = session.start_transaction(None).await;
+        if let Err(error) = result {
+            return Err(ConnectException::from(error));
+        }
+
+        let result = self.move_documents_session(query, target_collection, &mut session).await;
+        if result.is_err() {
+            let _ = session.abort_transaction().await;
+            return result;
+        }
+
+        let commit = session.commit_transaction().await;
+        if let Err(error) = commit {
+            let _ = session.abort_transaction().await;
+            return Err(ConnectException::from(error));
+        }
+
+        result
+    }
     
 }
\ No newline at end of file
@@ -0,0 +1,171 @@
+use std::{collections::HashMap, sync::{Arc, Mutex}};
+
+use mongodb::{event::{command::{CommandEventHandler, CommandStartedEvent}, sdam::{SdamEventHandler, ServerClosedEvent, ServerDescriptionChangedEvent}}, options::{ClientOptions, SelectionCriteria}, ServerInfo, ServerType};
+
+use crate::{domain::{e_read_preference::EReadPreference, routing::{e_routing_event_kind::ERoutingEventKind, routing_event::RoutingEvent, routing_policy::RoutingPolicy}}, infrastructure::routing_monitor::RoutingMonitor};
+
+const READ_COMMANDS: [&str; 4] = ["find", "aggregate", "count", "distinct"];
+
+#[derive(Clone)]
+pub struct MongoDbRouter {
+    read_preference: EReadPreference,
+    policy: RoutingPolicy,
+    servers: Arc<Mutex<HashMap<String, ServerType>>>,
+    pinned: Arc<Mutex<Option<String>>>,
+    monitor: RoutingMonitor
+}
+
+impl MongoDbRouter {
+
+    pub fn new(read_preference: EReadPreference, policy: RoutingPolicy) -> MongoDbRouter {
+        MongoDbRouter {
+            read_preference,
+            policy,
+            servers: Arc::new(Mutex::new(HashMap::new())),
+            pinned: Arc::new(Mutex::new(None)),
+            monitor: RoutingMonitor::default()
+        }
+    }
+
+    pub fn monitor(&self) -> RoutingMonitor {
+        return self.monitor.clone();
+    }
+
+    pub fn pinned(&self) -> Option<String> {
+        return self.pinned.lock().expect("Could not lock mutex").clone();
+    }
+
+    pub fn apply(&self, options: &mut ClientOptions) {
+        let router = self.clone();
+        options.selection_criteria = Some(SelectionCriteria::Predicate(Arc::new(move |server: &ServerInfo| router.accepts(server))));
+        options.sdam_event_handler = Some(Arc::new(self.clone()));
+        options.command_event_handler = Some(Arc::new(self.clone()));
+    }
+
+    pub fn accepts(&self, server: &ServerInfo) -> bool {
+        return self.accepts_server(&server.address().to_string(), server.server_type());
+    }
+
+    fn accepts_server(&self, host: &str, server_type: ServerType) -> bool {
+        if matches!(server_type, ServerType::Standalone | ServerType::Mongos | ServerType::LoadBalancer) {
+            return true;
+        }
+
+        if self.policy.is_sticky() {
+            if let Some(pinned) = self.pinned() {
+                return pinned == host;
+            }
+        }
+
+        let primary = server_type == ServerType::RsPrimary;
+        let secondary = server_type == ServerType::RsSecondary;
+        let failover = self.policy.is_failover();
+
+        return match self.read_preference {
+            EReadPreference::PRIMARY => primary || (failover && secondary && !self.has(ServerType::RsPrimary)),
+            EReadPreference::PRIMARYPREFERRED => primary || (secondary && !self.has(ServerType::RsPrimary)),
+            EReadPreference::SECONDARY => secondary || (failover && primary && !self.has(ServerType::RsSecondary)),
+            EReadPreference::SECONDARYPREFERRED => secondary || (primary && !self.has(ServerType::RsSecondary)),
+            EReadPreference::NEAREST => primary || secondary,
+        };
+    }
+
+    fn has(&self, server_type: ServerType) -> bool {
+        return self.servers.lock().expect("Could not lock mutex").values().any(|t| *t == server_type);
+    }
+
+    fn is_readable(server_type: ServerType) -> bool {
+        return matches!(server_type, ServerType::RsPrimary | ServerType::RsSecondary);
+    }
+
+    fn emit(&self, kind: ERoutingEventKind, host: &str, detail: &str) {
+        self.monitor.push(RoutingEvent::new(kind, String::from(host), String::from(detail)));
+    }
+
+    fn unpin(&self, host: &str) {
+        let mut pinned = self.pinned.lock().expect("Could not lock mutex");
+        if pinned.as_deref() != Some(host) {
+            return;
+        }
+        *pinned = None;
+        drop(pinned);
+
+        self.emit(ERoutingEventKind::UNPINNED, host, "Pinned server released, reads are rerouted.");
+    }
+
+}
+
+impl SdamEventHandler for MongoDbRouter {
+
+    fn handle_server_description_changed_event(&self, event: ServerDescriptionChangedEvent) {
+        let host = event.address.to_string();
+        let previous = event.previous_description.server_type();
+        let current = event.new_description.server_type();
+
+        self.servers.lock().expect("Could not lock mutex").insert(host.clone(), current);
+
+        if previous == current {
+            return;
+        }
+
+        if current == ServerType::Unknown {
+            self.emit(ERoutingEventKind::SERVERDOWN, &host, "Server is no longer reachable.");
+        } else if previous == ServerType::Unknown {
+            self.emit(ERoutingEventKind::SERVERUP, &host, "Server is reachable.");
+        }
+
+        if previous == ServerType::RsPrimary {
+            self.emit(ERoutingEventKind::PRIMARYLOST, &host, "Primary stepped down or became unreachable.");
+            if self.policy.is_failover() && self.read_preference == EReadPreference::PRIMARY {
+                self.emit(ERoutingEventKind::FAILOVER, &host, "Reads fall back to secondaries until a new primary is elected.");
+            }
+        }
+
+        if current == ServerType::RsPrimary {
+            self.emit(ERoutingEventKind::PRIMARYELECTED, &host, "Server became the primary.");
+            if matches!(self.read_preference, EReadPreference::PRIMARY | EReadPreference::PRIMARYPREFERRED) {
+                if let Some(pinned) = self.pinned().filter(|pinned| *pinned != host) {
+                    self.unpin(&pinned);
+                }
+            }
+        }
+
+        if !MongoDbRouter::is_readable(current) {
+            self.unpin(&host);
+        }
+    }
+
+    fn handle_server_closed_event(&self, event: ServerClosedEvent) {
+        let host = event.address.to_string();
+        self.servers.lock().expect("Could not lock mutex").remove(&host);
+        self.unpin(&host);
+    }
+
+}
+
+impl CommandEventHandler for MongoDbRouter {
+
+    fn handle_command_started_event(&self, event: CommandStartedEvent) {
+        if !self.policy.is_sticky() || !READ_COMMANDS.contains(&event.command_name.as_str()) {
+            return;
+        }
+
+        let host = event.connection.address.to_string();
+        let readable = self.servers.lock().expect("Could not lock mutex").get(&host)
+            .map(|t| MongoDbRouter::is_readable(*t))
+            .unwrap_or(false);
+        if !readable {
+            return;
+        }
+
+        let mut pinned = self.pinned.lock().expect("Could not lock mutex");
+        if pinned.is_some() {
+            return;
+        }
+        *pinned = Some(host.clone());
+        drop(pinned);
+
+        self.emit(ERoutingEventKind::PINNED, &host, "Reads for this connection stick to this server.");
+    }
+
+}
\ No newline at end of file
@@ -1,7 +1,9 @@
-use mongodb::{bson::{doc, oid::ObjectId, Bson, Document}, options::IndexOptions, IndexModel};
-use serde_json::from_str;
+use std::{path::PathBuf, time::Duration};
 
-use crate::{commons::exception::connect_exception::ConnectException, domain::{field::{e_field_code::EFieldCode, generate::field_data::FieldData}, filter::{e_filter_category::EFilterCategory, filter_element::FilterElement, filter_value::FilterValue}}};
+use mongodb::{bson::{doc, oid::ObjectId, Bson, Document}, options::{AuthMechanism, Collation, CollationStrength, CreateCollectionOptions, Credential, IndexOptions, ReadPreference, ReadPreferenceOptions, SelectionCriteria, TimeseriesGranularity, TimeseriesOptions, Tls, TlsOptions, ValidationAction, ValidationLevel}, IndexModel};
+use serde_json::{from_str, Value};
+
+use crate::{commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind}, domain::{collection::{collection_validator::CollectionValidator, generate_collection_query::GenerateCollectionQuery, e_validation_action::EValidationAction, e_validation_level::EValidationLevel}, connection_credential::ConnectionCredential, connection_tls::ConnectionTls, e_auth_mechanism::EAuthMechanism, e_read_preference::EReadPreference, field::{e_field_code::EFieldCode, generate::field_data::FieldData}, filter::{e_filter_category::EFilterCategory, e_filter_operator::EFilterOperator, filter_element::FilterElement, filter_value::FilterValue}, update::update_spec::UpdateSpec}};
 
 pub struct QueryItems {
     and_fields: Vec<Document>,
@@ -13,14 +15,13 @@ impl FilterElement {
     
     pub fn as_mongo_agregate(&self) -> Result<Vec<Document>, ConnectException> {
         let mut registry = QueryItems {and_fields: Vec::new(), or_fields: Vec::new(), queries: Vec::new()};
-        registry = self._as_mongo_agregate(registry);
+        registry = self._as_mongo_agregate(registry)?;
 
         let mut result = doc! {};
         let mut matches_collection = doc! {};
 
         let mut pipeline = Vec::new();
 
-
         if !registry.and_fields.is_empty() {
             matches_collection.insert("$and",  registry.and_fields);
         }
@@ -41,53 +42,53 @@ impl FilterElement {
         Ok(pipeline)
     }
 
-    fn _as_mongo_agregate(&self, mut registry: QueryItems) -> QueryItems {
+    fn _as_mongo_agregate(&self, mut registry: QueryItems) -> Result<QueryItems, ConnectException> {
         let f_value = self.value();
         let field = self.field();
 
-        let result = f_value.as_mongo_agregate(registry);
-        let value = result.0;
-        registry = result.1;
-
         let category = f_value.category();
 
-        if category == EFilterCategory::ROOT {
-            return registry;    
-        }
-
         if category == EFilterCategory::COLLECTION {
-            let mut block = doc! {};
+            return self.block_as_mongo_agregate(registry);
+        }
 
-            if !registry.and_fields.is_empty() {
-                block.insert("$and",  registry.and_fields.clone());
-                registry.and_fields.clear();
-            }
+        let result = f_value.as_mongo_agregate(registry)?;
+        let value = result.0;
+        registry = result.1;
 
-            if !registry.and_fields.is_empty() {
-                block.insert("$or",  registry.or_fields.clone());
-                registry.or_fields.clear();
-            }
+        if category == EFilterCategory::ROOT {
+            return Ok(registry);
+        }
 
-            if !block.is_empty() {
-                if self.is_or() {
-                    registry.or_fields.push(block);
-                } else {
-                    registry.and_fields.push(block);
-                }   
+        if category == EFilterCategory::QUERY {
+            match value {
+                Bson::Document(stage) => registry.queries.push(stage),
+                Bson::Array(stages) => {
+                    for stage in stages {
+                        match stage {
+                            Bson::Document(stage) => registry.queries.push(stage),
+                            _ => return Err(ConnectException::new(String::from("Query pipeline stages must be documents."))),
+                        }
+                    }
+                },
+                _ => return Err(ConnectException::new(String::from("Query must be a document or a pipeline."))),
             }
-
-            return registry;    
+            return Ok(registry);
         }
 
-        if category == EFilterCategory::QUERY {
-            let document = value.as_document();
-            //TODO: Error
-            registry.queries.push(document.cloned().unwrap());
-            return registry;    
+        if field.is_empty() {
+            return Err(ConnectException::new(String::from("Filter field cannot be empty.")));
         }
 
         let query;
-        if self.is_negate() {
+        if self.operator() != EFilterOperator::EQ {
+            let condition = self.operator_as_mongo_agregate(value)?;
+            if self.is_negate() {
+                query = doc! {field: {"$not": condition}};
+            } else {
+                query = doc! {field: condition};
+            }
+        } else if self.is_negate() {
             query = doc! {
                 field: {
                     "$not": {
@@ -105,52 +106,151 @@ impl FilterElement {
             registry.and_fields.push(query);
         }
 
-        return registry;
+        Ok(registry)
+    }
+
+    fn block_as_mongo_agregate(&self, mut registry: QueryItems) -> Result<QueryItems, ConnectException> {
+        let mut scope = QueryItems {and_fields: Vec::new(), or_fields: Vec::new(), queries: Vec::new()};
+        scope = self.value().collection_as_mongo_agregate(scope)?;
+
+        registry.queries.append(&mut scope.queries);
+
+        let mut block = doc! {};
+
+        if !scope.and_fields.is_empty() {
+            block.insert("$and",  scope.and_fields);
+        }
+
+        if !scope.or_fields.is_empty() {
+            block.insert("$or",  scope.or_fields);
+        }
+
+        if !block.is_empty() {
+            if self.is_or() {
+                registry.or_fields.push(block);
+            } else {
+                registry.and_fields.push(block);
+            }
+        }
+
+        Ok(registry)
+    }
+
+    fn operator_as_mongo_agregate(&self, value: Bson) -> Result<Document, ConnectException> {
+        let condition = match self.operator() {
+            EFilterOperator::EQ => doc! {"$eq": value},
+            EFilterOperator::GT => doc! {"$gt": value},
+            EFilterOperator::GTE => doc! {"$gte": value},
+            EFilterOperator::LT => doc! {"$lt": value},
+            EFilterOperator::LTE => doc! {"$lte": value},
+            EFilterOperator::IN => doc! {"$in": as_mongo_array(value)},
+            EFilterOperator::NOTIN => doc! {"$nin": as_mongo_array(value)},
+            EFilterOperator::REGEX => {
+                if let Bson::String(_) = value {
+                    doc! {"$regex": value}
+                } else {
+                    return Err(ConnectException::new(String::from("Regex filters require a string pattern.")));
+                }
+            },
+            EFilterOperator::EXISTS => doc! {"$exists": value},
+            EFilterOperator::BETWEEN => {
+                let values = as_mongo_array(value);
+                if values.len() != 2 {
+                    let message = format!("Between filters require two bounds, found {}.", values.len());
+                    return Err(ConnectException::new(message));
+                }
+                doc! {"$gte": values[0].clone(), "$lte": values[1].clone()}
+            },
+        };
+
+        Ok(condition)
     }
 
 }
 
+fn as_mongo_array(value: Bson) -> Vec<Bson> {
+    match value {
+        Bson::Array(values) => values,
+        value => vec![value],
+    }
+}
+
 impl FilterValue {
     
-    pub fn as_mongo_agregate(&self, registry: QueryItems) -> (Bson, QueryItems) {
+    pub fn as_mongo_agregate(&self, registry: QueryItems) -> Result<(Bson, QueryItems), ConnectException> {
         let value = self.value();
         match self.category() {
             EFilterCategory::IDNUMERIC | EFilterCategory::IDSTRING => {
                 let attributes = self.attributes();
                 let oid = attributes.iter().find(|a| a.key() == "$oid");
-                if let Some(_) = oid {
-                    let oid = ObjectId::parse_str(value);
-                    //TODO: Error
-                    return (Bson::ObjectId(oid.unwrap()), registry);
+                if oid.is_some() {
+                    let oid = ObjectId::parse_str(&value);
+                    if let Err(error) = oid {
+                        let message = format!("Invalid ObjectId '{}': {}", value, error);
+                        return Err(ConnectException::new(message));
+                    }
+                    return Ok((Bson::ObjectId(oid.unwrap()), registry));
+                }
+                if self.category() == EFilterCategory::IDNUMERIC {
+                    let number = value.parse::<i64>();
+                    if let Err(error) = number {
+                        let message = format!("Invalid numeric identifier '{}': {}", value, error);
+                        return Err(ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, message));
+                    }
+                    return Ok((Bson::Int64(number.unwrap()), registry));
                 }
-                (Bson::String(value), registry)
+                Ok((Bson::String(value), registry))
             },
             EFilterCategory::QUERY => {
-                let pipeline: Result<Document, serde_json::Error> = from_str(&value);
-                //TODO: Error
-                (Bson::Document(pipeline.unwrap()), registry)
+                let json: Result<Value, serde_json::Error> = from_str(&value);
+                if let Err(error) = json {
+                    let message = format!("Invalid query JSON: {}", error);
+                    return Err(ConnectException::new(message));
+                }
+                Ok((json_as_bson(json.unwrap())?, registry))
             },
-            EFilterCategory::STRING => (Bson::String(value), registry),
+            EFilterCategory::STRING => Ok((Bson::String(value), registry)),
             EFilterCategory::BOOLEAN => {
                 let boolean = value.parse::<bool>();
-                //TODO: Error
-                (Bson::Boolean(boolean.unwrap()), registry)
+                if let Err(error) = boolean {
+                    let message = format!("Invalid boolean '{}': {}", value, error);
+                    return Err(ConnectException::new(message));
+                }
+                Ok((Bson::Boolean(boolean.unwrap()), registry))
             },
             EFilterCategory::NUMERIC => {
-                let integer = value.parse::<i64>();
-                //TODO: Error
-                (Bson::Int64(integer.unwrap()), registry)
+                if let Ok(integer) = value.parse::<i64>() {
+                    return Ok((Bson::Int64(integer), registry));
+                }
+                let double = value.parse::<f64>();
+                if let Err(error) = double {
+                    let message = format!("Invalid number '{}': {}", value, error);
+                    return Err(ConnectException::new(message));
+                }
+                Ok((Bson::Double(double.unwrap()), registry))
             },
-            EFilterCategory::COLLECTION => (Bson::String(value), self.collection_as_mongo_agregate(registry)),
-            EFilterCategory::ROOT => (Bson::String(value), self.collection_as_mongo_agregate(registry)),
+            EFilterCategory::LIST => self.list_as_mongo_agregate(registry),
+            EFilterCategory::COLLECTION => Ok((Bson::String(value), self.collection_as_mongo_agregate(registry)?)),
+            EFilterCategory::ROOT => Ok((Bson::String(value), self.collection_as_mongo_agregate(registry)?)),
+            EFilterCategory::SNIPPET => Err(ConnectException::new(format!("Filter snippet '@{}' must be expanded before translation.", value))),
+        }
+    }
+
+    fn list_as_mongo_agregate(&self, mut registry: QueryItems) -> Result<(Bson, QueryItems), ConnectException> {
+        let mut values = Vec::new();
+        for child in self.children() {
+            let result = child.value().as_mongo_agregate(registry)?;
+            values.push(result.0);
+            registry = result.1;
         }
+        Ok((Bson::Array(values), registry))
     }
 
-    fn collection_as_mongo_agregate(&self, mut registry: QueryItems) -> QueryItems {
+    fn collection_as_mongo_agregate(&self, mut registry: QueryItems) -> Result<QueryItems, ConnectException> {
         for child in self.children() {
-            registry = child._as_mongo_agregate(registry);
+            registry = child._as_mongo_agregate(registry)?;
         }
-        return registry;
+        Ok(registry)
     }
 
 }
@@ -165,7 +265,7 @@ impl FieldData {
 
     pub fn as_mongo_create(&self) -> Result<IndexModel, ConnectException> {
         if self.code() != EFieldCode::INDEXED {
-            let exception = ConnectException::new(String::from("Field type not supported."));
+            let exception = ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, String::from("Field type not supported."));
             return Err(exception);
         }
 
@@ -204,4 +304,560 @@ impl FieldData {
         Ok(index)
     }
 
+}
+
+impl ConnectionCredential {
+
+    pub fn as_mongo_credential(&self) -> Credential {
+        let mut credential = Credential::default();
+        credential.username = Some(self.username());
+        credential.password = self.password();
+        credential.source = self.source();
+        credential.mechanism = self.mechanism().map(|m| m.as_mongo_mechanism());
+        credential
+    }
+
+}
+
+impl EAuthMechanism {
+
+    pub fn as_mongo_mechanism(&self) -> AuthMechanism {
+        match self {
+            EAuthMechanism::SCRAMSHA1 => AuthMechanism::ScramSha1,
+            EAuthMechanism::SCRAMSHA256 => AuthMechanism::ScramSha256,
+            EAuthMechanism::X509 => AuthMechanism::MongoDbX509,
+            EAuthMechanism::PLAIN => AuthMechanism::Plain,
+        }
+    }
+
+}
+
+impl ConnectionTls {
+
+    pub fn as_mongo_tls(&self) -> Result<Tls, ConnectException> {
+        if self.allow_invalid_hostnames() && !self.allow_invalid_certificates() {
+            let message = String::from("Relaxing only the TLS hostname check is not supported by the rustls backend, it would disable certificate validation too.");
+            return Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, message));
+        }
+
+        let mut options = TlsOptions::default();
+        options.ca_file_path = self.ca_file().map(PathBuf::from);
+        options.cert_key_file_path = self.cert_key_file().map(PathBuf::from);
+        if self.allow_invalid_certificates() {
+            options.allow_invalid_certificates = Some(true);
+        }
+        Ok(Tls::Enabled(options))
+    }
+
+}
+
+impl EReadPreference {
+
+    pub fn as_mongo_selection(&self) -> SelectionCriteria {
+        let options = ReadPreferenceOptions::default();
+        let preference = match self {
+            EReadPreference::PRIMARY => ReadPreference::Primary,
+            EReadPreference::PRIMARYPREFERRED => ReadPreference::PrimaryPreferred { options },
+            EReadPreference::SECONDARY => ReadPreference::Secondary { options },
+            EReadPreference::SECONDARYPREFERRED => ReadPreference::SecondaryPreferred { options },
+            EReadPreference::NEAREST => ReadPreference::Nearest { options },
+        };
+        SelectionCriteria::ReadPreference(preference)
+    }
+
+}
+
+const COLLECTION_OPTIONS: [&str; 9] = [
+    "CAPPED", "CAPPED_SIZE", "CAPPED_MAX",
+    "COLLATION_LOCALE", "COLLATION_STRENGTH",
+    "TIMESERIES_TIME_FIELD", "TIMESERIES_META_FIELD", "TIMESERIES_GRANULARITY", "EXPIRE_AFTER_SECONDS"
+];
+
+impl GenerateCollectionQuery {
+
+    pub fn as_mongo_create(&self) -> Result<Option<CreateCollectionOptions>, ConnectException> {
+        if let Some(option) = self.options().iter().find(|o| !COLLECTION_OPTIONS.contains(&o.code().as_str())) {
+            let message = format!("Collection option '{}' is not supported.", option.code());
+            return Err(ConnectException::new(message));
+        }
+
+        let mut options = match self.validator() {
+            Some(validator) => validator.as_mongo_create()?,
+            None => CreateCollectionOptions::default(),
+        };
+
+        let capped = self.option_bool("CAPPED")?.unwrap_or(false);
+        let size = self.option_number("CAPPED_SIZE")?;
+        let max = self.option_number("CAPPED_MAX")?;
+        if capped && size.is_none() {
+            return Err(ConnectException::new(String::from("Capped collections require a 'CAPPED_SIZE' option.")));
+        }
+        if !capped && (size.is_some() || max.is_some()) {
+            return Err(ConnectException::new(String::from("'CAPPED_SIZE' and 'CAPPED_MAX' require the 'CAPPED' option.")));
+        }
+        if capped {
+            options.capped = Some(true);
+            options.size = size;
+            options.max = max;
+        }
+
+        let strength = match self.option_number("COLLATION_STRENGTH")? {
+            None => None,
+            Some(1) => Some(CollationStrength::Primary),
+            Some(2) => Some(CollationStrength::Secondary),
+            Some(3) => Some(CollationStrength::Tertiary),
+            Some(4) => Some(CollationStrength::Quaternary),
+            Some(5) => Some(CollationStrength::Identical),
+            Some(code) => return Err(ConnectException::new(format!("Invalid collation strength '{}', expected 1 to 5.", code))),
+        };
+        match self.option("COLLATION_LOCALE") {
+            Some(locale) => options.collation = Some(Collation::builder().locale(locale).strength(strength).build()),
+            None if strength.is_some() => {
+                return Err(ConnectException::new(String::from("'COLLATION_STRENGTH' requires the 'COLLATION_LOCALE' option.")));
+            },
+            None => {},
+        }
+
+        let meta_field = self.option("TIMESERIES_META_FIELD");
+        let granularity = match self.option("TIMESERIES_GRANULARITY").as_deref() {
+            None => None,
+            Some("seconds") => Some(TimeseriesGranularity::Seconds),
+            Some("minutes") => Some(TimeseriesGranularity::Minutes),
+            Some("hours") => Some(TimeseriesGranularity::Hours),
+            Some(code) => return Err(ConnectException::new(format!("Invalid time series granularity '{}'.", code))),
+        };
+        let expire = self.option_number("EXPIRE_AFTER_SECONDS")?;
+        match self.option("TIMESERIES_TIME_FIELD") {
+            Some(time_field) => {
+                options.timeseries = Some(TimeseriesOptions::builder()
+                    .time_field(time_field)
+                    .meta_field(meta_field)
+                    .granularity(granularity)
+                    .build());
+                options.expire_after_seconds = expire.map(Duration::from_secs);
+            },
+            None if meta_field.is_some() || granularity.is_some() || expire.is_some() => {
+                return Err(ConnectException::new(String::from("Time series options require the 'TIMESERIES_TIME_FIELD' option.")));
+            },
+            None => {},
+        }
+
+        if self.options().is_empty() && self.validator().is_none() {
+            return Ok(None);
+        }
+
+        Ok(Some(options))
+    }
+
+    fn option_bool(&self, code: &str) -> Result<Option<bool>, ConnectException> {
+        match self.option(code) {
+            Some(value) => match value.parse::<bool>() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) => Err(ConnectException::new(format!("Option '{}' must be a boolean, found '{}'.", code, value))),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn option_number(&self, code: &str) -> Result<Option<u64>, ConnectException> {
+        match self.option(code) {
+            Some(value) => match value.parse::<u64>() {
+                Ok(value) => Ok(Some(value)),
+                Err(_) => Err(ConnectException::new(format!("Option '{}' must be a positive integer, found '{}'.", code, value))),
+            },
+            None => Ok(None),
+        }
+    }
+
+}
+
+impl CollectionValidator {
+
+    pub fn as_mongo_validator(&self) -> Result<Document, ConnectException> {
+        let Bson::Document(schema) = json_as_bson(self.schema())? else {
+            return Err(ConnectException::new(String::from("Collection validator schema must be a JSON object.")));
+        };
+        Ok(doc! {"$jsonSchema": schema})
+    }
+
+    pub fn as_mongo_create(&self) -> Result<CreateCollectionOptions, ConnectException> {
+        let mut options = CreateCollectionOptions::default();
+        options.validator = Some(self.as_mongo_validator()?);
+        options.validation_level = Some(self.level().as_mongo_level());
+        options.validation_action = Some(self.action().as_mongo_action());
+        Ok(options)
+    }
+
+    pub fn as_mongo_modify(&self, collection: &str) -> Result<Document, ConnectException> {
+        Ok(doc! {
+            "collMod": collection,
+            "validator": self.as_mongo_validator()?,
+            "validationLevel": self.level().to_string().to_lowercase(),
+            "validationAction": self.action().to_string().to_lowercase()
+        })
+    }
+
+    pub fn from_mongo_options(options: &Document) -> Result<Option<CollectionValidator>, ConnectException> {
+        let validator = match options.get_document("validator") {
+            Ok(validator) if !validator.is_empty() => validator,
+            _ => return Ok(None),
+        };
+
+        let schema = validator.get_document("$jsonSchema");
+        if validator.len() != 1 || schema.is_err() {
+            let keys: Vec<&String> = validator.keys().collect();
+            let message = format!("Collection validator is not a $jsonSchema validator: {:?}.", keys);
+            return Err(ConnectException::new(message));
+        }
+
+        let schema = Bson::Document(schema.unwrap().clone()).into_relaxed_extjson();
+
+        let level = match options.get_str("validationLevel") {
+            Ok(code) => EValidationLevel::from_mongo_level(code)?,
+            Err(_) => EValidationLevel::STRICT,
+        };
+
+        let action = match options.get_str("validationAction") {
+            Ok(code) => EValidationAction::from_mongo_action(code)?,
+            Err(_) => EValidationAction::ERROR,
+        };
+
+        Ok(Some(CollectionValidator::from(schema, level, action)))
+    }
+
+}
+
+impl EValidationLevel {
+
+    pub fn as_mongo_level(&self) -> ValidationLevel {
+        match self {
+            EValidationLevel::OFF => ValidationLevel::Off,
+            EValidationLevel::STRICT => ValidationLevel::Strict,
+            EValidationLevel::MODERATE => ValidationLevel::Moderate,
+        }
+    }
+
+    pub fn from_mongo_level(code: &str) -> Result<EValidationLevel, ConnectException> {
+        match EValidationLevel::from_string(&code.to_uppercase()) {
+            Some(level) => Ok(level),
+            None => Err(ConnectException::new(format!("Unknown validation level '{}'.", code))),
+        }
+    }
+
+}
+
+impl EValidationAction {
+
+    pub fn as_mongo_action(&self) -> ValidationAction {
+        match self {
+            EValidationAction::ERROR => ValidationAction::Error,
+            EValidationAction::WARN => ValidationAction::Warn,
+        }
+    }
+
+    pub fn from_mongo_action(code: &str) -> Result<EValidationAction, ConnectException> {
+        match EValidationAction::from_string(&code.to_uppercase()) {
+            Some(action) => Ok(action),
+            None => Err(ConnectException::new(format!("Unknown validation action '{}'.", code))),
+        }
+    }
+
+}
+
+impl UpdateSpec {
+
+    pub fn as_mongo_update(&self) -> Result<Document, ConnectException> {
+        let mut update = doc! {};
+
+        let mut set = doc! {};
+        for (field, value) in self.set_fields() {
+            set.insert(field, json_as_bson(value)?);
+        }
+
+        if !set.is_empty() {
+            update.insert("$set", set);
+        }
+
+        let unset: Document = self.unset_fields().into_iter()
+            .map(|f| (f, Bson::String(String::new())))
+            .collect();
+
+        if !unset.is_empty() {
+            update.insert("$unset", unset);
+        }
+
+        let rename: Document = self.rename_fields().into_iter()
+            .map(|(f, t)| (f, Bson::String(t)))
+            .collect();
+
+        if !rename.is_empty() {
+            update.insert("$rename", rename);
+        }
+
+        let operators = [
+            ("$inc", self.inc_fields()),
+            ("$mul", self.mul_fields()),
+            ("$push", self.push_fields()),
+            ("$pull", self.pull_fields()),
+            ("$addToSet", self.add_to_set_fields()),
+        ];
+
+        for (operator, fields) in operators {
+            let mut operation = doc! {};
+            for (field, value) in fields {
+                operation.insert(field, json_as_bson(value)?);
+            }
+
+            if !operation.is_empty() {
+                update.insert(operator, operation);
+            }
+        }
+
+        Ok(update)
+    }
+
+    pub fn as_mongo_agregate(&self) -> Result<Vec<Document>, ConnectException> {
+        let mut set = doc! {};
+        let mut unset = self.unset_fields();
+
+        for (field, target) in self.rename_fields() {
+            set.insert(target, format!("${}", field));
+            unset.push(field);
+        }
+
+        for (field, value) in self.set_fields() {
+            set.insert(field, doc! { "$literal": json_as_bson(value)? });
+        }
+
+        for (field, value) in self.inc_fields() {
+            let current = doc! { "$ifNull": [format!("${}", field), 0] };
+            set.insert(field, doc! { "$add": [current, json_as_bson(value)?] });
+        }
+
+        for (field, value) in self.mul_fields() {
+            let current = doc! { "$ifNull": [format!("${}", field), 0] };
+            set.insert(field, doc! { "$multiply": [current, json_as_bson(value)?] });
+        }
+
+        for (field, value) in self.push_fields() {
+            let current = doc! { "$ifNull": [format!("${}", field), []] };
+            let value = doc! { "$literal": [json_as_bson(value)?] };
+            set.insert(field, doc! { "$concatArrays": [current, value] });
+        }
+
+        for (field, value) in self.pull_fields() {
+            let current = doc! { "$ifNull": [format!("${}", field), []] };
+            let value = doc! { "$literal": json_as_bson(value)? };
+            set.insert(field, doc! { "$filter": { "input": current, "cond": { "$ne": ["$$this", value] } } });
+        }
+
+        for (field, value) in self.add_to_set_fields() {
+            let current = doc! { "$ifNull": [format!("${}", field), []] };
+            let value = doc! { "$literal": [json_as_bson(value)?] };
+            set.insert(field, doc! { "$setUnion": [current, value] });
+        }
+
+        let mut pipeline = Vec::new();
+
+        if !set.is_empty() {
+            pipeline.push(doc! { "$set": set });
+        }
+
+        if !unset.is_empty() {
+            pipeline.push(doc! { "$unset": unset });
+        }
+
+        Ok(pipeline)
+    }
+
+}
+
+fn json_as_bson(value: Value) -> Result<Bson, ConnectException> {
+    let bson = Bson::try_from(value);
+    if let Err(error) = bson {
+        let err = format!("Failed to convert JSON to BSON: {}", error);
+        return Err(ConnectException::new(err));
+    }
+
+    Ok(bson.unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+
+    use mongodb::{bson::{doc, Document}, options::Tls};
+    use serde_json::json;
+
+    use crate::{commons::exception::e_connect_error_kind::EConnectErrorKind, domain::{connection_tls::ConnectionTls, filter::{e_filter_operator::EFilterOperator, filter_element::FilterElement, filter_value::FilterValue}, update::update_spec::UpdateSpec}};
+
+    fn render(filters: Vec<FilterElement>) -> Vec<Document> {
+        let mut filter = FilterElement::new();
+        for element in filters {
+            filter.push(element);
+        }
+        filter.as_mongo_agregate().unwrap()
+    }
+
+    fn number(value: i32) -> FilterValue {
+        FilterValue::i32(value, Vec::new())
+    }
+
+    fn string(value: &str) -> FilterValue {
+        FilterValue::string(String::from(value), Vec::new())
+    }
+
+    #[test]
+    fn comparison_operators_render_their_mongo_operator() {
+        let operators = [
+            (EFilterOperator::GT, "$gt"),
+            (EFilterOperator::GTE, "$gte"),
+            (EFilterOperator::LT, "$lt"),
+            (EFilterOperator::LTE, "$lte"),
+        ];
+
+        for (operator, code) in operators {
+            let pipeline = render(vec![FilterElement::compare(String::from("age"), operator, number(18))]);
+            assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"age": {code: 18_i64}}]}}]);
+        }
+    }
+
+    #[test]
+    fn set_operators_render_arrays() {
+        let pipeline = render(vec![FilterElement::list(String::from("tag"), EFilterOperator::IN, vec![string("a"), string("b")])]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"tag": {"$in": ["a", "b"]}}]}}]);
+
+        let pipeline = render(vec![FilterElement::list(String::from("tag"), EFilterOperator::NOTIN, vec![string("a")])]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"tag": {"$nin": ["a"]}}]}}]);
+    }
+
+    #[test]
+    fn regex_and_exists_render_their_operands() {
+        let pipeline = render(vec![FilterElement::regex(String::from("name"), String::from("^a"))]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"name": {"$regex": "^a"}}]}}]);
+
+        let pipeline = render(vec![FilterElement::exists(String::from("deleted"), false)]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"deleted": {"$exists": false}}]}}]);
+    }
+
+    #[test]
+    fn regex_requires_a_string_pattern() {
+        let mut filter = FilterElement::new();
+        filter.push(FilterElement::compare(String::from("name"), EFilterOperator::REGEX, number(1)));
+
+        assert!(filter.as_mongo_agregate().is_err());
+    }
+
+    #[test]
+    fn between_renders_an_inclusive_range() {
+        let pipeline = render(vec![FilterElement::between(String::from("n"), number(1), number(5))]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"n": {"$gte": 1_i64, "$lte": 5_i64}}]}}]);
+    }
+
+    #[test]
+    fn between_requires_two_bounds() {
+        let mut filter = FilterElement::new();
+        filter.push(FilterElement::list(String::from("n"), EFilterOperator::BETWEEN, vec![number(1)]));
+
+        assert!(filter.as_mongo_agregate().is_err());
+    }
+
+    #[test]
+    fn or_groups_are_kept_apart_from_their_siblings() {
+        let filter = FilterElement::from_id_chain_collection(vec![String::from("_id=1"), String::from("_id=2")]);
+
+        let pipeline = filter.as_mongo_agregate().unwrap();
+
+        assert_eq!(pipeline, vec![doc! {"$match": {"$or": [{"$and": [{"_id": "2"}]}, {"$and": [{"_id": "1"}]}]}}]);
+    }
+
+    #[test]
+    fn nested_or_group_is_rendered_as_or() {
+        let mut group = FilterElement::new();
+        group.push(FilterElement::string(String::from("a"), String::from("1"), Vec::new()).as_or_ref());
+        group.push(FilterElement::string(String::from("b"), String::from("2"), Vec::new()).as_or_ref());
+
+        let pipeline = render(vec![group, FilterElement::string(String::from("c"), String::from("3"), Vec::new())]);
+
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"c": "3"}, {"$or": [{"b": "2"}, {"a": "1"}]}]}}]);
+    }
+
+    #[test]
+    fn negated_operators_are_wrapped_in_not() {
+        let pipeline = render(vec![FilterElement::compare(String::from("age"), EFilterOperator::GT, number(18)).negate_ref()]);
+        assert_eq!(pipeline, vec![doc! {"$match": {"$and": [{"age": {"$not": {"$gt": 18_i64}}}]}}]);
+    }
+
+    fn invalid_certificates(tls: Tls) -> Option<bool> {
+        match tls {
+            Tls::Enabled(options) => options.allow_invalid_certificates,
+            Tls::Disabled => None,
+        }
+    }
+
+    #[test]
+    fn update_spec_renders_each_operator() {
+        let mut spec = UpdateSpec::new();
+        spec.set(String::from("status"), json!("active"))
+            .unset(String::from("legacy"))
+            .rename(String::from("old"), String::from("new"))
+            .inc(String::from("visits"), json!(2));
+
+        let update = spec.as_mongo_update().unwrap();
+
+        assert_eq!(update, doc! {
+            "$set": {"status": "active"},
+            "$unset": {"legacy": ""},
+            "$rename": {"old": "new"},
+            "$inc": {"visits": 2},
+        });
+    }
+
+    #[test]
+    fn update_spec_omits_unused_operators() {
+        let mut spec = UpdateSpec::new();
+        spec.unset(String::from("legacy"));
+
+        let update = spec.as_mongo_update().unwrap();
+
+        assert_eq!(update, doc! {"$unset": {"legacy": ""}});
+    }
+
+    #[test]
+    fn update_spec_converts_nested_json_values() {
+        let mut spec = UpdateSpec::new();
+        spec.set(String::from("address"), json!({"city": "Madrid", "zip": [28001, 28002]}));
+
+        let update = spec.as_mongo_update().unwrap();
+
+        assert_eq!(update, doc! {"$set": {"address": {"city": "Madrid", "zip": [28001, 28002]}}});
+    }
+
+    #[test]
+    fn tls_validates_certificates_by_default() {
+        let tls = ConnectionTls::new().as_mongo_tls().unwrap();
+
+        assert_eq!(invalid_certificates(tls), None);
+    }
+
+    #[test]
+    fn tls_rejects_relaxing_only_hostnames() {
+        let mut tls = ConnectionTls::new();
+        tls.set_allow_invalid_hostnames(true);
+
+        let error = tls.as_mongo_tls().unwrap_err();
+
+        assert!(error.is(EConnectErrorKind::UNSUPPORTED));
+    }
+
+    #[test]
+    fn tls_relaxes_certificates_when_asked_explicitly() {
+        let mut tls = ConnectionTls::new();
+        tls.set_allow_invalid_certificates(true);
+        tls.set_allow_invalid_hostnames(true);
+
+        let tls = tls.as_mongo_tls().unwrap();
+
+        assert_eq!(invalid_certificates(tls), Some(true));
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,301 @@
+use std::path::Path;
+
+use async_trait::async_trait;
+use futures_util::stream::BoxStream;
+
+use crate::{
+    commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind},
+    domain::{
+        connection_data::ConnectionData,
+        collection::{
+            collection_data::CollectionData, collection_definition::CollectionDefinition, collection_index::CollectionIndex, collection_validator::CollectionValidator, dump_report::DumpReport, generate_collection_query::GenerateCollectionQuery, import_integrity::ImportIntegrity, import_preview::ImportPreview
+        },
+        data_base::generate_database_query::GenerateDatabaseQuery,
+        document::{document_data::DocumentData, document_delete_preview::DocumentDeletePreview, document_insert_result::DocumentInsertResult, document_schema::DocumentSchema, document_size::DocumentSize, soft_delete_policy::SoftDeletePolicy, document_update_field::DocumentUpdateField, lineage_policy::LineagePolicy},
+        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery},
+        host_latency::HostLatency,
+        metadata::{collection_metadata::CollectionMetadata, data_base_metadata::DataBaseMetadata, server_metadata::ServerMetadata},
+        routing::routing_event::RoutingEvent,
+        server_time::ServerTime,
+        table::table_data_group::TableDataGroup,
+        update::{update_preview::UpdatePreview, update_spec::UpdateSpec, update_summary::UpdateSummary},
+    },
+};
+
+use super::{i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository};
+
+#[derive(Clone)]
+pub struct UnsupportedRepository {
+    connection: ConnectionData
+}
+
+impl UnsupportedRepository {
+
+    pub fn new(connection: &ConnectionData) -> UnsupportedRepository {
+        UnsupportedRepository {
+            connection: connection.clone()
+        }
+    }
+
+    fn unsupported(&self, operation: &str) -> ConnectException {
+        let message = format!("Operation '{}' is not supported by the {} repository yet.", operation, self.connection.category().to_string());
+        return ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, message);
+    }
+
+}
+
+#[async_trait]
+impl IDBRepository for UnsupportedRepository {
+
+    fn fingerprint(&self) -> String {
+        return self.connection.fingerprint();
+    }
+
+    fn soft_delete(&self, data_base: &str, collection: &str) -> Option<SoftDeletePolicy> {
+        return self.connection.soft_delete(data_base, collection);
+    }
+
+    fn lineage(&self) -> Option<LineagePolicy> {
+        return self.connection.lineage();
+    }
+
+    fn routing_events(&self) -> Vec<RoutingEvent> {
+        return Vec::new();
+    }
+
+    async fn status(&self) -> Result<(), ConnectException> {
+        return Err(self.unsupported("status"));
+    }
+
+    async fn metadata(&self) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return Err(self.unsupported("metadata"));
+    }
+
+    async fn server_stats(&self) -> Result<ServerMetadata, ConnectException> {
+        return Err(self.unsupported("server_stats"));
+    }
+
+    async fn server_time(&self) -> Result<ServerTime, ConnectException> {
+        return Err(self.unsupported("server_time"));
+    }
+
+    async fn latency_probe(&self) -> Result<Vec<HostLatency>, ConnectException> {
+        return Err(self.unsupported("latency_probe"));
+    }
+
+    async fn data_base_metadata(&self, _query: &DataBaseQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return Err(self.unsupported("data_base_metadata"));
+    }
+
+    async fn data_base_stats(&self, _query: &DataBaseQuery) -> Result<DataBaseMetadata, ConnectException> {
+        return Err(self.unsupported("data_base_stats"));
+    }
+
+    async fn data_base_find_all(&self) -> Result<Vec<String>, ConnectException> {
+        return Err(self.unsupported("data_base_find_all"));
+    }
+
+    async fn data_base_exists(&self, _query: &DataBaseQuery) -> Result<bool, ConnectException> {
+        return Err(self.unsupported("data_base_exists"));
+    }
+
+    async fn data_base_create(&self, _query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        return Err(self.unsupported("data_base_create"));
+    }
+
+    async fn data_base_drop(&self, _query: &GenerateDatabaseQuery) -> Result<String, ConnectException> {
+        return Err(self.unsupported("data_base_drop"));
+    }
+
+    async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {
+        return Err(self.unsupported("collection_accept_schema"));
+    }
+
+    async fn collection_metadata(&self, _query: &CollectionQuery) -> Result<Vec<TableDataGroup>, ConnectException> {
+        return Err(self.unsupported("collection_metadata"));
+    }
+
+    async fn collection_stats(&self, _query: &CollectionQuery) -> Result<CollectionMetadata, ConnectException> {
+        return Err(self.unsupported("collection_stats"));
+    }
+
+    async fn collection_find_all(&self, _query: &DataBaseQuery) -> Result<Vec<String>, ConnectException> {
+        return Err(self.unsupported("collection_find_all"));
+    }
+
+    async fn collection_indexes(&self, _query: &CollectionQuery) -> Result<Vec<CollectionIndex>, ConnectException> {
+        return Err(self.unsupported("collection_indexes"));
+    }
+
+    async fn collection_exists(&self, _query: &CollectionQuery) -> Result<bool, ConnectException> {
+        return Err(self.unsupported("collection_exists"));
+    }
+
+    async fn collection_create(&self, _query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        return Err(self.unsupported("collection_create"));
+    }
+
+    async fn collection_drop(&self, _query: &GenerateCollectionQuery) -> Result<String, ConnectException> {
+        return Err(self.unsupported("collection_drop"));
+    }
+
+    async fn collection_validator(&self, _query: &CollectionQuery) -> Result<Option<CollectionValidator>, ConnectException> {
+        return Err(self.unsupported("collection_validator"));
+    }
+
+    async fn collection_set_validator(&self, _query: &CollectionQuery, _validator: Option<&CollectionValidator>) -> Result<String, ConnectException> {
+        return Err(self.unsupported("collection_set_validator"));
+    }
+
+    async fn collection_rename(&self, _query: &CollectionQuery, _name: &str) -> Result<String, ConnectException> {
+        return Err(self.unsupported("collection_rename"));
+    }
+
+    async fn collection_export(&self, _query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("collection_export"));
+    }
+
+    async fn collection_export_partitioned(&self, _query: &CollectionQuery, _partitions: usize) -> Result<Vec<BoxStream<'static, Result<DocumentData, ConnectException>>>, ConnectException> {
+        return Err(self.unsupported("collection_export_partitioned"));
+    }
+
+    async fn collection_import(&self, _query: &CollectionQuery, _documents: Vec<String>) -> Result<ImportIntegrity, ConnectException> {
+        return Err(self.unsupported("collection_import"));
+    }
+
+    async fn collection_import_preview(&self, _query: &CollectionQuery, _documents: Vec<String>) -> Result<ImportPreview, ConnectException> {
+        return Err(self.unsupported("collection_import_preview"));
+    }
+
+    async fn collection_dump(&self, _query: &CollectionQuery, _path: &Path) -> Result<DumpReport, ConnectException> {
+        return Err(self.unsupported("collection_dump"));
+    }
+
+    async fn collection_restore(&self, _query: &CollectionQuery, _path: &Path) -> Result<DumpReport, ConnectException> {
+        return Err(self.unsupported("collection_restore"));
+    }
+
+    async fn find_all(&self, _query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
+        return Err(self.unsupported("find_all"));
+    }
+
+    async fn find_query(&self, _query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
+        return Err(self.unsupported("find_query"));
+    }
+
+    async fn find_page(&self, _query: &DocumentQuery, _offset: u64, _limit: u64) -> Result<CollectionData, ConnectException> {
+        return Err(self.unsupported("find_page"));
+    }
+
+    async fn find(&self, _query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException> {
+        return Err(self.unsupported("find"));
+    }
+
+    async fn count(&self, _query: &DocumentQuery) -> Result<u64, ConnectException> {
+        return Err(self.unsupported("count"));
+    }
+
+    async fn document_exists(&self, _query: &DocumentQuery) -> Result<bool, ConnectException> {
+        return Err(self.unsupported("document_exists"));
+    }
+
+    async fn document_size(&self, _query: &DocumentQuery) -> Result<DocumentSize, ConnectException> {
+        return Err(self.unsupported("document_size"));
+    }
+
+    async fn find_stream(&self, _query: &DocumentQuery) -> Result<BoxStream<'static, Result<DocumentData, ConnectException>>, ConnectException> {
+        return Err(self.unsupported("find_stream"));
+    }
+
+    async fn schema(&self, _query: &CollectionQuery) -> Result<DocumentSchema, ConnectException> {
+        return Err(self.unsupported("schema"));
+    }
+
+    async fn insert(&self, _query: &CollectionQuery, _value: &str) -> Result<DocumentData, ConnectException> {
+        return Err(self.unsupported("insert"));
+    }
+
+    async fn insert_many(&self, _query: &CollectionQuery, _values: Vec<String>, _batch_size: usize) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        return Err(self.unsupported("insert_many"));
+    }
+
+    async fn update(&self, _query: &DocumentQuery, _value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("update"));
+    }
+
+    async fn update_transaction(&self, _query: &DocumentQuery, _value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("update_transaction"));
+    }
+
+    async fn patch(&self, _query: &DocumentQuery, _fields: Vec<DocumentUpdateField>) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("patch"));
+    }
+
+    async fn bulk_set(&self, _query: &DocumentQuery, _spec: &UpdateSpec) -> Result<UpdateSummary, ConnectException> {
+        return Err(self.unsupported("bulk_set"));
+    }
+
+    async fn bulk_set_preview(&self, _query: &DocumentQuery, _spec: &UpdateSpec) -> Result<UpdatePreview, ConnectException> {
+        return Err(self.unsupported("bulk_set_preview"));
+    }
+
+    async fn upsert(&self, _query: &DocumentQuery, _value: &str) -> Result<DocumentData, ConnectException> {
+        return Err(self.unsupported("upsert"));
+    }
+
+    async fn update_revision(&self, _query: &DocumentQuery, _value: &str, _revision: i64) -> Result<DocumentData, ConnectException> {
+        return Err(self.unsupported("update_revision"));
+    }
+
+    async fn delete(&self, _query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("delete"));
+    }
+
+    async fn delete_transaction(&self, _query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("delete_transaction"));
+    }
+
+    async fn delete_preview(&self, _query: &DocumentQuery) -> Result<DocumentDeletePreview, ConnectException> {
+        return Err(self.unsupported("delete_preview"));
+    }
+
+    async fn aggregate_raw(&self, _query: &CollectionQuery, _pipeline_json: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("aggregate_raw"));
+    }
+
+    async fn move_documents(&self, _query: &DocumentQuery, _target_collection: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        return Err(self.unsupported("move_documents"));
+    }
+
+}
+
+#[async_trait]
+impl IDBMaintenance for UnsupportedRepository {
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{commons::exception::e_connect_error_kind::EConnectErrorKind, domain::connection_data::ConnectionData, infrastructure::repository::{e_db_repository::EDBRepository, i_db_repository::IDBRepository}};
+
+    use super::UnsupportedRepository;
+
+    #[tokio::test]
+    async fn operations_report_unsupported() {
+        let connection = ConnectionData::new(EDBRepository::MySQL, String::from("mysql://localhost:3306"));
+        let repository = UnsupportedRepository::new(&connection);
+
+        let error = repository.status().await.unwrap_err();
+
+        assert!(error.is(EConnectErrorKind::UNSUPPORTED));
+        assert!(error.message().contains("MySQL"));
+    }
+
+    #[test]
+    fn backend_names_round_trip() {
+        for repository in EDBRepository::items() {
+            let parsed = EDBRepository::from_string(&repository.to_string()).expect("Backend name should parse");
+            assert_eq!(parsed.to_string(), repository.to_string());
+        }
+    }
+
+}
\ No newline at end of file
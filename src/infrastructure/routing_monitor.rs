@@ -0,0 +1,50 @@
+use std::{collections::VecDeque, sync::{Arc, Mutex}};
+
+use crate::domain::routing::routing_event::RoutingEvent;
+
+const DEFAULT_CAPACITY: usize = 100;
+
+#[derive(Clone)]
+pub struct RoutingMonitor {
+    capacity: usize,
+    events: Arc<Mutex<VecDeque<RoutingEvent>>>
+}
+
+impl Default for RoutingMonitor {
+
+    fn default() -> Self {
+        RoutingMonitor::new(DEFAULT_CAPACITY)
+    }
+
+}
+
+impl RoutingMonitor {
+
+    pub fn new(capacity: usize) -> RoutingMonitor {
+        RoutingMonitor {
+            capacity: capacity.max(1),
+            events: Arc::new(Mutex::new(VecDeque::new()))
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        return self.capacity;
+    }
+
+    pub fn push(&self, event: RoutingEvent) {
+        let mut events = self.events.lock().expect("Could not lock mutex");
+        if events.len() >= self.capacity {
+            events.pop_front();
+        }
+        events.push_back(event);
+    }
+
+    pub fn events(&self) -> Vec<RoutingEvent> {
+        return self.events.lock().expect("Could not lock mutex").iter().cloned().collect();
+    }
+
+    pub fn drain(&self) -> Vec<RoutingEvent> {
+        return self.events.lock().expect("Could not lock mutex").drain(..).collect();
+    }
+
+}
\ No newline at end of file
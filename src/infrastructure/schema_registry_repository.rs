@@ -0,0 +1,165 @@
+use std::{fs, path::PathBuf};
+
+use serde_json::{json, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::document::{document_schema::DocumentSchema, schema_field::SchemaField, schema_registry::SchemaRegistry, schema_version::SchemaVersion}};
+
+const SCHEMAS_KEY: &str = "schemas";
+
+#[derive(Clone)]
+pub struct SchemaRegistryRepository {
+    path: PathBuf
+}
+
+impl SchemaRegistryRepository {
+
+    pub fn new(path: PathBuf) -> SchemaRegistryRepository {
+        SchemaRegistryRepository { path }
+    }
+
+    pub fn path(&self) -> PathBuf {
+        return self.path.clone();
+    }
+
+    pub fn load(&self) -> Result<SchemaRegistry, ConnectException> {
+        let mut registry = SchemaRegistry::new();
+        if !self.path.exists() {
+            return Ok(registry);
+        }
+
+        let content = fs::read_to_string(&self.path);
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&content.unwrap());
+        if let Err(error) = json {
+            return Err(ConnectException::from(error));
+        }
+
+        let json = json.unwrap();
+        let Some(items) = json.get(SCHEMAS_KEY).and_then(|s| s.as_array()) else {
+            let exception = ConnectException::new(format!("Schema registry file '{}' has no '{}' list.", self.path.display(), SCHEMAS_KEY));
+            return Err(exception);
+        };
+
+        for item in items {
+            let data_base = self.string_field(item, "data_base")?;
+            let collection = self.string_field(item, "collection")?;
+            registry.restore(&data_base, &collection, self.version_from_json(item)?)?;
+        }
+
+        return Ok(registry);
+    }
+
+    pub fn save(&self, registry: &SchemaRegistry) -> Result<(), ConnectException> {
+        let mut items = Vec::new();
+        for (data_base, collection) in registry.collections() {
+            for version in registry.versions(&data_base, &collection) {
+                items.push(self.version_as_json(&data_base, &collection, &version));
+            }
+        }
+
+        let content = serde_json::to_string_pretty(&json!({ SCHEMAS_KEY: items }));
+        if let Err(error) = content {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Some(parent) = self.path.parent() {
+            if let Err(error) = fs::create_dir_all(parent) {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        let temporal = self.path.with_extension("tmp");
+        if let Err(error) = fs::write(&temporal, content.unwrap()) {
+            return Err(ConnectException::from(error));
+        }
+
+        if let Err(error) = fs::rename(&temporal, &self.path) {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(());
+    }
+
+    fn version_as_json(&self, data_base: &str, collection: &str, version: &SchemaVersion) -> Value {
+        let schema = version.schema();
+        let inferred: Vec<Value> = schema.inferred().iter()
+            .map(|f| json!({
+                "path": f.path(),
+                "types": f.types(),
+                "occurrences": f.occurrences(),
+                "optional": f.is_optional(),
+                "examples": f.examples()
+            }))
+            .collect();
+
+        return json!({
+            "data_base": data_base,
+            "collection": collection,
+            "version": version.version(),
+            "registered_at": version.registered_at() as u64,
+            "schema": {
+                "comments": schema.comments(),
+                "strict": schema.is_strict(),
+                "validation": schema.validation(),
+                "inferred": inferred,
+                "sample_size": schema.sample_size()
+            }
+        });
+    }
+
+    fn version_from_json(&self, json: &Value) -> Result<SchemaVersion, ConnectException> {
+        let Some(version) = json.get("version").and_then(|v| v.as_u64()).and_then(|v| u32::try_from(v).ok()) else {
+            return Err(ConnectException::new(String::from("Schema registry field 'version' is missing or not a number.")));
+        };
+
+        let registered_at = json.get("registered_at").and_then(|r| r.as_u64()).unwrap_or_default() as u128;
+
+        let Some(schema) = json.get("schema").and_then(|s| s.as_object()) else {
+            return Err(ConnectException::new(format!("Schema registry version {} has no schema object.", version)));
+        };
+
+        let comments = schema.get("comments").and_then(|c| c.as_array())
+            .map(|c| c.iter().filter_map(|c| c.as_str()).map(String::from).collect())
+            .unwrap_or_default();
+        let strict = schema.get("strict").and_then(|s| s.as_bool()).unwrap_or_default();
+
+        let mut document_schema = DocumentSchema::new(comments, strict, Vec::new());
+
+        if let Some(validation) = schema.get("validation").filter(|v| !v.is_null()) {
+            document_schema.set_validation(validation.clone());
+        }
+
+        let mut inferred = Vec::new();
+        for field in schema.get("inferred").and_then(|i| i.as_array()).cloned().unwrap_or_default() {
+            inferred.push(SchemaField::new(
+                self.string_field(&field, "path")?,
+                self.strings_field(&field, "types"),
+                field.get("occurrences").and_then(|o| o.as_u64()).unwrap_or_default() as usize,
+                field.get("optional").and_then(|o| o.as_bool()).unwrap_or_default(),
+                self.strings_field(&field, "examples")
+            ));
+        }
+
+        let sample_size = schema.get("sample_size").and_then(|s| s.as_u64()).unwrap_or_default() as usize;
+        document_schema.set_inferred(inferred, sample_size);
+
+        return Ok(SchemaVersion::new(version, document_schema, registered_at));
+    }
+
+    fn string_field(&self, json: &Value, key: &str) -> Result<String, ConnectException> {
+        let Some(value) = json.get(key).and_then(|v| v.as_str()) else {
+            return Err(ConnectException::new(format!("Schema registry field '{}' is missing or not a string.", key)));
+        };
+        return Ok(String::from(value));
+    }
+
+    fn strings_field(&self, json: &Value, key: &str) -> Vec<String> {
+        return json.get(key).and_then(|v| v.as_array())
+            .map(|v| v.iter().filter_map(|v| v.as_str()).map(String::from).collect())
+            .unwrap_or_default();
+    }
+
+}
\ No newline at end of file
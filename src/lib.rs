@@ -1,12 +1,22 @@
 pub mod commons {
     pub mod configuration {
         pub mod definition {
+            pub mod help;
             pub mod mongo_db;
         }
         pub mod configuration;
     }
     pub mod exception {
         pub mod connect_exception;
+        pub mod e_connect_error_kind;
+        pub mod error_explainer;
+        pub mod error_explanation;
+    }
+    pub mod secret {
+        pub mod aes_gcm_secret_store;
+        pub mod i_secret_store;
+        pub mod passphrase;
+        pub mod signature;
     }
     pub mod utils;
 }
@@ -15,30 +25,93 @@ pub mod infrastructure {
         pub mod mongo_db {
             pub mod e_action;
             pub mod extractor_metadata_mongo_db;
+            pub mod mongo_db_maintenance;
             pub mod mongo_db_repository;
+            pub mod mongo_db_router;
             pub mod mongo_utils;
         }
         pub mod db_dictionary;
+        pub mod db_repository;
         pub mod e_db_repository;
+        pub mod i_db_maintenance;
         pub mod i_db_repository;
+        pub mod i_metadata_extractor;
+        pub mod unsupported_repository;
     }
+    pub mod connection_pool;
+    pub mod connection_profile_repository;
     pub mod db_service_lite;
     pub mod db_service;
+    pub mod export_manifest_repository;
+    pub mod preferences_repository;
+    pub mod query_history_repository;
+    pub mod query_library_repository;
+    pub mod schema_registry_repository;
+    pub mod routing_monitor;
 }
 pub mod domain {
+    pub mod avro {
+        pub mod avro_container;
+        pub mod avro_field;
+        pub mod avro_schema;
+        pub mod e_avro_type;
+    }
     pub mod collection {
         pub mod collection_data;
         pub mod collection_definition;
+        pub mod collection_index;
+        pub mod collection_option;
+        pub mod collection_option_definition;
+        pub mod collection_validator;
+        pub mod copy_options;
+        pub mod copy_report;
+        pub mod dump_report;
+        pub mod e_conflict_policy;
+        pub mod e_import_action;
+        pub mod e_import_mode;
+        pub mod e_validation_action;
+        pub mod e_validation_level;
         pub mod generate_collection_query;
+        pub mod import_failure;
+        pub mod import_integrity;
+        pub mod import_options;
+        pub mod import_preview;
+        pub mod import_preview_entry;
+        pub mod import_report;
+        pub mod import_worker_report;
+        pub mod index_suggestion;
+        pub mod maintenance_report;
     }
     pub mod data_base {
         pub mod generate_database_query;
     }
     pub mod document {
         pub mod document_data;
+        pub mod document_delete_preview;
+        pub mod document_field_size;
+        pub mod document_insert_result;
         pub mod document_key;
         pub mod document_key_attribute;
+        pub mod document_origin;
         pub mod document_schema;
+        pub mod document_size;
+        pub mod document_update_field;
+        pub mod e_id_strategy;
+        pub mod e_merge_strategy;
+        pub mod lineage_policy;
+        pub mod merge;
+        pub mod schema_field;
+        pub mod schema_registry;
+        pub mod schema_validator;
+        pub mod schema_version;
+        pub mod schema_version_report;
+        pub mod schema_violation;
+        pub mod soft_delete_policy;
+    }
+    pub mod environment {
+        pub mod e_environment_action;
+        pub mod environment_definition;
+        pub mod environment_policy;
     }
     pub mod field {
         pub mod definition {
@@ -58,17 +131,90 @@ pub mod domain {
         pub mod data_base_query;
         pub mod document_query;
         pub mod e_filter_category;
+        pub mod e_filter_operator;
         pub mod filter_value;
         pub mod filter_value_attribute;
         pub mod filter_element;
+        pub mod filter_snippets;
+        pub mod sort_field;
+    }
+    pub mod help {
+        pub mod help_catalog;
+        pub mod help_entry;
+    }
+    pub mod history {
+        pub mod e_history_action;
+        pub mod history_entry;
+        pub mod query_history;
+    }
+    pub mod library {
+        pub mod change_event;
+        pub mod e_change_kind;
+        pub mod e_saved_query_kind;
+        pub mod result_snapshot;
+        pub mod saved_query;
+    }
+    pub mod mapping {
+        pub mod document_mapping;
+        pub mod document_mapping_preview;
+        pub mod e_mapping_operation;
+        pub mod field_mapping;
+    }
+    pub mod manifest {
+        pub mod e_export_kind;
+        pub mod export_manifest;
+        pub mod manifest_check;
+        pub mod manifest_file;
+    }
+    pub mod metadata {
+        pub mod collection_metadata;
+        pub mod data_base_metadata;
+        pub mod server_metadata;
+    }
+    pub mod preference {
+        pub mod e_preference_layer;
+        pub mod preferences;
+        pub mod query_options;
+    }
+    pub mod promote {
+        pub mod promote_item;
+        pub mod promote_item_report;
+        pub mod promote_options;
+        pub mod promote_report;
+    }
+    pub mod routing {
+        pub mod e_routing_event_kind;
+        pub mod routing_event;
+        pub mod routing_policy;
     }
     pub mod table {
+        pub mod e_table_export_format;
+        pub mod table_data_export;
         pub mod table_data_field;
         pub mod table_data_group;
     }
+    pub mod update {
+        pub mod update_preview;
+        pub mod update_spec;
+        pub mod update_summary;
+    }
+    pub mod e_auth_mechanism;
     pub mod e_json_type;
+    pub mod e_read_preference;
+    pub mod connection_credential;
     pub mod connection_data;
+    pub mod connection_profile;
+    pub mod connection_tls;
+    pub mod health_score;
+    pub mod host_latency;
+    pub mod server_time;
+    pub mod session_state;
+    pub mod session_tab;
 }
 pub mod service {
+    pub mod preferences_service;
+    pub mod profile_service;
+    pub mod query_library_service;
     pub mod service;
+    pub mod session_manager;
 }
\ No newline at end of file
@@ -0,0 +1,141 @@
+use serde_json::{Map, Value};
+
+use crate::{commons::exception::connect_exception::ConnectException, domain::preference::{e_preference_layer::EPreferenceLayer, preferences::{Preferences, QUERY_OPTIONS}, query_options::QueryOptions}, infrastructure::preferences_repository::PreferencesRepository};
+
+#[derive(Clone)]
+pub struct PreferencesService {
+    preferences: Preferences,
+    workspace: Option<PreferencesRepository>,
+    user: Option<PreferencesRepository>
+}
+
+impl PreferencesService {
+
+    pub fn new() -> PreferencesService {
+        PreferencesService::default()
+    }
+
+    pub fn from(workspace: Option<PreferencesRepository>, user: Option<PreferencesRepository>) -> Result<PreferencesService, ConnectException> {
+        let mut service = PreferencesService {
+            preferences: Preferences::new(),
+            workspace,
+            user
+        };
+        service.reload()?;
+        return Ok(service);
+    }
+
+    pub fn reload(&mut self) -> Result<&mut PreferencesService, ConnectException> {
+        let workspace = match &self.workspace {
+            Some(repository) => repository.load()?,
+            None => Map::new(),
+        };
+        let user = match &self.user {
+            Some(repository) => repository.load()?,
+            None => Map::new(),
+        };
+
+        self.preferences.set_layer(&EPreferenceLayer::WORKSPACE, workspace);
+        self.preferences.set_layer(&EPreferenceLayer::USER, user);
+
+        return Ok(self);
+    }
+
+    pub fn preferences(&self) -> Preferences {
+        return self.preferences.clone();
+    }
+
+    pub fn get(&self, key: &str) -> Option<Value> {
+        return self.preferences.get(key);
+    }
+
+    pub fn source(&self, key: &str) -> Option<EPreferenceLayer> {
+        return self.preferences.source(key);
+    }
+
+    pub fn set(&mut self, layer: &EPreferenceLayer, key: &str, value: Value) -> Result<&mut PreferencesService, ConnectException> {
+        if *layer == EPreferenceLayer::DEFAULT {
+            return Err(ConnectException::new(String::from("Built-in preference defaults cannot be modified.")));
+        }
+
+        self.preferences.set(layer, key, value);
+        self.persist(layer)?;
+
+        return Ok(self);
+    }
+
+    pub fn unset(&mut self, layer: &EPreferenceLayer, key: &str) -> Result<Option<Value>, ConnectException> {
+        if *layer == EPreferenceLayer::DEFAULT {
+            return Err(ConnectException::new(String::from("Built-in preference defaults cannot be modified.")));
+        }
+
+        let value = self.preferences.unset(layer, key);
+        self.persist(layer)?;
+
+        return Ok(value);
+    }
+
+    pub fn query_options(&self, data_base: &str, collection: &str) -> Option<QueryOptions> {
+        return self.preferences.query_options(data_base, collection);
+    }
+
+    pub fn set_query_options(&mut self, layer: &EPreferenceLayer, data_base: &str, collection: &str, options: &QueryOptions) -> Result<&mut PreferencesService, ConnectException> {
+        if *layer == EPreferenceLayer::DEFAULT {
+            return Err(ConnectException::new(String::from("Built-in preference defaults cannot be modified.")));
+        }
+
+        options.validate()?;
+
+        self.preferences.set_query_options(layer, data_base, collection, options);
+        self.persist(layer)?;
+
+        return Ok(self);
+    }
+
+    pub fn unset_query_options(&mut self, layer: &EPreferenceLayer, data_base: &str, collection: &str) -> Result<Option<QueryOptions>, ConnectException> {
+        if *layer == EPreferenceLayer::DEFAULT {
+            return Err(ConnectException::new(String::from("Built-in preference defaults cannot be modified.")));
+        }
+
+        if self.preferences.layer(layer).get(QUERY_OPTIONS).is_none() {
+            return Ok(None);
+        }
+
+        let options = self.preferences.unset_query_options(layer, data_base, collection);
+        self.persist(layer)?;
+
+        return Ok(options);
+    }
+
+    pub fn clear_session(&mut self) -> &mut PreferencesService {
+        self.preferences.clear_session();
+        return self;
+    }
+
+    fn persist(&self, layer: &EPreferenceLayer) -> Result<(), ConnectException> {
+        let repository = match layer {
+            EPreferenceLayer::WORKSPACE => &self.workspace,
+            EPreferenceLayer::USER => &self.user,
+            _ => return Ok(()),
+        };
+
+        if let Some(repository) = repository {
+            repository.save(&self.preferences.layer(layer))?;
+        }
+
+        return Ok(());
+    }
+
+}
+
+impl Default for PreferencesService {
+
+    fn default() -> Self {
+        PreferencesService {
+            preferences: Preferences::new(),
+            workspace: None,
+            user: None
+        }
+    }
+
+}
\ No newline at end of file
@@ -0,0 +1,65 @@
+use crate::{commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind}, domain::{connection_profile::ConnectionProfile, document::document_origin::DocumentOrigin}, infrastructure::{connection_profile_repository::ConnectionProfileRepository, repository::{db_dictionary, i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository}}};
+
+use super::service::Service;
+
+#[derive(Clone)]
+pub struct ProfileService {
+    repository: ConnectionProfileRepository
+}
+
+impl ProfileService {
+
+    pub fn from(repository: ConnectionProfileRepository) -> ProfileService {
+        ProfileService { repository }
+    }
+
+    pub fn list(&self) -> Result<Vec<ConnectionProfile>, ConnectException> {
+        return self.repository.find_all();
+    }
+
+    pub fn list_tagged(&self, tag: &str) -> Result<Vec<ConnectionProfile>, ConnectException> {
+        let profiles = self.repository.find_all()?;
+        return Ok(profiles.into_iter().filter(|p| p.has_tag(tag)).collect());
+    }
+
+    pub fn find(&self, alias: &str) -> Result<ConnectionProfile, ConnectException> {
+        let Some(profile) = self.repository.find(alias)? else {
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, format!("Profile '{}' not found.", alias)));
+        };
+        return Ok(profile);
+    }
+
+    pub fn add(&self, profile: ConnectionProfile) -> Result<ConnectionProfile, ConnectException> {
+        if self.repository.find(&profile.alias())?.is_some() {
+            return Err(ConnectException::new(format!("Profile '{}' already exists.", profile.alias())));
+        }
+        self.repository.save(&profile)?;
+        return Ok(profile);
+    }
+
+    pub fn update(&self, profile: ConnectionProfile) -> Result<ConnectionProfile, ConnectException> {
+        self.find(&profile.alias())?;
+        self.repository.save(&profile)?;
+        return Ok(profile);
+    }
+
+    pub fn remove(&self, alias: &str) -> Result<ConnectionProfile, ConnectException> {
+        let profile = self.find(alias)?;
+        self.repository.delete(alias)?;
+        return Ok(profile);
+    }
+
+    pub async fn test(&self, alias: &str) -> Result<(), ConnectException> {
+        let profile = self.find(alias)?;
+        let repository = db_dictionary::find(&profile.connection_data()).await?;
+        return repository.status().await;
+    }
+
+    pub async fn instance(&self, alias: &str) -> Result<Service<impl IDBRepository + IDBMaintenance>, ConnectException> {
+        let profile = self.find(alias)?;
+        let repository = db_dictionary::find(&profile.connection_data()).await?;
+        let origin = DocumentOrigin::new(profile.alias(), profile.category());
+        return Ok(Service::from_origin(repository, origin));
+    }
+
+}
\ No newline at end of file
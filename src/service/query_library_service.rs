@@ -0,0 +1,150 @@
+use std::{collections::VecDeque, time::{Duration, SystemTime, UNIX_EPOCH}};
+
+use futures_util::{stream::{self, BoxStream}, StreamExt};
+use serde_json::Value;
+
+use crate::{commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind}, domain::{collection::collection_data::CollectionData, filter::{collection_query::CollectionQuery, document_query::DocumentQuery, filter_element::FilterElement}, library::{change_event::ChangeEvent, e_change_kind::EChangeKind, e_saved_query_kind::ESavedQueryKind, result_snapshot::ResultSnapshot, saved_query::SavedQuery}}, infrastructure::{query_library_repository::QueryLibraryRepository, repository::i_db_repository::IDBRepository}};
+
+use super::service::Service;
+
+#[derive(Clone)]
+pub struct QueryLibraryService {
+    repository: QueryLibraryRepository
+}
+
+impl QueryLibraryService {
+
+    pub fn from(repository: QueryLibraryRepository) -> QueryLibraryService {
+        QueryLibraryService { repository }
+    }
+
+    pub fn list(&self, connection: &str, data_base: &str, collection: &str) -> Result<Vec<SavedQuery>, ConnectException> {
+        let queries = self.repository.find_all()?;
+        return Ok(queries.into_iter().filter(|q| q.belongs_to(connection, data_base, collection)).collect());
+    }
+
+    pub fn list_connection(&self, connection: &str) -> Result<Vec<SavedQuery>, ConnectException> {
+        let queries = self.repository.find_all()?;
+        return Ok(queries.into_iter().filter(|q| q.connection() == connection).collect());
+    }
+
+    pub fn find(&self, connection: &str, data_base: &str, collection: &str, name: &str) -> Result<SavedQuery, ConnectException> {
+        let Some(query) = self.repository.find(connection, data_base, collection, name)? else {
+            let message = format!("Saved query '{}' not found for '{}.{}' on '{}'.", name, data_base, collection, connection);
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, message));
+        };
+        return Ok(query);
+    }
+
+    pub fn add(&self, query: SavedQuery) -> Result<SavedQuery, ConnectException> {
+        if self.repository.find(&query.connection(), &query.data_base(), &query.collection(), &query.name())?.is_some() {
+            return Err(ConnectException::new(format!("Saved query '{}' already exists.", query.name())));
+        }
+        self.validate(&query)?;
+        self.repository.save(&query)?;
+        return Ok(query);
+    }
+
+    pub fn update(&self, query: SavedQuery) -> Result<SavedQuery, ConnectException> {
+        self.find(&query.connection(), &query.data_base(), &query.collection(), &query.name())?;
+        self.validate(&query)?;
+        self.repository.save(&query)?;
+        return Ok(query);
+    }
+
+    pub fn remove(&self, connection: &str, data_base: &str, collection: &str, name: &str) -> Result<SavedQuery, ConnectException> {
+        let query = self.find(connection, data_base, collection, name)?;
+        self.repository.delete(&query)?;
+        return Ok(query);
+    }
+
+    pub async fn run<T: IDBRepository>(&self, service: &Service<T>, query: &SavedQuery, target: &CollectionQuery) -> Result<CollectionData, ConnectException> {
+        match query.kind() {
+            ESavedQueryKind::FILTER => {
+                let filter = FilterElement::query(format!("{{\"$match\": {}}}", query.body()), Vec::new());
+                let query = DocumentQuery::from_filter(target.data_base(), target.collection(), filter);
+                return service.find_query(&query).await;
+            },
+            ESavedQueryKind::PIPELINE => {
+                let documents = service.aggregate_raw(target, &query.body()).await?;
+                return Ok(CollectionData::new(documents.len(), None, None, documents));
+            },
+        }
+    }
+
+    /// Polls the saved query every `interval` and emits the documents that entered or
+    /// left its result. The first run only sets the baseline; failed runs are emitted
+    /// as errors and polling continues.
+    pub fn subscribe<T: IDBRepository + 'static>(&self, service: Service<T>, query: SavedQuery, target: CollectionQuery, interval: Duration) -> BoxStream<'static, Result<ChangeEvent, ConnectException>> {
+        let state = (self.clone(), service, None::<ResultSnapshot>, VecDeque::new(), false);
+        return stream::unfold(state, move |(library, service, mut snapshot, mut pending, mut polled)| {
+            let query = query.clone();
+            let target = target.clone();
+            async move {
+                loop {
+                    if let Some(event) = pending.pop_front() {
+                        return Some((event, (library, service, snapshot, pending, polled)));
+                    }
+
+                    if polled {
+                        tokio::time::sleep(interval).await;
+                    }
+                    polled = true;
+
+                    let data = match library.run(&service, &query, &target).await {
+                        Ok(data) => data,
+                        Err(error) => {
+                            pending.push_back(Err(error));
+                            continue;
+                        },
+                    };
+
+                    let next = ResultSnapshot::from(data.documents());
+                    if let Some(previous) = &snapshot {
+                        let timestamp = Self::timestamp();
+                        let (added, removed) = previous.diff(&next);
+                        for document in removed {
+                            pending.push_back(Ok(ChangeEvent::new(EChangeKind::REMOVED, query.name(), document, timestamp)));
+                        }
+                        for document in added {
+                            pending.push_back(Ok(ChangeEvent::new(EChangeKind::ADDED, query.name(), document, timestamp)));
+                        }
+                    }
+                    snapshot = Some(next);
+                }
+            }
+        }).boxed();
+    }
+
+    fn timestamp() -> u128 {
+        return SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
+    }
+
+    fn validate(&self, query: &SavedQuery) -> Result<(), ConnectException> {
+        let json: Result<Value, serde_json::Error> = serde_json::from_str(&query.body());
+        if let Err(error) = json {
+            let message = format!("Saved query '{}' has an invalid body: {}", query.name(), error);
+            return Err(ConnectException::new(message));
+        }
+
+        let valid = match query.kind() {
+            ESavedQueryKind::FILTER => json.unwrap().is_object(),
+            ESavedQueryKind::PIPELINE => json.unwrap().as_array().map(|s| s.iter().all(|s| s.is_object())).unwrap_or(false),
+        };
+
+        if !valid {
+            let expected = match query.kind() {
+                ESavedQueryKind::FILTER => "a filter document",
+                ESavedQueryKind::PIPELINE => "an array of stage documents",
+            };
+            let message = format!("Saved query '{}' must be {}.", query.name(), expected);
+            return Err(ConnectException::new(message));
+        }
+
+        return Ok(());
+    }
+
+}
\ No newline at end of file
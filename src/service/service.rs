@@ -1,26 +1,335 @@
+use std::{cmp::Reverse, future::Future, path::{Path, PathBuf}, sync::Arc, time::{Instant, SystemTime, UNIX_EPOCH}};
+
+use futures_util::{future::try_join_all, stream::BoxStream, StreamExt};
+use serde_json::Value;
+use tokio::{fs::File, io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, BufWriter}, sync::{mpsc, Mutex, OwnedSemaphorePermit, Semaphore}};
+
 use crate::{
-    commons::exception::connect_exception::ConnectException,
+    commons::exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind},
     domain::{
+        avro::{avro_container::AvroContainer, avro_schema::AvroSchema},
         collection::{
-            collection_data::CollectionData, collection_definition::CollectionDefinition, generate_collection_query::GenerateCollectionQuery
+            collection_data::CollectionData, collection_definition::CollectionDefinition, collection_index::CollectionIndex, collection_validator::CollectionValidator, copy_options::CopyOptions, copy_report::CopyReport, dump_report::DumpReport, e_conflict_policy::EConflictPolicy, e_import_action::EImportAction, e_import_mode::EImportMode, generate_collection_query::GenerateCollectionQuery, import_integrity::ImportIntegrity, import_options::ImportOptions, import_preview::ImportPreview, import_report::ImportReport, import_worker_report::ImportWorkerReport, index_suggestion::IndexSuggestion, maintenance_report::MaintenanceReport
         },
         data_base::generate_database_query::GenerateDatabaseQuery,
-        document::{document_data::DocumentData, document_schema::DocumentSchema},
-        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery},
-        table::table_data_group::TableDataGroup,
+        environment::{e_environment_action::EEnvironmentAction, environment_definition::Environment},
+        document::{document_data::DocumentData, document_delete_preview::DocumentDeletePreview, document_insert_result::DocumentInsertResult, document_origin::DocumentOrigin, document_schema::DocumentSchema, document_size::DocumentSize, document_update_field::DocumentUpdateField, schema_registry::{SchemaRegistry, SCHEMA_FIELD}, lineage_policy::LineagePolicy, schema_validator::SchemaValidator, schema_version_report::SchemaVersionReport, schema_violation::SchemaViolation, soft_delete_policy::SoftDeletePolicy},
+        filter::{collection_query::CollectionQuery, data_base_query::DataBaseQuery, document_query::DocumentQuery, e_filter_operator::EFilterOperator, filter_element::FilterElement, filter_snippets::FilterSnippets, filter_value::FilterValue},
+        mapping::{document_mapping::DocumentMapping, document_mapping_preview::DocumentMappingPreview},
+        preference::{preferences::Preferences, query_options::QueryOptions},
+        health_score::HealthScore,
+        history::{e_history_action::EHistoryAction, history_entry::HistoryEntry, query_history::QueryHistory},
+        promote::{promote_item::PromoteItem, promote_item_report::PromoteItemReport, promote_options::PromoteOptions, promote_report::PromoteReport},
+        host_latency::HostLatency,
+        manifest::{e_export_kind::EExportKind, export_manifest::ExportManifest, manifest_check::ManifestCheck},
+        metadata::{collection_metadata::CollectionMetadata, data_base_metadata::DataBaseMetadata, server_metadata::ServerMetadata},
+        routing::routing_event::RoutingEvent,
+        server_time::ServerTime,
+        table::{e_table_export_format::ETableExportFormat, table_data_export::TableDataExport, table_data_group::TableDataGroup},
+        update::{update_preview::UpdatePreview, update_spec::UpdateSpec, update_summary::UpdateSummary},
     },
-    infrastructure::repository::i_db_repository::IDBRepository,
+    infrastructure::{export_manifest_repository, repository::{i_db_maintenance::IDBMaintenance, i_db_repository::IDBRepository}},
 };
 
+const BUDGET_UNIT: usize = 1024;
+const HIGH_LATENCY: i64 = 100;
+const CRITICAL_LATENCY: i64 = 250;
+const HIGH_SKEW: i64 = 1000;
+const CRITICAL_SKEW: i64 = 5000;
+
 #[derive(Clone)]
 pub struct Service<T: IDBRepository> {
-    repository: T,
+    repository: Arc<T>,
+    origin: Option<DocumentOrigin>,
+    validator: Option<Arc<SchemaValidator>>,
+    registry: Option<Arc<SchemaRegistry>>,
+    job_id: Option<String>,
+    snippets: Option<Arc<FilterSnippets>>,
+    preferences: Option<Arc<Preferences>>,
+    history: Arc<Mutex<QueryHistory>>,
 }
 
 impl <T: IDBRepository> Service<T> {
 
     pub fn from(repository: T) -> Service<T> {
-        Service { repository }
+        Service { repository: Arc::new(repository), origin: None, validator: None, registry: None, job_id: None, snippets: None, preferences: None, history: Arc::new(Mutex::new(QueryHistory::default())) }
+    }
+
+    pub fn from_origin(repository: T, origin: DocumentOrigin) -> Service<T> {
+        Service { repository: Arc::new(repository), origin: Some(origin), validator: None, registry: None, job_id: None, snippets: None, preferences: None, history: Arc::new(Mutex::new(QueryHistory::default())) }
+    }
+
+    pub fn origin(&self) -> Option<DocumentOrigin> {
+        return self.origin.clone();
+    }
+
+    pub fn set_validator(&mut self, validator: SchemaValidator) -> &mut Self {
+        self.validator = Some(Arc::new(validator));
+        return self;
+    }
+
+    pub fn validate(&self, data_base: &str, collection: &str, value: &str) -> Result<Vec<SchemaViolation>, ConnectException> {
+        return match &self.validator {
+            Some(validator) => validator.validate(data_base, collection, value),
+            None => Ok(Vec::new()),
+        };
+    }
+
+    pub fn set_schema_registry(&mut self, registry: SchemaRegistry) -> &mut Self {
+        self.registry = Some(Arc::new(registry));
+        return self;
+    }
+
+    fn prepare_document(&self, query: &CollectionQuery, value: &str) -> Result<String, ConnectException> {
+        let value = match &self.registry {
+            Some(registry) => registry.claim(&query.data_base(), &query.collection(), value)?,
+            None => String::from(value),
+        };
+        return self.stamp(&value);
+    }
+
+    fn prepare_documents(&self, query: &CollectionQuery, values: Vec<String>) -> Result<Vec<String>, ConnectException> {
+        return values.iter()
+            .map(|v| self.prepare_document(query, v))
+            .collect();
+    }
+
+    pub fn set_job_id(&mut self, job_id: String) -> &mut Self {
+        self.job_id = Some(job_id);
+        return self;
+    }
+
+    pub fn lineage(&self) -> Option<LineagePolicy> {
+        let mut lineage = self.repository.lineage()?;
+        if let Some(job_id) = &self.job_id {
+            lineage.set_job_id(job_id.clone());
+        }
+        return Some(lineage);
+    }
+
+    fn stamp(&self, value: &str) -> Result<String, ConnectException> {
+        return match self.lineage() {
+            Some(lineage) => lineage.stamp(value, Self::timestamp()),
+            None => Ok(String::from(value)),
+        };
+    }
+
+    fn stamp_spec(&self, spec: &UpdateSpec) -> UpdateSpec {
+        return match self.lineage() {
+            Some(lineage) => lineage.stamp_spec(spec, Self::timestamp()),
+            None => spec.clone(),
+        };
+    }
+
+    pub fn set_snippets(&mut self, snippets: FilterSnippets) -> &mut Self {
+        self.snippets = Some(Arc::new(snippets));
+        return self;
+    }
+
+    fn expand(&self, query: &DocumentQuery) -> Result<DocumentQuery, ConnectException> {
+        return match &self.snippets {
+            Some(snippets) => snippets.expand(query),
+            None => Ok(query.clone()),
+        };
+    }
+
+    fn visible(&self, query: &DocumentQuery) -> Result<DocumentQuery, ConnectException> {
+        let mut query = self.expand(query)?;
+        if let Some(policy) = self.repository.soft_delete(&query.data_base(), &query.collection()) {
+            query.set_filter(Some(policy.active_filter(query.filter())));
+        }
+        return Ok(query);
+    }
+
+    fn soft_delete_policy(&self, data_base: &str, collection: &str) -> Result<SoftDeletePolicy, ConnectException> {
+        let Some(policy) = self.repository.soft_delete(data_base, collection) else {
+            let message = format!("Collection '{}.{}' has no soft-delete policy.", data_base, collection);
+            return Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, message));
+        };
+        return Ok(policy);
+    }
+
+    async fn soft_delete(&self, query: &DocumentQuery, policy: &SoftDeletePolicy) -> Result<Vec<DocumentData>, ConnectException> {
+        let documents = self.repository.find_all(query).await?.documents();
+        if !documents.is_empty() {
+            self.repository.bulk_set(query, &self.stamp_spec(&policy.deletion(Self::timestamp()))).await?;
+        }
+        return Ok(self.tag_documents(documents));
+    }
+
+    pub fn set_preferences(&mut self, preferences: Preferences) -> &mut Self {
+        self.preferences = Some(Arc::new(preferences));
+        return self;
+    }
+
+    pub fn query_options(&self, data_base: &str, collection: &str) -> Option<QueryOptions> {
+        return self.preferences.as_ref()
+            .and_then(|p| p.query_options(data_base, collection));
+    }
+
+    fn apply_options(&self, query: &DocumentQuery) -> DocumentQuery {
+        return match self.query_options(&query.data_base(), &query.collection()) {
+            Some(options) => options.apply(query),
+            None => query.clone(),
+        };
+    }
+
+    pub fn set_history(&mut self, history: QueryHistory) -> &mut Self {
+        self.history = Arc::new(Mutex::new(history));
+        return self;
+    }
+
+    pub async fn history(&self) -> QueryHistory {
+        return self.history.lock().await.clone();
+    }
+
+    pub async fn query_history(&self) -> Vec<HistoryEntry> {
+        return self.history.lock().await.entries();
+    }
+
+    pub async fn clear_history(&self) {
+        self.history.lock().await.clear();
+    }
+
+    pub async fn replay(&self, id: u64) -> Result<CollectionData, ConnectException> {
+        let Some(entry) = self.history.lock().await.find(id) else {
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, format!("History entry '{}' not found.", id)));
+        };
+
+        let query = entry.query();
+        return match entry.action() {
+            EHistoryAction::FINDQUERY => self.find_query(&query).await,
+            EHistoryAction::FINDPAGE => self.find_page(&query, entry.offset().unwrap_or_default(), entry.limit().unwrap_or_default()).await,
+            EHistoryAction::FINDALL => self.find_all(&query).await,
+            EHistoryAction::FIND => {
+                let documents: Vec<DocumentData> = self.find(&query).await?.into_iter().collect();
+                Ok(CollectionData::new(documents.len(), None, None, documents))
+            },
+            EHistoryAction::AGGREGATE => {
+                let collection = CollectionQuery::from(query.data_base(), query.collection());
+                let documents = self.aggregate_raw(&collection, &entry.pipeline().unwrap_or_default()).await?;
+                Ok(CollectionData::new(documents.len(), None, None, documents))
+            },
+            action => {
+                let message = format!("History entry '{}' is a '{}' operation and cannot be replayed.", id, action.to_string());
+                Err(ConnectException::from_kind(EConnectErrorKind::UNSUPPORTED, message))
+            },
+        };
+    }
+
+    fn entry(action: EHistoryAction, query: &DocumentQuery) -> HistoryEntry {
+        return HistoryEntry::new(action, query.clone(), Self::timestamp());
+    }
+
+    fn collection_entry(action: EHistoryAction, query: &CollectionQuery) -> HistoryEntry {
+        let find = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        return Self::entry(action, &find);
+    }
+
+    async fn tracked<R>(&self, mut entry: HistoryEntry, operation: impl Future<Output = Result<R, ConnectException>>, count: impl Fn(&R) -> Option<usize>) -> Result<R, ConnectException> {
+        let start = Instant::now();
+        let result = operation.await;
+
+        entry.set_duration(start.elapsed().as_millis());
+        match &result {
+            Ok(value) => if let Some(count) = count(value) {
+                entry.set_count(count);
+            },
+            Err(error) => {
+                entry.set_error(error.message());
+            },
+        };
+        self.history.lock().await.push(entry);
+
+        return result;
+    }
+
+    fn check_schema(&self, data_base: &str, collection: &str, value: &str) -> Result<(), ConnectException> {
+        let violations = self.validate(data_base, collection, value)?;
+        if violations.is_empty() {
+            return Ok(());
+        }
+
+        let details: Vec<String> = violations.iter().map(|v| v.describe()).collect();
+        let message = format!("Schema validation failed: {}", details.join("; "));
+        return Err(ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, message));
+    }
+
+    fn check_schemas(&self, query: &CollectionQuery, documents: &[String]) -> Result<(), ConnectException> {
+        let mut details = Vec::new();
+        for (index, document) in documents.iter().enumerate() {
+            if let Err(error) = self.check_schema(&query.data_base(), &query.collection(), document) {
+                details.push(format!("#{}: {}", index, error.message()));
+            }
+        }
+
+        if details.is_empty() {
+            return Ok(());
+        }
+
+        let message = format!("Import rejected, {} documents do not match the schema: {}", details.len(), details.join("; "));
+        return Err(ConnectException::from_kind(EConnectErrorKind::INVALIDPAYLOAD, message));
+    }
+
+    async fn write_ndjson<W: AsyncWrite + Unpin>(&self, mut stream: BoxStream<'static, Result<DocumentData, ConnectException>>, writer: &mut W, byte_budget: Option<usize>) -> Result<usize, ConnectException> {
+        let mut count = 0;
+        let mut pending = 0;
+        while let Some(r_document) = stream.next().await {
+            let line = format!("{}\n", r_document?.document());
+            if let Err(error) = writer.write_all(line.as_bytes()).await {
+                return Err(ConnectException::from(error));
+            }
+            count += 1;
+
+            pending += line.len();
+            if Self::is_over_budget(pending, byte_budget) {
+                if let Err(error) = writer.flush().await {
+                    return Err(ConnectException::from(error));
+                }
+                pending = 0;
+            }
+        }
+
+        if let Err(error) = writer.flush().await {
+            return Err(ConnectException::from(error));
+        }
+
+        return Ok(count);
+    }
+
+    fn take_batch(values: &mut impl Iterator<Item = String>, batch_size: usize, byte_budget: Option<usize>) -> Vec<String> {
+        let mut batch = Vec::new();
+        let mut bytes = 0;
+        while batch.len() < batch_size && (batch.is_empty() || !Self::is_over_budget(bytes, byte_budget)) {
+            let Some(value) = values.next() else {
+                break;
+            };
+            bytes += value.len();
+            batch.push(value);
+        }
+        return batch;
+    }
+
+    fn is_over_budget(bytes: usize, byte_budget: Option<usize>) -> bool {
+        return byte_budget.is_some_and(|budget| bytes >= budget);
+    }
+
+    fn tag_document(&self, mut document: DocumentData) -> DocumentData {
+        if let Some(origin) = &self.origin {
+            document.set_origin(origin.clone());
+        }
+        return document;
+    }
+
+    fn tag_documents(&self, documents: Vec<DocumentData>) -> Vec<DocumentData> {
+        return documents.into_iter().map(|d| self.tag_document(d)).collect();
+    }
+
+    fn tag_collection(&self, mut collection: CollectionData) -> CollectionData {
+        if let Some(origin) = &self.origin {
+            collection.set_origin(origin.clone());
+        }
+        return collection;
     }
 
     pub async fn status(&self) -> Result<(), ConnectException> {
@@ -31,6 +340,92 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.metadata().await;
     }
 
+    pub async fn server_stats(&self) -> Result<ServerMetadata, ConnectException> {
+        return self.repository.server_stats().await;
+    }
+
+    pub async fn stats_export(&self, format: ETableExportFormat) -> Result<String, ConnectException> {
+        let mut export = TableDataExport::new();
+        export.push(Vec::new(), self.repository.metadata().await?);
+
+        for data_base in self.repository.data_base_find_all().await? {
+            let query = DataBaseQuery::from(data_base.clone());
+            let labels = vec![(String::from("data_base"), data_base.clone())];
+            export.push(labels, self.repository.data_base_metadata(&query).await?);
+
+            for collection in self.repository.collection_find_all(&query).await? {
+                let query = CollectionQuery::from(data_base.clone(), collection.clone());
+                let labels = vec![
+                    (String::from("data_base"), data_base.clone()),
+                    (String::from("collection"), collection)
+                ];
+                export.push(labels, self.repository.collection_metadata(&query).await?);
+            }
+        }
+
+        return Ok(export.render(format));
+    }
+
+    pub async fn server_time(&self) -> Result<ServerTime, ConnectException> {
+        return self.repository.server_time().await;
+    }
+
+    pub async fn health_score(&self) -> HealthScore {
+        let mut health = HealthScore::new();
+
+        if let Err(error) = self.repository.status().await {
+            health.penalize(100, format!("Connection failed: {}", error.message()));
+            return health;
+        }
+
+        match self.repository.latency_probe().await {
+            Ok(hosts) => {
+                let unreachable = hosts.iter().filter(|h| !h.is_reachable()).count();
+                if unreachable > 0 {
+                    let penalty = (50 * unreachable / hosts.len()) as u8;
+                    health.penalize(penalty, format!("{} of {} hosts unreachable", unreachable, hosts.len()));
+                }
+
+                let latencies: Vec<i64> = hosts.iter().filter_map(|h| h.latency()).collect();
+                if !latencies.is_empty() {
+                    let average = latencies.iter().sum::<i64>() / latencies.len() as i64;
+                    if average > CRITICAL_LATENCY {
+                        health.penalize(20, format!("Average latency {} ms", average));
+                    } else if average > HIGH_LATENCY {
+                        health.penalize(10, format!("Average latency {} ms", average));
+                    }
+                }
+            },
+            Err(error) => {
+                health.penalize(20, format!("Latency probe failed: {}", error.message()));
+            },
+        }
+
+        match self.repository.server_time().await {
+            Ok(time) => {
+                let skew = time.skew().abs();
+                if skew > CRITICAL_SKEW {
+                    health.penalize(15, format!("Clock skew {} ms", skew));
+                } else if skew > HIGH_SKEW {
+                    health.penalize(5, format!("Clock skew {} ms", skew));
+                }
+            },
+            Err(error) => {
+                health.penalize(5, format!("Server time unavailable: {}", error.message()));
+            },
+        }
+
+        return health;
+    }
+
+    pub async fn latency_probe(&self) -> Result<Vec<HostLatency>, ConnectException> {
+        return self.repository.latency_probe().await;
+    }
+
+    pub fn routing_events(&self) -> Vec<RoutingEvent> {
+        return self.repository.routing_events();
+    }
+
     pub async fn data_base_exists(&self, query: &DataBaseQuery) -> Result<bool, ConnectException> {
         return self.repository.data_base_exists(query).await;
     }
@@ -51,6 +446,10 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.data_base_metadata(query).await;
     }
 
+    pub async fn data_base_stats(&self, query: &DataBaseQuery) -> Result<DataBaseMetadata, ConnectException> {
+        return self.repository.data_base_stats(query).await;
+    }
+
     pub async fn collection_accept_schema(&self) -> Result<CollectionDefinition, ConnectException> {
         return self.repository.collection_accept_schema().await;
     }
@@ -59,6 +458,51 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.collection_metadata(query).await;
     }
 
+    pub async fn collection_stats(&self, query: &CollectionQuery) -> Result<CollectionMetadata, ConnectException> {
+        return self.repository.collection_stats(query).await;
+    }
+
+    pub async fn collection_indexes(&self, query: &CollectionQuery) -> Result<Vec<CollectionIndex>, ConnectException> {
+        return self.repository.collection_indexes(query).await;
+    }
+
+    pub async fn suggest_indexes(&self, query: &CollectionQuery, history: &[DocumentQuery]) -> Result<Vec<IndexSuggestion>, ConnectException> {
+        let indexes = self.repository.collection_indexes(query).await?;
+
+        let mut suggestions: Vec<IndexSuggestion> = Vec::new();
+        for entry in history {
+            if entry.data_base() != query.data_base() || entry.collection() != query.collection() {
+                continue;
+            }
+
+            let fields = match self.expand(entry)?.filter() {
+                Some(filter) => filter.fields(),
+                None => continue,
+            };
+
+            if fields.is_empty() || indexes.iter().any(|i| i.covers(&fields)) {
+                continue;
+            }
+
+            let position = suggestions.iter().position(|s| {
+                let current = s.fields();
+                current.len() == fields.len() && current.iter().all(|f| fields.contains(f))
+            });
+
+            match position {
+                Some(position) => {
+                    let suggestion = &suggestions[position];
+                    suggestions[position] = IndexSuggestion::new(suggestion.fields(), suggestion.occurrences() + 1);
+                },
+                None => suggestions.push(IndexSuggestion::new(fields, 1)),
+            }
+        }
+
+        suggestions.sort_by_key(|s| Reverse(s.occurrences()));
+
+        return Ok(suggestions);
+    }
+
     pub async fn collection_exists(&self, query: &CollectionQuery) -> Result<bool, ConnectException> {
         return self.repository.collection_exists(query).await;
     }
@@ -71,48 +515,1036 @@ impl <T: IDBRepository> Service<T> {
         return self.repository.collection_drop(query).await;
     }
 
+    pub async fn collection_validator(&self, query: &CollectionQuery) -> Result<Option<CollectionValidator>, ConnectException> {
+        return self.repository.collection_validator(query).await;
+    }
+
+    pub async fn collection_set_validator(&self, query: &CollectionQuery, validator: Option<&CollectionValidator>) -> Result<String, ConnectException> {
+        return self.repository.collection_set_validator(query, validator).await;
+    }
+
     pub async fn collection_rename(&self, query: &CollectionQuery, name: &str) -> Result<String, ConnectException> {
         return self.repository.collection_rename(query, name).await;
     }
 
     pub async fn collection_export(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException> {
-        return self.repository.collection_export(query).await;
+        let documents = self.repository.collection_export(query).await?;
+        return Ok(self.tag_documents(documents));
+    }
+
+    pub async fn collection_export_partitioned(&self, query: &CollectionQuery, partitions: usize, directory: &Path, byte_budget: Option<usize>) -> Result<Vec<usize>, ConnectException> {
+        let streams = self.repository.collection_export_partitioned(query, partitions).await?;
+
+        let paths: Vec<PathBuf> = (0..streams.len())
+            .map(|index| directory.join(format!("{}.part{:04}.ndjson", query.collection(), index)))
+            .collect();
+
+        let mut manifest = self.start_manifest(EExportKind::NDJSON, query);
+
+        let writes = streams.into_iter().zip(paths.iter()).map(|(stream, path)| async move {
+            let file = File::create(path).await;
+            if let Err(error) = file {
+                return Err(ConnectException::from(error));
+            }
+
+            let mut writer = BufWriter::new(file.unwrap());
+            return self.write_ndjson(stream, &mut writer, byte_budget).await;
+        });
+
+        let counts = try_join_all(writes).await?;
+
+        manifest.set_counts(counts.iter().sum(), 0);
+        self.seal_manifest(&mut manifest, &paths).await?;
+
+        return Ok(counts);
+    }
+
+    pub async fn collection_export_ndjson<W: AsyncWrite + Unpin>(&self, query: &CollectionQuery, writer: &mut W, byte_budget: Option<usize>) -> Result<usize, ConnectException> {
+        let find = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        let stream = self.repository.find_stream(&find).await?;
+        return self.write_ndjson(stream, writer, byte_budget).await;
+    }
+
+    pub async fn collection_export_ndjson_file(&self, query: &CollectionQuery, path: &Path, byte_budget: Option<usize>) -> Result<usize, ConnectException> {
+        let file = File::create(path).await;
+        if let Err(error) = file {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut manifest = self.start_manifest(EExportKind::NDJSON, query);
+
+        let mut writer = BufWriter::new(file.unwrap());
+        let count = self.collection_export_ndjson(query, &mut writer, byte_budget).await?;
+        drop(writer);
+
+        manifest.set_counts(count, 0);
+        self.seal_manifest(&mut manifest, &[path.to_path_buf()]).await?;
+
+        return Ok(count);
+    }
+
+    pub async fn collection_export_avro_file(&self, query: &CollectionQuery, path: &Path, block_size: usize) -> Result<usize, ConnectException> {
+        let block_size = block_size.max(1);
+
+        let schema = self.repository.schema(query).await?;
+        let container = AvroContainer::new(AvroSchema::from_schema(&query.data_base(), &query.collection(), &schema)?)?;
+
+        let file = File::create(path).await;
+        if let Err(error) = file {
+            return Err(ConnectException::from(error));
+        }
+
+        let mut manifest = self.start_manifest(EExportKind::AVRO, query);
+
+        let mut writer = BufWriter::new(file.unwrap());
+        if let Err(error) = writer.write_all(&container.header()).await {
+            return Err(ConnectException::from(error));
+        }
+
+        let find = DocumentQuery::from(query.data_base(), query.collection(), None, None, None);
+        let mut stream = self.repository.find_stream(&find).await?;
+
+        let avro = container.schema();
+        let mut count = 0;
+        let mut records = Vec::new();
+        while let Some(r_document) = stream.next().await {
+            let document = r_document?;
+            match avro.encode(&document.document()) {
+                Ok(record) => records.push(record),
+                Err(error) => {
+                    let message = format!("Document #{} cannot be exported as Avro: {}", count, error.message());
+                    return Err(ConnectException::from_kind(error.kind(), message));
+                },
+            }
+            count += 1;
+
+            if records.len() >= block_size {
+                if let Err(error) = writer.write_all(&container.block(&records)).await {
+                    return Err(ConnectException::from(error));
+                }
+                records.clear();
+            }
+        }
+
+        if !records.is_empty() {
+            if let Err(error) = writer.write_all(&container.block(&records)).await {
+                return Err(ConnectException::from(error));
+            }
+        }
+
+        if let Err(error) = writer.flush().await {
+            return Err(ConnectException::from(error));
+        }
+        drop(writer);
+
+        manifest.set_counts(count, 0);
+        self.seal_manifest(&mut manifest, &[path.to_path_buf()]).await?;
+
+        return Ok(count);
+    }
+
+    pub async fn collection_import_ndjson<R: AsyncBufRead + Unpin>(&self, query: &CollectionQuery, reader: R, batch_size: usize, byte_budget: Option<usize>) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let batch_size = batch_size.max(1);
+        let mut lines = reader.lines();
+
+        let mut results = Vec::new();
+        let mut batch = Vec::new();
+        let mut positions = Vec::new();
+        let mut position = 0;
+        let mut bytes = 0;
+
+        loop {
+            let line = match lines.next_line().await {
+                Ok(line) => line,
+                Err(error) => return Err(ConnectException::from(error)),
+            };
+
+            let Some(line) = line else {
+                break;
+            };
+
+            position += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            bytes += line.len();
+            batch.push(line);
+            positions.push(position);
+
+            if batch.len() >= batch_size || Self::is_over_budget(bytes, byte_budget) {
+                results.extend(self.import_ndjson_batch(query, std::mem::take(&mut batch), std::mem::take(&mut positions)).await?);
+                bytes = 0;
+            }
+        }
+
+        if !batch.is_empty() {
+            results.extend(self.import_ndjson_batch(query, batch, positions).await?);
+        }
+
+        return Ok(results);
+    }
+
+    pub async fn collection_import_ndjson_file(&self, query: &CollectionQuery, path: &Path, batch_size: usize, byte_budget: Option<usize>) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let file = File::open(path).await;
+        if let Err(error) = file {
+            return Err(ConnectException::from(error));
+        }
+
+        return self.collection_import_ndjson(query, BufReader::new(file.unwrap()), batch_size, byte_budget).await;
+    }
+
+    pub async fn collection_import_ndjson_file_verified(&self, query: &CollectionQuery, path: &Path, batch_size: usize, byte_budget: Option<usize>) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let check = self.verify_manifest(&export_manifest_repository::manifest_path(path)).await?;
+        let manifest = Self::accept_manifest(check, EExportKind::NDJSON)?;
+
+        let name = path.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+        if !manifest.files().iter().any(|f| f.name() == name) {
+            let message = format!("Manifest does not describe '{}'.", path.display());
+            return Err(ConnectException::new(message));
+        }
+
+        return self.collection_import_ndjson_file(query, path, batch_size, byte_budget).await;
+    }
+
+    async fn import_ndjson_batch(&self, query: &CollectionQuery, batch: Vec<String>, positions: Vec<usize>) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let size = batch.len();
+        let results = self.insert_many(query, batch, size).await?;
+        return Ok(results.into_iter()
+            .map(|r| {
+                let line = positions.get(r.index()).cloned().unwrap_or(r.index());
+                match r.document() {
+                    Some(document) => DocumentInsertResult::success(line, document),
+                    None => DocumentInsertResult::failure(line, r.error().unwrap_or_default()),
+                }
+            })
+            .collect());
+    }
+
+    pub async fn collection_dump(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        let mut manifest = self.start_manifest(EExportKind::DUMP, query);
+
+        let mut report = self.repository.collection_dump(query, path).await?;
+        if report.files().is_empty() {
+            return Ok(report);
+        }
+
+        let files: Vec<PathBuf> = report.files().iter().map(PathBuf::from).collect();
+        manifest.set_counts(report.documents(), report.indexes());
+        let manifest = self.seal_manifest(&mut manifest, &files).await?;
+        report.set_manifest(manifest.display().to_string());
+
+        return Ok(report);
+    }
+
+    pub async fn collection_restore(&self, query: &CollectionQuery, path: &Path) -> Result<DumpReport, ConnectException> {
+        return self.repository.collection_restore(query, path).await;
+    }
+
+    pub async fn collection_restore_verified(&self, query: &CollectionQuery, path: &Path, manifest: &Path) -> Result<DumpReport, ConnectException> {
+        let check = self.verify_manifest(manifest).await?;
+        Self::accept_manifest(check, EExportKind::DUMP)?;
+        return self.repository.collection_restore(query, path).await;
+    }
+
+    pub async fn verify_manifest(&self, path: &Path) -> Result<ManifestCheck, ConnectException> {
+        return export_manifest_repository::verify(path).await;
+    }
+
+    fn start_manifest(&self, kind: EExportKind, query: &CollectionQuery) -> ExportManifest {
+        let mut manifest = ExportManifest::new(kind, self.repository.fingerprint(), query.data_base(), query.collection(), Self::timestamp());
+        if let Some(origin) = &self.origin {
+            manifest.set_origin(origin.clone());
+        }
+        return manifest;
+    }
+
+    async fn seal_manifest(&self, manifest: &mut ExportManifest, files: &[PathBuf]) -> Result<PathBuf, ConnectException> {
+        for file in files {
+            manifest.push_file(export_manifest_repository::describe_file(file).await?);
+        }
+        manifest.finish(Self::timestamp());
+
+        let path = export_manifest_repository::manifest_path(&files[0]);
+        export_manifest_repository::write(&path, manifest).await?;
+        return Ok(path);
+    }
+
+    fn accept_manifest(check: ManifestCheck, kind: EExportKind) -> Result<ExportManifest, ConnectException> {
+        let manifest = check.manifest();
+        if manifest.kind() != kind {
+            let message = format!("Manifest describes a {} export, expected {}.", manifest.kind().to_string(), kind.to_string());
+            return Err(ConnectException::new(message));
+        }
+
+        if !check.is_valid() {
+            let message = format!("Manifest verification failed: {}", check.issues().join("; "));
+            return Err(ConnectException::new(message));
+        }
+
+        return Ok(manifest);
+    }
+
+    fn timestamp() -> u128 {
+        return SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("Cannot read actual date.")
+            .as_millis();
     }
 
-    pub async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<String, ConnectException> {
+    pub async fn collection_import(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportIntegrity, ConnectException> {
+        let documents = self.prepare_documents(query, documents)?;
+        self.check_schemas(query, &documents)?;
         return self.repository.collection_import(query, documents).await;
     }
 
+    pub async fn collection_import_preview(&self, query: &CollectionQuery, documents: Vec<String>) -> Result<ImportPreview, ConnectException> {
+        return self.repository.collection_import_preview(query, documents).await;
+    }
+
+    pub async fn collection_import_confirmed(&self, query: &CollectionQuery, documents: Vec<String>, confirmed: bool) -> Result<ImportPreview, ConnectException> {
+        let mut preview = self.repository.collection_import_preview(query, documents.clone()).await?;
+        if !confirmed {
+            return Ok(preview);
+        }
+
+        let documents = self.prepare_documents(query, documents)?;
+        self.check_schemas(query, &documents)?;
+
+        let mut inserts = Vec::new();
+        for entry in preview.entries() {
+            let document = documents[entry.index()].clone();
+            match (entry.action(), entry.id()) {
+                (EImportAction::INSERT, _) => inserts.push(document),
+                (EImportAction::REPLACE, Some(id)) => {
+                    if !inserts.is_empty() {
+                        Self::check_import(self.repository.collection_import(query, std::mem::take(&mut inserts)).await?)?;
+                    }
+                    self.repository.upsert(&Self::id_query(query, vec![id]), &document).await?;
+                },
+                _ => {},
+            }
+        }
+
+        if !inserts.is_empty() {
+            Self::check_import(self.repository.collection_import(query, inserts).await?)?;
+        }
+
+        preview.set_applied(true);
+
+        return Ok(preview);
+    }
+
+    fn check_import(integrity: ImportIntegrity) -> Result<ImportIntegrity, ConnectException> {
+        if integrity.failures().is_empty() {
+            return Ok(integrity);
+        }
+
+        let details: Vec<String> = integrity.failures().iter()
+            .map(|f| format!("#{}{}: {}", f.index(), f.key().map(|k| format!(" {}", k)).unwrap_or_default(), f.error()))
+            .collect();
+        let message = format!("Import failed for {} documents: {}", details.len(), details.join("; "));
+        return Err(ConnectException::new(message));
+    }
+
+    pub async fn collection_import_mapped(&self, query: &CollectionQuery, documents: Vec<String>, mapping: &DocumentMapping) -> Result<ImportIntegrity, ConnectException> {
+        mapping.validate()?;
+
+        let mut mapped = Vec::new();
+        for document in documents {
+            mapped.push(mapping.apply(&document)?);
+        }
+
+        let mapped = self.prepare_documents(query, mapped)?;
+        self.check_schemas(query, &mapped)?;
+        return self.repository.collection_import(query, mapped).await;
+    }
+
+    pub async fn collection_mapping_preview(&self, query: &CollectionQuery, mapping: &DocumentMapping, sample: usize) -> Result<Vec<DocumentMappingPreview>, ConnectException> {
+        mapping.validate()?;
+
+        let fix = DocumentQuery::from(query.data_base(), query.collection(), None, Some(sample), None);
+        let documents = self.repository.find_all(&fix).await?.documents().iter()
+            .map(|d| d.document())
+            .collect();
+
+        return Ok(mapping.preview(documents));
+    }
+
     pub async fn collection_find_all(&self, query: &DataBaseQuery) -> Result<Vec<String>, ConnectException> {
         return self.repository.collection_find_all(query).await;
     }
 
     pub async fn find_query(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
-        return self.repository.find_query(query).await;
+        let entry = Self::entry(EHistoryAction::FINDQUERY, query);
+        return self.tracked(entry, async {
+            let query = &self.apply_options(&self.visible(query)?);
+            let collection = self.repository.find_query(query).await?;
+            Ok(self.tag_collection(collection))
+        }, |c| Some(c.documents().len())).await;
+    }
+
+    pub async fn find_page(&self, query: &DocumentQuery, offset: u64, limit: u64) -> Result<CollectionData, ConnectException> {
+        let mut entry = Self::entry(EHistoryAction::FINDPAGE, query);
+        entry.set_page(offset, limit);
+        return self.tracked(entry, async {
+            let query = &self.apply_options(&self.visible(query)?);
+            let collection = self.repository.find_page(query, offset, limit).await?;
+            Ok(self.tag_collection(collection))
+        }, |c| Some(c.documents().len())).await;
     }
 
     pub async fn find_all(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
-        return self.repository.find_all(query).await;
+        let entry = Self::entry(EHistoryAction::FINDALL, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            let collection = self.repository.find_all(query).await?;
+            Ok(self.tag_collection(collection))
+        }, |c| Some(c.documents().len())).await;
     }
     
     pub async fn find(&self, query: &DocumentQuery) -> Result<Option<DocumentData>, ConnectException> {
-        return self.repository.find(query).await;
+        let entry = Self::entry(EHistoryAction::FIND, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            let document = self.repository.find(query).await?;
+            Ok(document.map(|d| self.tag_document(d)))
+        }, |d| Some(d.iter().count())).await;
+    }
+
+    pub async fn chain(&self, source: &DocumentQuery, extract_field: &str, template: &DocumentQuery, target_field: &str) -> Result<CollectionData, ConnectException> {
+        let entry = Self::entry(EHistoryAction::CHAIN, template);
+        return self.tracked(entry, async {
+            let source = &self.expand(source)?;
+            let template = &self.expand(template)?;
+            let collection = self.repository.find_query(source).await?;
+
+            let mut keys: Vec<String> = Vec::new();
+            for document in collection.documents() {
+                for value in document.field_values(extract_field)? {
+                    let key = value.to_string();
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+            }
+
+            let values = keys.into_iter()
+                .map(|k| FilterValue::query(k, Vec::new()))
+                .collect();
+            let chain = FilterElement::list(String::from(target_field), EFilterOperator::IN, values);
+
+            let mut filter = template.filter().unwrap_or_else(FilterElement::new);
+            filter.push(chain);
+
+            let query = DocumentQuery::from(template.data_base(), template.collection(), template.skip(), template.limit(), Some(filter));
+            let result = self.repository.find_query(&query).await?;
+            Ok(self.tag_collection(result))
+        }, |c| Some(c.documents().len())).await;
+    }
+
+    pub async fn count(&self, query: &DocumentQuery) -> Result<u64, ConnectException> {
+        let entry = Self::entry(EHistoryAction::COUNT, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            self.repository.count(query).await
+        }, |c| Some(*c as usize)).await;
+    }
+
+    pub async fn document_exists(&self, query: &DocumentQuery) -> Result<bool, ConnectException> {
+        let entry = Self::entry(EHistoryAction::EXISTS, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            self.repository.document_exists(query).await
+        }, |e| Some(usize::from(*e))).await;
+    }
+
+    pub async fn document_size(&self, query: &DocumentQuery) -> Result<DocumentSize, ConnectException> {
+        let entry = Self::entry(EHistoryAction::SIZE, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            self.repository.document_size(query).await
+        }, |_| Some(1)).await;
+    }
+
+    pub async fn find_stream(&self, query: &DocumentQuery) -> Result<BoxStream<'static, Result<DocumentData, ConnectException>>, ConnectException> {
+        let entry = Self::entry(EHistoryAction::FINDSTREAM, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            let stream = self.repository.find_stream(query).await?;
+            let origin = self.origin.clone();
+            Ok(stream.map(move |r_document| r_document.map(|mut document| {
+                if let Some(origin) = &origin {
+                    document.set_origin(origin.clone());
+                }
+                document
+            })).boxed())
+        }, |_| None).await;
     }
 
     pub async fn schema(&self, query: &CollectionQuery) ->  Result<DocumentSchema, ConnectException> {
         return self.repository.schema(query).await;
     }
 
+    pub async fn schema_distribution(&self, query: &CollectionQuery) -> Result<SchemaVersionReport, ConnectException> {
+        let registered: Vec<u32> = match &self.registry {
+            Some(registry) => registry.versions(&query.data_base(), &query.collection()).iter().map(|v| v.version()).collect(),
+            None => Vec::new(),
+        };
+
+        let pipeline = format!("[{{\"$group\": {{\"_id\": \"${}\", \"count\": {{\"$sum\": 1}}}}}}]", SCHEMA_FIELD);
+        let groups = self.repository.aggregate_raw(query, &pipeline).await?;
+
+        let mut claims = Vec::new();
+        for group in groups {
+            let json: Result<Value, serde_json::Error> = serde_json::from_str(&group.document());
+            if let Err(error) = json {
+                return Err(ConnectException::from(error));
+            }
+
+            let json = json.unwrap();
+            let claim = json.get("_id").cloned().unwrap_or(Value::Null);
+            let count = json.get("count").and_then(|c| c.as_u64()).unwrap_or_default() as usize;
+            claims.push((claim, count));
+        }
+
+        return Ok(SchemaVersionReport::from_claims(query.data_base(), query.collection(), &registered, claims));
+    }
+
     pub async fn insert(&self, query: &CollectionQuery, value: &str) -> Result<DocumentData, ConnectException> {
-        return self.repository.insert(query, &value).await;
+        let entry = Self::collection_entry(EHistoryAction::INSERT, query);
+        return self.tracked(entry, async {
+            let value = &self.prepare_document(query, value)?;
+            self.check_schema(&query.data_base(), &query.collection(), value)?;
+            let document = self.repository.insert(query, value).await?;
+            Ok(self.tag_document(document))
+        }, |_| Some(1)).await;
+    }
+
+    pub async fn insert_many(&self, query: &CollectionQuery, values: Vec<String>, batch_size: usize) -> Result<Vec<DocumentInsertResult>, ConnectException> {
+        let entry = Self::collection_entry(EHistoryAction::INSERTMANY, query);
+        return self.tracked(entry, async {
+            let mut results = Vec::new();
+            let mut valid = Vec::new();
+            let mut positions = Vec::new();
+            for (index, value) in values.into_iter().enumerate() {
+                let value = match self.prepare_document(query, &value) {
+                    Ok(value) => value,
+                    Err(error) => {
+                        results.push(DocumentInsertResult::failure(index, error.message()));
+                        continue;
+                    },
+                };
+                match self.check_schema(&query.data_base(), &query.collection(), &value) {
+                    Ok(()) => {
+                        valid.push(value);
+                        positions.push(index);
+                    },
+                    Err(error) => results.push(DocumentInsertResult::failure(index, error.message())),
+                }
+            }
+
+            if !valid.is_empty() {
+                let inserted = self.repository.insert_many(query, valid, batch_size).await?;
+                results.extend(inserted.into_iter()
+                    .map(|r| {
+                        let index = positions.get(r.index()).cloned().unwrap_or(r.index());
+                        match r.document() {
+                            Some(document) => DocumentInsertResult::success(index, self.tag_document(document)),
+                            None => DocumentInsertResult::failure(index, r.error().unwrap_or_default()),
+                        }
+                    }));
+            }
+
+            results.sort_by_key(|r| r.index());
+
+            Ok(results)
+        }, |r| Some(r.iter().filter(|r| r.document().is_some()).count())).await;
     }
 
     pub async fn update(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
-        return self.repository.update(query, value).await;
+        let entry = Self::entry(EHistoryAction::UPDATE, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let value = &self.stamp(value)?;
+            self.check_schema(&query.data_base(), &query.collection(), value)?;
+            let documents = self.repository.update(query, value).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn patch(&self, query: &DocumentQuery, fields: Vec<DocumentUpdateField>) -> Result<Vec<DocumentData>, ConnectException> {
+        let entry = Self::entry(EHistoryAction::PATCH, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let fields = match self.lineage() {
+                Some(lineage) => lineage.stamp_fields(fields, Self::timestamp()),
+                None => fields,
+            };
+            let documents = self.repository.patch(query, fields).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn update_transaction(&self, query: &DocumentQuery, value: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        let entry = Self::entry(EHistoryAction::UPDATETRANSACTION, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let value = &self.stamp(value)?;
+            self.check_schema(&query.data_base(), &query.collection(), value)?;
+            let documents = self.repository.update_transaction(query, value).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn bulk_set(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdateSummary, ConnectException> {
+        let entry = Self::entry(EHistoryAction::BULKSET, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            self.repository.bulk_set(query, &self.stamp_spec(spec)).await
+        }, |s| Some(s.modified())).await;
+    }
+
+    pub async fn bulk_set_preview(&self, query: &DocumentQuery, spec: &UpdateSpec) -> Result<UpdatePreview, ConnectException> {
+        let entry = Self::entry(EHistoryAction::BULKSETPREVIEW, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            self.repository.bulk_set_preview(query, spec).await
+        }, |p| Some(p.total())).await;
+    }
+
+    pub async fn upsert(&self, query: &DocumentQuery, value: &str) -> Result<DocumentData, ConnectException> {
+        let entry = Self::entry(EHistoryAction::UPSERT, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let value = &self.stamp(value)?;
+            self.check_schema(&query.data_base(), &query.collection(), value)?;
+            let document = self.repository.upsert(query, value).await?;
+            Ok(self.tag_document(document))
+        }, |_| Some(1)).await;
+    }
+
+    pub async fn update_revision(&self, query: &DocumentQuery, value: &str, revision: i64) -> Result<DocumentData, ConnectException> {
+        let entry = Self::entry(EHistoryAction::UPDATEREVISION, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let value = &self.stamp(value)?;
+            self.check_schema(&query.data_base(), &query.collection(), value)?;
+            let document = self.repository.update_revision(query, value, revision).await?;
+            Ok(self.tag_document(document))
+        }, |_| Some(1)).await;
     }
 
     pub async fn delete(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>,ConnectException> {
-        return self.repository.delete(query).await;
+        let entry = Self::entry(EHistoryAction::DELETE, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            if let Some(policy) = self.repository.soft_delete(&query.data_base(), &query.collection()) {
+                return self.soft_delete(query, &policy).await;
+            }
+            let documents = self.repository.delete(query).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn delete_transaction(&self, query: &DocumentQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        let entry = Self::entry(EHistoryAction::DELETETRANSACTION, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            if let Some(policy) = self.repository.soft_delete(&query.data_base(), &query.collection()) {
+                return self.soft_delete(query, &policy).await;
+            }
+            let documents = self.repository.delete_transaction(query).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn find_deleted(&self, query: &DocumentQuery) -> Result<CollectionData, ConnectException> {
+        let policy = self.soft_delete_policy(&query.data_base(), &query.collection())?;
+        let mut query = self.expand(query)?;
+        query.set_filter(Some(policy.deleted_filter(query.filter())));
+        let collection = self.repository.find_all(&query).await?;
+        return Ok(self.tag_collection(collection));
+    }
+
+    pub async fn restore(&self, query: &DocumentQuery) -> Result<UpdateSummary, ConnectException> {
+        let entry = Self::entry(EHistoryAction::RESTORE, query);
+        return self.tracked(entry, async {
+            let policy = self.soft_delete_policy(&query.data_base(), &query.collection())?;
+            let mut query = self.expand(query)?;
+            query.set_filter(Some(policy.deleted_filter(query.filter())));
+            self.repository.bulk_set(&query, &self.stamp_spec(&policy.restoration())).await
+        }, |s| Some(s.modified())).await;
+    }
+
+    pub async fn purge(&self, query: &CollectionQuery) -> Result<Vec<DocumentData>, ConnectException> {
+        let entry = Self::collection_entry(EHistoryAction::PURGE, query);
+        return self.tracked(entry, async {
+            let policy = self.soft_delete_policy(&query.data_base(), &query.collection())?;
+            let filter = policy.purge_filter(Self::timestamp());
+            let purge = DocumentQuery::from_filter(query.data_base(), query.collection(), filter);
+            let documents = self.repository.delete(&purge).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn delete_preview(&self, query: &DocumentQuery) -> Result<DocumentDeletePreview, ConnectException> {
+        let entry = Self::entry(EHistoryAction::DELETEPREVIEW, query);
+        return self.tracked(entry, async {
+            let query = &self.visible(query)?;
+            self.repository.delete_preview(query).await
+        }, |p| Some(p.total())).await;
+    }
+
+    pub async fn aggregate_raw(&self, query: &CollectionQuery, pipeline_json: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        let mut entry = Self::collection_entry(EHistoryAction::AGGREGATE, query);
+        entry.set_pipeline(String::from(pipeline_json));
+        return self.tracked(entry, async {
+            let documents = self.repository.aggregate_raw(query, pipeline_json).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn move_documents(&self, query: &DocumentQuery, target_collection: &str) -> Result<Vec<DocumentData>, ConnectException> {
+        let entry = Self::entry(EHistoryAction::MOVE, query);
+        return self.tracked(entry, async {
+            let query = &self.expand(query)?;
+            let documents = self.repository.move_documents(query, target_collection).await?;
+            Ok(self.tag_documents(documents))
+        }, |d| Some(d.len())).await;
+    }
+
+    pub async fn copy_collection<U: IDBRepository>(&self, source: &CollectionQuery, target: &Service<U>, destination: &CollectionQuery, options: &CopyOptions, progress: impl Fn(&CopyReport)) -> Result<CopyReport, ConnectException> {
+        let query = DocumentQuery::from(source.data_base(), source.collection(), None, None, None);
+        return self.copy_query(&query, target, destination, options, None, progress).await;
+    }
+
+    pub async fn copy_documents<U: IDBRepository>(&self, query: &DocumentQuery, target: &Service<U>, destination: &CollectionQuery, options: &CopyOptions, progress: impl Fn(&CopyReport)) -> Result<CopyReport, ConnectException> {
+        return self.copy_query(query, target, destination, options, None, progress).await;
+    }
+
+    pub async fn promote<U: IDBRepository>(&self, environment: &Environment, target: &Service<U>, target_environment: &Environment, items: Vec<PromoteItem>, options: &PromoteOptions, key: &str) -> Result<PromoteReport, ConnectException> {
+        environment.authorize(EEnvironmentAction::READ, None, false)?;
+
+        let lower = target_environment.is_lower_than(environment);
+        let copy = options.copy();
+        let action = if copy.conflict() == EConflictPolicy::OVERWRITE {
+            EEnvironmentAction::DESTRUCTIVE
+        } else if lower {
+            EEnvironmentAction::SEED
+        } else {
+            EEnvironmentAction::WRITE
+        };
+        target_environment.authorize(action, options.confirmation().as_deref(), options.is_force())?;
+
+        let masking = if lower { environment.masking() } else { None };
+
+        let mut reports = Vec::new();
+        for item in items {
+            let source = item.source();
+            let destination = item.destination();
+            let report = self.copy_query(&source, target, &destination, &copy, masking.as_ref(), |_| {}).await?;
+            reports.push(PromoteItemReport::new(
+                format!("{}.{}", source.data_base(), source.collection()),
+                format!("{}.{}", destination.data_base(), destination.collection()),
+                report));
+        }
+
+        let mut report = PromoteReport::new(environment.name(), target_environment.name(), Self::timestamp(), masking.is_some(), reports);
+        report.sign(key);
+
+        return Ok(report);
+    }
+
+    async fn copy_query<U: IDBRepository>(&self, query: &DocumentQuery, target: &Service<U>, destination: &CollectionQuery, options: &CopyOptions, masking: Option<&DocumentMapping>, progress: impl Fn(&CopyReport)) -> Result<CopyReport, ConnectException> {
+        let query = &self.expand(query)?;
+        let mut stream = self.repository.find_stream(query).await?;
+
+        let mut report = CopyReport::new(options.conflict());
+        let mut batch = Vec::new();
+        let mut bytes = 0;
+
+        while let Some(r_document) = stream.next().await {
+            report.add_read(1);
+            let r_document = match (r_document, masking) {
+                (Ok(document), Some(mapping)) => mapping.apply(&document.document())
+                    .map(|masked| DocumentData::new(document.data_base(), document.collection(), document.base_key(), document.keys(), masked)),
+                (r_document, _) => r_document,
+            };
+
+            match r_document {
+                Ok(document) => {
+                    bytes += document.document().len();
+                    batch.push(document);
+                },
+                Err(error) => {
+                    report.push_error(error.message());
+                },
+            }
+
+            if batch.len() >= options.batch_size() || Self::is_over_budget(bytes, options.byte_budget()) {
+                Self::copy_batch(target, destination, std::mem::take(&mut batch), options, &mut report).await?;
+                progress(&report);
+                bytes = 0;
+            }
+        }
+
+        if !batch.is_empty() {
+            Self::copy_batch(target, destination, batch, options, &mut report).await?;
+        }
+
+        progress(&report);
+
+        return Ok(report);
+    }
+
+    fn id_query(collection: &CollectionQuery, ids: Vec<String>) -> DocumentQuery {
+        let values = ids.into_iter()
+            .map(|id| FilterValue::query(id, Vec::new()))
+            .collect();
+        let mut filter = FilterElement::new();
+        filter.push(FilterElement::list(String::from("_id"), EFilterOperator::IN, values));
+        return DocumentQuery::from(collection.data_base(), collection.collection(), None, None, Some(filter));
+    }
+
+    async fn copy_batch<U: IDBRepository>(target: &Service<U>, destination: &CollectionQuery, batch: Vec<DocumentData>, options: &CopyOptions, report: &mut CopyReport) -> Result<(), ConnectException> {
+        let mut keyed = Vec::new();
+        for document in batch {
+            match document.field_values("_id") {
+                Ok(ids) if !ids.is_empty() => keyed.push((ids[0].to_string(), document.document())),
+                Ok(_) => {
+                    report.push_error(String::from("Document without identifier skipped."));
+                },
+                Err(error) => {
+                    report.push_error(error.message());
+                },
+            }
+        }
+
+        if keyed.is_empty() {
+            return Ok(());
+        }
+
+        let ids = keyed.iter().map(|(id, _)| id.clone()).collect();
+        let query = Self::id_query(destination, ids);
+        let mut existing = Vec::new();
+        for document in target.find_query(&query).await?.documents() {
+            if let Some(id) = document.field_values("_id")?.first() {
+                existing.push(id.to_string());
+            }
+        }
+
+        let (conflicts, fresh): (Vec<_>, Vec<_>) = keyed.into_iter()
+            .partition(|(id, _)| existing.contains(id));
+
+        if !fresh.is_empty() {
+            let values = fresh.into_iter().map(|(_, document)| document).collect();
+            for result in target.insert_many(destination, values, options.batch_size()).await? {
+                match result.error() {
+                    Some(error) => {
+                        report.push_error(error);
+                    },
+                    None => {
+                        report.add_inserted(1);
+                    },
+                }
+            }
+        }
+
+        if options.conflict() == EConflictPolicy::SKIP {
+            report.add_skipped(conflicts.len());
+            return Ok(());
+        }
+
+        for (id, document) in conflicts {
+            let query = Self::id_query(destination, vec![id]);
+            match target.upsert(&query, &document).await {
+                Ok(_) => {
+                    report.add_overwritten(1);
+                },
+                Err(error) => {
+                    report.push_error(error.message());
+                },
+            }
+        }
+
+        return Ok(());
+    }
+
+}
+
+impl <T: IDBRepository + IDBMaintenance> Service<T> {
+
+    pub async fn collection_compact(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return self.repository.collection_compact(query).await;
+    }
+
+    pub async fn collection_reindex(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return self.repository.collection_reindex(query).await;
+    }
+
+    pub async fn collection_validate(&self, query: &CollectionQuery) -> Result<MaintenanceReport, ConnectException> {
+        return self.repository.collection_validate(query).await;
+    }
+
+}
+
+impl <T: IDBRepository + 'static> Service<T> {
+
+    pub async fn collection_import_parallel(&self, query: &CollectionQuery, values: Vec<String>, options: &ImportOptions) -> Result<ImportReport, ConnectException> {
+        let mut rejected = Vec::new();
+        let mut valid = Vec::new();
+        let mut positions = Vec::new();
+        for (index, value) in values.into_iter().enumerate() {
+            if options.mode() == EImportMode::ORDERED && !rejected.is_empty() {
+                rejected.push(DocumentInsertResult::failure(index, String::from("Skipped after a previous failure.")));
+                continue;
+            }
+            match self.check_schema(&query.data_base(), &query.collection(), &value) {
+                Ok(()) => {
+                    valid.push(value);
+                    positions.push(index);
+                },
+                Err(error) => rejected.push(DocumentInsertResult::failure(index, error.message())),
+            }
+        }
+
+        let (imported, workers) = match options.mode() {
+            EImportMode::ORDERED => self.import_ordered(query, valid, options).await?,
+            EImportMode::UNORDERED => self.import_unordered(query, valid, options).await?,
+        };
+
+        let mut results: Vec<DocumentInsertResult> = imported.into_iter()
+            .map(|r| {
+                let index = positions.get(r.index()).cloned().unwrap_or(r.index());
+                match r.document() {
+                    Some(document) => DocumentInsertResult::success(index, self.tag_document(document)),
+                    None => DocumentInsertResult::failure(index, r.error().unwrap_or_default()),
+                }
+            })
+            .collect();
+        results.append(&mut rejected);
+        results.sort_by_key(|r| r.index());
+
+        return Ok(ImportReport::new(options.mode(), results, workers));
+    }
+
+    async fn import_ordered(&self, query: &CollectionQuery, values: Vec<String>, options: &ImportOptions) -> Result<(Vec<DocumentInsertResult>, Vec<ImportWorkerReport>), ConnectException> {
+        let mut results = Vec::new();
+        let mut report = ImportWorkerReport::new(0);
+
+        let mut failed = false;
+        let mut offset = 0;
+        let mut values = values.into_iter();
+        loop {
+            let batch = Self::take_batch(&mut values, options.batch_size(), options.byte_budget());
+            if batch.is_empty() {
+                break;
+            }
+
+            let size = batch.len();
+            if failed {
+                for index in 0..size {
+                    results.push(DocumentInsertResult::failure(offset + index, String::from("Skipped after a previous failure.")));
+                }
+                offset += size;
+                continue;
+            }
+
+            for result in self.repository.insert_many(query, batch, size).await? {
+                report.register(&result);
+                failed = failed || !result.is_success();
+                results.push(result.offset(offset));
+            }
+            offset += size;
+        }
+
+        return Ok((results, vec![report]));
+    }
+
+    async fn import_unordered(&self, query: &CollectionQuery, values: Vec<String>, options: &ImportOptions) -> Result<(Vec<DocumentInsertResult>, Vec<ImportWorkerReport>), ConnectException> {
+        let parallelism = options.parallelism();
+
+        let (sender, receiver) = mpsc::channel::<(usize, Vec<String>, Option<OwnedSemaphorePermit>)>(parallelism * 2);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let budget = options.byte_budget().map(|b| b.div_ceil(BUDGET_UNIT).clamp(1, u32::MAX as usize) as u32);
+        let semaphore = budget.map(|b| Arc::new(Semaphore::new(b as usize)));
+
+        let mut handles = Vec::new();
+        for worker in 0..parallelism {
+            let receiver = receiver.clone();
+            let repository = self.repository.clone();
+            let query = query.clone();
+
+            handles.push(tokio::spawn(async move {
+                let mut report = ImportWorkerReport::new(worker);
+                let mut results = Vec::new();
+
+                loop {
+                    let batch = receiver.lock().await.recv().await;
+                    let Some((offset, values, permit)) = batch else {
+                        break;
+                    };
+
+                    let size = values.len();
+                    let inserted = match repository.insert_many(&query, values, size).await {
+                        Ok(inserted) => inserted,
+                        Err(error) => (0..size)
+                            .map(|index| DocumentInsertResult::failure(index, error.message()))
+                            .collect(),
+                    };
+                    drop(permit);
+
+                    for result in inserted {
+                        report.register(&result);
+                        results.push(result.offset(offset));
+                    }
+                }
+
+                (results, report)
+            }));
+        }
+
+        let mut offset = 0;
+        let mut values = values.into_iter();
+        loop {
+            let batch = Self::take_batch(&mut values, options.batch_size(), options.byte_budget());
+            if batch.is_empty() {
+                break;
+            }
+
+            let mut permit = None;
+            if let (Some(semaphore), Some(budget)) = (&semaphore, budget) {
+                let bytes: usize = batch.iter().map(|v| v.len()).sum();
+                let cost = bytes.div_ceil(BUDGET_UNIT).clamp(1, budget as usize) as u32;
+                match semaphore.clone().acquire_many_owned(cost).await {
+                    Ok(acquired) => permit = Some(acquired),
+                    Err(error) => return Err(ConnectException::new(error.to_string())),
+                }
+            }
+
+            let size = batch.len();
+            if sender.send((offset, batch, permit)).await.is_err() {
+                break;
+            }
+            offset += size;
+        }
+        drop(sender);
+
+        let mut results = Vec::new();
+        let mut workers = Vec::new();
+        for handle in handles {
+            match handle.await {
+                Ok((mut worker_results, report)) => {
+                    results.append(&mut worker_results);
+                    workers.push(report);
+                },
+                Err(error) => return Err(ConnectException::new(error.to_string())),
+            }
+        }
+
+        return Ok((results, workers));
     }
 
 }
\ No newline at end of file
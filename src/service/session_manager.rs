@@ -0,0 +1,501 @@
+use std::{collections::HashMap, time::{Duration, Instant}};
+
+use crate::{commons::{exception::{connect_exception::ConnectException, e_connect_error_kind::EConnectErrorKind}, secret::passphrase}, domain::{collection::{copy_options::CopyOptions, copy_report::CopyReport}, filter::{collection_query::CollectionQuery, document_query::DocumentQuery}, session_state::SessionState, session_tab::SessionTab}, infrastructure::repository::i_db_repository::IDBRepository};
+
+use super::service::Service;
+
+pub struct SessionManager<T: IDBRepository> {
+    services: HashMap<String, Service<T>>,
+    states: HashMap<String, SessionState>,
+    current: Option<String>,
+    tabs: Vec<SessionTab>,
+    active_tab: Option<String>,
+    idle_timeout: Option<Duration>,
+    passphrase: Option<String>,
+    locked: bool,
+    last_activity: Instant
+}
+
+impl <T: IDBRepository> SessionManager<T> {
+
+    pub fn new() -> SessionManager<T> {
+        SessionManager {
+            services: HashMap::new(),
+            states: HashMap::new(),
+            current: None,
+            tabs: Vec::new(),
+            active_tab: None,
+            idle_timeout: None,
+            passphrase: None,
+            locked: false,
+            last_activity: Instant::now()
+        }
+    }
+
+    pub fn set_idle_timeout(&mut self, idle_timeout: Option<Duration>) -> &mut SessionManager<T> {
+        self.idle_timeout = idle_timeout;
+        return self;
+    }
+
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        return self.idle_timeout;
+    }
+
+    pub fn set_passphrase(&mut self, value: &str) -> Result<&mut SessionManager<T>, ConnectException> {
+        self.check_unlocked()?;
+        self.passphrase = Some(passphrase::hash(value)?);
+        return Ok(self);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        return self.locked;
+    }
+
+    pub fn lock(&mut self) -> Result<(), ConnectException> {
+        if self.passphrase.is_none() {
+            return Err(ConnectException::new(String::from("Define a passphrase before locking the session.")));
+        }
+        self.locked = true;
+        return Ok(());
+    }
+
+    pub fn unlock(&mut self, value: &str) -> Result<(), ConnectException> {
+        let Some(hashed) = &self.passphrase else {
+            self.locked = false;
+            return Ok(());
+        };
+
+        if !passphrase::verify(value, hashed) {
+            return Err(ConnectException::new(String::from("Invalid passphrase.")));
+        }
+
+        self.locked = false;
+        self.last_activity = Instant::now();
+        return Ok(());
+    }
+
+    pub fn touch(&mut self) -> Result<(), ConnectException> {
+        self.check_unlocked()?;
+        self.last_activity = Instant::now();
+        if let Some(state) = self.current.as_ref().and_then(|alias| self.states.get_mut(alias)) {
+            state.touch();
+        }
+        return Ok(());
+    }
+
+    pub fn check_idle(&mut self) -> Vec<String> {
+        let Some(timeout) = self.idle_timeout else {
+            return Vec::new();
+        };
+
+        if self.last_activity.elapsed() >= timeout && self.passphrase.is_some() {
+            self.locked = true;
+        }
+
+        let idle: Vec<String> = self.states.iter()
+            .filter(|(_, s)| s.idle_for() >= timeout.as_millis())
+            .map(|(alias, _)| alias.clone())
+            .collect();
+
+        for alias in &idle {
+            self.drop_connection(alias);
+        }
+
+        return idle;
+    }
+
+    fn check_unlocked(&self) -> Result<(), ConnectException> {
+        if self.locked {
+            return Err(ConnectException::new(String::from("Session is locked, unlock it with the passphrase.")));
+        }
+        return Ok(());
+    }
+
+    pub fn open(&mut self, alias: &str, service: Service<T>) -> Result<&Service<T>, ConnectException> {
+        self.check_unlocked()?;
+        if self.services.contains_key(alias) {
+            return Err(ConnectException::new(format!("Connection '{}' is already open.", alias)));
+        }
+
+        self.last_activity = Instant::now();
+        self.services.insert(String::from(alias), service);
+        self.states.insert(String::from(alias), SessionState::new(String::from(alias)));
+
+        if self.current.is_none() {
+            self.current = Some(String::from(alias));
+        }
+
+        return self.find(alias);
+    }
+
+    pub fn close(&mut self, alias: &str) -> Result<(), ConnectException> {
+        if !self.services.contains_key(alias) {
+            return Err(ConnectException::new(format!("Connection '{}' is not open.", alias)));
+        }
+
+        self.drop_connection(alias);
+
+        return Ok(());
+    }
+
+    fn drop_connection(&mut self, alias: &str) {
+        self.services.remove(alias);
+        self.states.remove(alias);
+
+        if self.current.as_deref() == Some(alias) {
+            self.current = None;
+        }
+
+        let ids: Vec<String> = self.tabs.iter()
+            .filter(|t| t.alias() == alias)
+            .map(|t| t.id())
+            .collect();
+        for id in ids {
+            self.remove_tab(&id);
+        }
+    }
+
+    pub fn switch_connection(&mut self, alias: &str) -> Result<&Service<T>, ConnectException> {
+        self.check_unlocked()?;
+        let Some(state) = self.states.get_mut(alias) else {
+            return Err(ConnectException::new(format!("Connection '{}' is not open.", alias)));
+        };
+
+        state.touch();
+        self.last_activity = Instant::now();
+        self.current = Some(String::from(alias));
+
+        return self.find(alias);
+    }
+
+    pub fn current(&mut self) -> Option<&Service<T>> {
+        if self.locked {
+            return None;
+        }
+        let alias = self.current.clone()?;
+        self.touch_connection(&alias);
+        return self.services.get(&alias);
+    }
+
+    pub fn current_alias(&self) -> Option<String> {
+        return self.current.clone();
+    }
+
+    pub fn get(&mut self, alias: &str) -> Option<&Service<T>> {
+        if self.locked {
+            return None;
+        }
+        self.touch_connection(alias);
+        return self.services.get(alias);
+    }
+
+    pub fn find(&mut self, alias: &str) -> Result<&Service<T>, ConnectException> {
+        self.check_unlocked()?;
+        self.touch_connection(alias);
+        return self.service(alias);
+    }
+
+    fn service(&self, alias: &str) -> Result<&Service<T>, ConnectException> {
+        let Some(service) = self.services.get(alias) else {
+            return Err(ConnectException::new(format!("Connection '{}' is not open.", alias)));
+        };
+        return Ok(service);
+    }
+
+    fn touch_connection(&mut self, alias: &str) {
+        let Some(state) = self.states.get_mut(alias) else {
+            return;
+        };
+        state.touch();
+        self.last_activity = Instant::now();
+    }
+
+    pub fn aliases(&self) -> Vec<String> {
+        let mut aliases: Vec<String> = self.services.keys().cloned().collect();
+        aliases.sort();
+        return aliases;
+    }
+
+    pub fn state(&self, alias: &str) -> Option<SessionState> {
+        return self.states.get(alias).cloned();
+    }
+
+    pub fn set_location(&mut self, alias: &str, data_base: Option<String>, collection: Option<String>) -> Result<SessionState, ConnectException> {
+        self.check_unlocked()?;
+        let Some(state) = self.states.get_mut(alias) else {
+            return Err(ConnectException::new(format!("Connection '{}' is not open.", alias)));
+        };
+
+        state.set_data_base(data_base);
+        state.set_collection(collection);
+        state.touch();
+        self.last_activity = Instant::now();
+
+        return Ok(state.clone());
+    }
+
+    pub fn tabs(&self) -> Vec<SessionTab> {
+        return self.tabs.clone();
+    }
+
+    pub fn active_tab(&self) -> Option<SessionTab> {
+        return self.active_tab.as_ref().and_then(|id| self.tab(id));
+    }
+
+    pub fn tab(&self, id: &str) -> Option<SessionTab> {
+        return self.tabs.iter().find(|t| t.id() == id).cloned();
+    }
+
+    pub fn open_tab(&mut self, alias: &str) -> Result<SessionTab, ConnectException> {
+        self.check_unlocked()?;
+        if !self.services.contains_key(alias) {
+            return Err(ConnectException::new(format!("Connection '{}' is not open.", alias)));
+        }
+
+        let tab = SessionTab::new(String::from(alias));
+        self.tabs.push(tab.clone());
+        self.activate_tab(&tab.id());
+
+        return Ok(tab);
+    }
+
+    pub fn close_tab(&mut self, id: &str) -> Result<(), ConnectException> {
+        if self.tab(id).is_none() {
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, format!("Tab '{}' does not exist.", id)));
+        }
+
+        self.remove_tab(id);
+
+        return Ok(());
+    }
+
+    pub fn switch_tab(&mut self, id: &str) -> Result<SessionTab, ConnectException> {
+        self.check_unlocked()?;
+        if self.tab(id).is_none() {
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, format!("Tab '{}' does not exist.", id)));
+        }
+
+        self.activate_tab(id);
+
+        return Ok(self.tab(id).unwrap());
+    }
+
+    pub fn next_tab(&mut self) -> Result<Option<SessionTab>, ConnectException> {
+        return self.cycle_tab(1);
+    }
+
+    pub fn previous_tab(&mut self) -> Result<Option<SessionTab>, ConnectException> {
+        return self.cycle_tab(-1);
+    }
+
+    pub fn set_tab_location(&mut self, id: &str, data_base: Option<String>, collection: Option<String>) -> Result<SessionTab, ConnectException> {
+        self.check_unlocked()?;
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id() == id) else {
+            return Err(ConnectException::from_kind(EConnectErrorKind::NOTFOUND, format!("Tab '{}' does not exist.", id)));
+        };
+
+        tab.set_location(data_base, collection);
+        self.last_activity = Instant::now();
+
+        return Ok(tab.clone());
+    }
+
+    fn cycle_tab(&mut self, step: isize) -> Result<Option<SessionTab>, ConnectException> {
+        self.check_unlocked()?;
+        if self.tabs.is_empty() {
+            return Ok(None);
+        }
+
+        let size = self.tabs.len() as isize;
+        let position = self.active_tab.as_ref()
+            .and_then(|id| self.tabs.iter().position(|t| &t.id() == id))
+            .map(|p| p as isize)
+            .unwrap_or(if step > 0 { -1 } else { 0 });
+        let next = (position + step).rem_euclid(size) as usize;
+
+        let id = self.tabs[next].id();
+        self.activate_tab(&id);
+
+        return Ok(self.tab(&id));
+    }
+
+    fn activate_tab(&mut self, id: &str) {
+        let Some(tab) = self.tabs.iter_mut().find(|t| t.id() == id) else {
+            return;
+        };
+
+        tab.touch();
+        let alias = tab.alias();
+        self.active_tab = Some(String::from(id));
+        self.touch_connection(&alias);
+        self.current = Some(alias);
+        self.last_activity = Instant::now();
+    }
+
+    fn remove_tab(&mut self, id: &str) {
+        let Some(position) = self.tabs.iter().position(|t| t.id() == id) else {
+            return;
+        };
+
+        self.tabs.remove(position);
+
+        if self.active_tab.as_deref() != Some(id) {
+            return;
+        }
+
+        self.active_tab = None;
+        if !self.tabs.is_empty() {
+            let next = self.tabs[position.min(self.tabs.len() - 1)].id();
+            self.activate_tab(&next);
+        }
+    }
+
+    pub async fn copy_documents(&mut self, source: &str, query: &DocumentQuery, target: &str, destination: &CollectionQuery, options: &CopyOptions) -> Result<CopyReport, ConnectException> {
+        self.check_unlocked()?;
+        self.touch_connection(source);
+        self.touch_connection(target);
+
+        let from = self.service(source)?;
+        let to = self.service(target)?;
+
+        return from.copy_documents(query, to, destination, options, |_| {}).await;
+    }
+
+}
+
+impl <T: IDBRepository> Default for SessionManager<T> {
+
+    fn default() -> Self {
+        SessionManager::new()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use std::{thread, time::Duration};
+
+    use crate::{domain::connection_data::ConnectionData, infrastructure::repository::{e_db_repository::EDBRepository, i_db_repository::IDBRepository, mongo_db::mongo_db_repository::MongoDbRepository}, service::service::Service};
+
+    use super::SessionManager;
+
+    async fn manager(aliases: &[&str]) -> SessionManager<impl IDBRepository> {
+        let connection = ConnectionData::new(EDBRepository::MongoDB, String::from("mongodb://localhost:27017"));
+        let repository = MongoDbRepository::new(&connection).await.expect("Client options should parse without a server");
+
+        let mut manager = SessionManager::new();
+        for alias in aliases {
+            manager.open(alias, Service::from(repository.clone())).expect("Connection should open");
+        }
+        manager
+    }
+
+    #[tokio::test]
+    async fn first_connection_becomes_current() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        assert_eq!(manager.current_alias().as_deref(), Some("a"));
+        assert!(manager.current().is_some());
+    }
+
+    #[tokio::test]
+    async fn open_tab_activates_it_and_its_connection() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        let tab = manager.open_tab("b").unwrap();
+
+        assert_eq!(manager.active_tab().map(|t| t.id()), Some(tab.id()));
+        assert_eq!(manager.current_alias().as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn cycling_tabs_wraps_around() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        let first = manager.open_tab("a").unwrap();
+        let second = manager.open_tab("b").unwrap();
+
+        assert_eq!(manager.next_tab().unwrap().map(|t| t.id()), Some(first.id()));
+        assert_eq!(manager.current_alias().as_deref(), Some("a"));
+        assert_eq!(manager.previous_tab().unwrap().map(|t| t.id()), Some(second.id()));
+        assert_eq!(manager.current_alias().as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn closing_the_active_tab_activates_a_neighbour() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        let first = manager.open_tab("a").unwrap();
+        let second = manager.open_tab("b").unwrap();
+        manager.close_tab(&second.id()).unwrap();
+
+        assert_eq!(manager.active_tab().map(|t| t.id()), Some(first.id()));
+        assert_eq!(manager.current_alias().as_deref(), Some("a"));
+        assert!(manager.switch_tab(&second.id()).is_err());
+    }
+
+    #[tokio::test]
+    async fn switch_connection_changes_current_and_rejects_unknown() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        assert!(manager.switch_connection("b").is_ok());
+        assert_eq!(manager.current_alias().as_deref(), Some("b"));
+
+        assert!(manager.switch_connection("missing").is_err());
+        assert_eq!(manager.current_alias().as_deref(), Some("b"));
+    }
+
+    #[tokio::test]
+    async fn closing_a_connection_drops_its_tabs() {
+        let mut manager = manager(&["a", "b"]).await;
+
+        manager.open_tab("a").unwrap();
+        manager.open_tab("b").unwrap();
+        manager.close("b").unwrap();
+
+        assert_eq!(manager.tabs().len(), 1);
+        assert_eq!(manager.current_alias().as_deref(), Some("a"));
+        assert_eq!(manager.aliases(), vec![String::from("a")]);
+    }
+
+    #[tokio::test]
+    async fn check_idle_drops_only_unused_connections() {
+        let mut manager = manager(&["a", "b"]).await;
+        manager.set_idle_timeout(Some(Duration::from_millis(100)));
+
+        thread::sleep(Duration::from_millis(150));
+        assert!(manager.find("a").is_ok());
+
+        let idle = manager.check_idle();
+
+        assert_eq!(idle, vec![String::from("b")]);
+        assert_eq!(manager.aliases(), vec![String::from("a")]);
+    }
+
+    #[tokio::test]
+    async fn check_idle_without_timeout_keeps_everything() {
+        let mut manager = manager(&["a"]).await;
+
+        thread::sleep(Duration::from_millis(20));
+
+        assert!(manager.check_idle().is_empty());
+        assert_eq!(manager.aliases(), vec![String::from("a")]);
+    }
+
+    #[tokio::test]
+    async fn idle_timeout_locks_a_protected_session() {
+        let mut manager = manager(&["a"]).await;
+        manager.set_passphrase("secret").unwrap();
+        manager.set_idle_timeout(Some(Duration::from_millis(50)));
+
+        thread::sleep(Duration::from_millis(80));
+        manager.check_idle();
+
+        assert!(manager.is_locked());
+        assert!(manager.current().is_none());
+        assert!(manager.unlock("wrong").is_err());
+        assert!(manager.unlock("secret").is_ok());
+    }
+
+}
\ No newline at end of file